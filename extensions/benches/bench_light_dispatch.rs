@@ -0,0 +1,31 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+use solana_tools_lite_extensions::analysis::light_protocol::constants;
+use solana_tools_lite_extensions::analysis::light_protocol::parsing::parse_light_instruction;
+use std::hint::black_box;
+
+fn bench_parse_light_instruction(c: &mut Criterion) {
+    let inputs: Vec<(PubkeyBase58, Vec<u8>)> = [
+        (constants::LIGHT_SYSTEM_PROGRAM_ID, constants::DISCRIMINATOR_INVOKE_CPI),
+        (constants::ACCOUNT_COMPRESSION_PROGRAM_ID, constants::DISCRIMINATOR_INSERT_INTO_QUEUES),
+        (constants::LIGHT_REGISTRY_ID, constants::DISCRIMINATOR_REPORT_WORK),
+        (constants::COMPRESSED_TOKEN_PROGRAM_ID, constants::DISCRIMINATOR_CTOKEN_FREEZE),
+    ]
+    .into_iter()
+    .map(|(program, disc)| (PubkeyBase58::try_from(program).unwrap(), disc.to_vec()))
+    .collect();
+
+    // Many Light instructions in one transaction: each goes through the dispatch once
+    c.bench_function("parse_light_instruction_x100", |b| {
+        b.iter(|| {
+            for _ in 0..25 {
+                for (program_id, data) in &inputs {
+                    black_box(parse_light_instruction(black_box(program_id), black_box(data)));
+                }
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_parse_light_instruction);
+criterion_main!(benches);
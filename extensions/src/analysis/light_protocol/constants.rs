@@ -70,6 +70,9 @@ pub const NEW_ADDRESS_PARAMS_SIZE: usize = 40;
 /// Size of a Pubkey (32 bytes).
 pub const PUBKEY_SIZE: usize = 32;
 
+/// Size of CpiContextAccountInitParams (merkle tree + queue + five u16 capacities).
+pub const CPI_CONTEXT_INIT_PARAMS_SIZE: usize = 2 * PUBKEY_SIZE + 5 * U16_SIZE;
+
 // ============================================================================
 // INSTRUCTION DATA OFFSETS
 // ============================================================================
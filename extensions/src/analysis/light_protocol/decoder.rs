@@ -2,8 +2,9 @@ use super::constants::{
     U64_SIZE, U16_SIZE, DISCRIMINATOR_SIZE,
     TRANSFER2_HEADER_SIZE, COMPRESSION_STRUCT_SIZE, COMPRESSION_AMOUNT_OFFSET,
     OUTPUT_STRUCT_SIZE, OUTPUT_AMOUNT_OFFSET, NEW_ADDRESS_PARAMS_SIZE,
-    PUBKEY_SIZE,
+    PUBKEY_SIZE, CPI_CONTEXT_INIT_PARAMS_SIZE,
 };
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
 
 /// Safely decode a u64 value from instruction data at the given offset.
 /// 
@@ -469,10 +470,11 @@ pub fn decode_transfer2(data: &[u8]) -> super::models::LightProtocolAction {
         None => None,
     };
 
+    // A lamports-only compress/decompress moves no tokens: report no amount rather than 0
     Action::Transfer2 {
         in_lamports,
         out_lamports,
-        amount: Some(total_amount),
+        amount: (total_amount > 0).then_some(total_amount),
     }
 }
 
@@ -682,6 +684,40 @@ pub fn decode_invoke_cpi_with_account_info(data: &[u8]) -> super::models::LightP
     Action::InvokeCpiWithAccountInfo { lamports: None, from_index: None, to_index: None }
 }
 
+/// Decode InitCpiContextAccount instruction from Light System Program.
+///
+/// Layout after the discriminator (`CpiContextAccountInitParams`):
+/// `associated_merkle_tree: Pubkey`, `associated_queue: Pubkey`, then five `u16` capacities
+/// (new addresses, read-only addresses, read-only accounts, input and output account infos).
+/// Legacy instructions carry no data; any length other than the exact layout yields `params: None`.
+pub fn decode_init_cpi_context_account(data: &[u8]) -> super::models::LightProtocolAction {
+    use super::models::{CpiContextAccountParams, LightProtocolAction as Action};
+
+    if data.len() != DISCRIMINATOR_SIZE + CPI_CONTEXT_INIT_PARAMS_SIZE {
+        return Action::InitCpiContextAccount { params: None };
+    }
+
+    let pubkey_at = |offset: usize| PubkeyBase58::try_from(&data[offset..offset + PUBKEY_SIZE]).ok();
+    let tree_offset = DISCRIMINATOR_SIZE;
+    let queue_offset = tree_offset + PUBKEY_SIZE;
+    let lens_offset = queue_offset + PUBKEY_SIZE;
+    let len_at = |i: usize| decode_u16_at_offset(data, lens_offset + i * U16_SIZE);
+
+    let params = (|| {
+        Some(CpiContextAccountParams {
+            associated_merkle_tree: pubkey_at(tree_offset)?,
+            associated_queue: pubkey_at(queue_offset)?,
+            new_addresses_len: len_at(0)?,
+            readonly_addresses_len: len_at(1)?,
+            readonly_accounts_len: len_at(2)?,
+            in_account_infos_len: len_at(3)?,
+            out_account_infos_len: len_at(4)?,
+        })
+    })();
+
+    Action::InitCpiContextAccount { params }
+}
+
 /// Decode Token Interface MintTo instruction.
 pub fn decode_token_interface_mint_to(data: &[u8]) -> super::models::LightProtocolAction {
     use super::models::LightProtocolAction as Action;
@@ -1,3 +1,4 @@
+use solana_tools_lite::extensions::registry;
 use solana_tools_lite::extensions::traits::ProtocolAnalyzer;
 use solana_tools_lite::models::analysis::{TxAnalysis, AnalysisWarning};
 use solana_tools_lite::extensions::analysis::{PrivacyImpact, AnalysisExtensionAction};
@@ -62,13 +63,13 @@ impl ProtocolAnalyzer for LightProtocol {
         let programs = match self.supported_programs() {
             Ok(programs) => programs,
             Err(e) => {
-                eprintln!("[CRITICAL] Light Protocol: Failed to initialize program IDs: {}", e);
+                log::error!("Light Protocol: failed to initialize program IDs: {}", e);
                 return;
             }
         };
         
-        for instr in message.instructions() {
-            let program_id = match account_list.get(instr.program_id_index as usize) {
+        for (instruction_index, instr) in message.instructions().iter().enumerate() {
+            let program_id = match instr.program_id(account_list) {
                 Some(pk) => pk,
                 None => continue,
             };
@@ -83,6 +84,7 @@ impl ProtocolAnalyzer for LightProtocol {
                 continue;
             }
 
+            // The core hands analyzers data already cut to the scan window
             let action = parsing::parse_light_instruction(program_id, &instr.data);
 
             // Record SOL transfers if action provides them
@@ -102,18 +104,24 @@ impl ProtocolAnalyzer for LightProtocol {
                     
                     analysis.transfers.push(solana_tools_lite::models::analysis::TransferView {
                         from: from.clone(),
-                        to,
+                        to: to.clone(),
                         lamports: *l,
                         from_is_signer: match from_index {
                             Some(idx) => account_list.get(*idx as usize).map(|pk| pk == signer).unwrap_or(false),
                             None => false,
                         },
+                        // Lookup-resolved indexes are not covered by the header and report false
+                        from_writable: from_index.is_some_and(|idx| message.is_static_writable(idx as usize)),
+                        to_writable: to_index.is_some_and(|idx| message.is_static_writable(idx as usize)),
+                        to_is_fee_payer: *to_index == Some(0),
                     });
 
                     // Track total SOL sent by signer if applicable
                     if let Some(idx) = from_index {
                         if account_list.get(*idx as usize).map(|pk| pk == signer).unwrap_or(false) {
                             analysis.total_sol_send_by_signer += *l as u128;
+                            let outflow = analysis.signer_outflows_by_recipient.entry(to).or_default();
+                            *outflow = outflow.saturating_add(*l);
                         }
                     }
                 }
@@ -122,7 +130,7 @@ impl ProtocolAnalyzer for LightProtocol {
 
             // Count privacy impact regardless of signer involvement
             // Privacy level should reflect the transaction's actual operations, not the signer's role
-            match action.privacy_impact() {
+            match registry::effective_privacy_impact(&action) {
                 PrivacyImpact::Confidential => analysis.confidential_ops_count += 1,
                 PrivacyImpact::StorageCompression => analysis.storage_ops_count += 1,
                 PrivacyImpact::Hybrid => {
@@ -134,7 +142,7 @@ impl ProtocolAnalyzer for LightProtocol {
 
             analysis
                 .extension_actions
-                .push(AnalysisExtensionAction::new(Arc::new(action)));
+                .push(AnalysisExtensionAction::at_instruction(instruction_index, Arc::new(action)));
         }
     }
 
@@ -1,4 +1,24 @@
 use solana_tools_lite::extensions::{traits::ExtensionAction, analysis::{PrivacyImpact, AnalysisAction}};
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+
+/// Parameters of InitCpiContextAccount (`CpiContextAccountInitParams`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CpiContextAccountParams {
+    /// State Merkle tree the CPI context is bound to.
+    pub associated_merkle_tree: PubkeyBase58,
+    /// Output queue of that tree.
+    pub associated_queue: PubkeyBase58,
+    /// Capacity for new addresses.
+    pub new_addresses_len: u16,
+    /// Capacity for read-only addresses.
+    pub readonly_addresses_len: u16,
+    /// Capacity for read-only accounts.
+    pub readonly_accounts_len: u16,
+    /// Capacity for input account infos.
+    pub in_account_infos_len: u16,
+    /// Capacity for output account infos.
+    pub out_account_infos_len: u16,
+}
 
 /// Action types detected for Light Protocol (ZK Compression).
 #[derive(Debug, Clone, PartialEq)]
@@ -61,8 +81,9 @@ pub enum LightProtocolAction {
     /// InvokeCpiWithAccountInfo: CPI invocation with AccountInfo support.
     InvokeCpiWithAccountInfo { lamports: Option<u64>, from_index: Option<u8>, to_index: Option<u8> },
     /// InitCpiContextAccount: Initialize a CPI context account.
-    InitCpiContextAccount,
-    /// ReInitCpiContextAccount: Reinitialize a CPI context account.
+    /// `params` is `None` for the legacy form (no instruction data) or an unrecognized layout.
+    InitCpiContextAccount { params: Option<CpiContextAccountParams> },
+    /// ReInitCpiContextAccount: Reinitialize a CPI context account (takes no instruction data).
     ReInitCpiContextAccount,
     
     // ========================================================================
@@ -204,6 +225,8 @@ impl LightProtocolAction {
                 
                 if parts.is_empty() {
                     "Compressed Token - Batch Transfer".to_string()
+                } else if amount.is_none() {
+                    format!("Compressed Token - Lamports Transfer ({})", parts.join(", "))
                 } else {
                     format!("Compressed Token - Batch Transfer ({})", parts.join(", "))
                 }
@@ -243,7 +266,15 @@ impl LightProtocolAction {
                     None => "Light System Invoke (CPI with AccountInfo)".to_string(),
                 }
             }
-            Self::InitCpiContextAccount => "Initialize CPI Context Account".to_string(),
+            Self::InitCpiContextAccount { params } => {
+                match params {
+                    Some(p) => format!(
+                        "Initialize CPI Context Account (tree {}, queue {})",
+                        p.associated_merkle_tree, p.associated_queue
+                    ),
+                    None => "Initialize CPI Context Account".to_string(),
+                }
+            }
             Self::ReInitCpiContextAccount => "Reinitialize CPI Context Account".to_string(),
             
             // Account Compression Program
@@ -300,6 +331,64 @@ impl LightProtocolAction {
         }
     }
 
+    /// Stable action name (the variant name), used as the privacy policy key.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CTokenTransfer { .. } => "CTokenTransfer",
+            Self::CTokenApprove { .. } => "CTokenApprove",
+            Self::CTokenRevoke => "CTokenRevoke",
+            Self::CTokenMintTo { .. } => "CTokenMintTo",
+            Self::CTokenBurn { .. } => "CTokenBurn",
+            Self::CloseTokenAccount => "CloseTokenAccount",
+            Self::CTokenFreezeAccount => "CTokenFreezeAccount",
+            Self::CTokenThawAccount => "CTokenThawAccount",
+            Self::CTokenTransferChecked { .. } => "CTokenTransferChecked",
+            Self::CTokenMintToChecked { .. } => "CTokenMintToChecked",
+            Self::CTokenBurnChecked { .. } => "CTokenBurnChecked",
+            Self::CreateTokenAccount => "CreateTokenAccount",
+            Self::CreateAssociatedTokenAccount => "CreateAssociatedTokenAccount",
+            Self::Transfer2 { .. } => "Transfer2",
+            Self::CreateAssociatedTokenAccountIdempotent => "CreateAssociatedTokenAccountIdempotent",
+            Self::MintAction => "MintAction",
+            Self::Claim => "Claim",
+            Self::WithdrawFundingPool { .. } => "WithdrawFundingPool",
+            Self::Invoke { .. } => "Invoke",
+            Self::InvokeCpi { .. } => "InvokeCpi",
+            Self::InvokeCpiWithReadOnly { .. } => "InvokeCpiWithReadOnly",
+            Self::InvokeCpiWithAccountInfo { .. } => "InvokeCpiWithAccountInfo",
+            Self::InitCpiContextAccount { .. } => "InitCpiContextAccount",
+            Self::ReInitCpiContextAccount => "ReInitCpiContextAccount",
+            Self::InsertIntoQueues => "InsertIntoQueues",
+            Self::InitializeCompressionConfig => "InitializeCompressionConfig",
+            Self::UpdateCompressionConfig => "UpdateCompressionConfig",
+            Self::DecompressAccountsIdempotent => "DecompressAccountsIdempotent",
+            Self::CompressAccountsIdempotent => "CompressAccountsIdempotent",
+            Self::CreateConfigCounter => "CreateConfigCounter",
+            Self::CreateCompressibleConfig => "CreateCompressibleConfig",
+            Self::RegistryClaim => "RegistryClaim",
+            Self::CompressAndClose => "CompressAndClose",
+            Self::RegisterForester => "RegisterForester",
+            Self::RegisterForesterEpoch => "RegisterForesterEpoch",
+            Self::FinalizeRegistration => "FinalizeRegistration",
+            Self::ReportWork => "ReportWork",
+            Self::TokenInterfaceMintTo { .. } => "TokenInterfaceMintTo",
+            Self::TokenInterfaceTransfer { .. } => "TokenInterfaceTransfer",
+            Self::BatchCompress { .. } => "BatchCompress",
+            Self::TokenInterfaceApprove => "TokenInterfaceApprove",
+            Self::TokenInterfaceRevoke => "TokenInterfaceRevoke",
+            Self::TokenInterfaceFreeze => "TokenInterfaceFreeze",
+            Self::TokenInterfaceThaw => "TokenInterfaceThaw",
+            Self::CreateTokenPool => "CreateTokenPool",
+            Self::AddTokenPool => "AddTokenPool",
+            Self::CTokenFreeze => "CTokenFreeze",
+            Self::CTokenThaw => "CTokenThaw",
+            Self::Freeze => "Freeze",
+            Self::Thaw => "Thaw",
+            Self::Unknown { .. } => "Unknown",
+            Self::UnknownEightByte { .. } => "UnknownEightByte",
+        }
+    }
+
     /// Determine the privacy impact of this Light Protocol action.
     pub fn privacy_impact(&self) -> PrivacyImpact {
         match self {
@@ -324,7 +413,7 @@ impl LightProtocolAction {
             Self::CTokenApprove { .. } | Self::CTokenRevoke |
             Self::MintAction | Self::Claim | Self::WithdrawFundingPool { .. } |
             Self::Invoke { .. } | Self::InvokeCpi { .. } | Self::InvokeCpiWithReadOnly { .. } | Self::InvokeCpiWithAccountInfo { .. } |
-            Self::InitCpiContextAccount | Self::ReInitCpiContextAccount |
+            Self::InitCpiContextAccount { .. } | Self::ReInitCpiContextAccount |
             Self::InsertIntoQueues | Self::InitializeCompressionConfig | Self::UpdateCompressionConfig |
             Self::DecompressAccountsIdempotent | Self::CompressAccountsIdempotent |
             Self::CreateConfigCounter | Self::CreateCompressibleConfig |
@@ -356,6 +445,10 @@ impl ExtensionAction for LightProtocolAction {
 
 /// Implement AnalysisAction trait (specialized trait) for Light Protocol actions.
 impl AnalysisAction for LightProtocolAction {
+    fn action_name(&self) -> &'static str {
+        self.name()
+    }
+
     fn privacy_impact(&self) -> PrivacyImpact {
         self.privacy_impact()
     }
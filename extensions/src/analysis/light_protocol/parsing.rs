@@ -2,13 +2,105 @@
 use super::decoder::{
     decode_u64_at_offset, extract_discriminator_u8, extract_discriminator_u64, decode_transfer2, decode_token_interface_mint_to, decode_batch_compress,
     decode_invoke, decode_invoke_cpi, decode_invoke_cpi_with_readonly, decode_invoke_cpi_with_account_info,
+    decode_init_cpi_context_account,
 };
+use super::constants;
+use super::models::LightProtocolAction;
 use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Decoder for a single 8-byte discriminator; receives the full instruction data.
+pub(crate) type Decoder = fn(&[u8]) -> LightProtocolAction;
+
+/// 8-byte discriminator dispatch tables, one per Light program.
+///
+/// Tables are scoped per program so a discriminator sent to the wrong program
+/// still resolves to `UnknownEightByte`.
+pub(crate) struct DispatchTables {
+    /// Token Interface instructions of the Compressed Token program (fallback after 1-byte discriminators)
+    pub compressed_token: HashMap<[u8; 8], Decoder>,
+    pub light_system: HashMap<[u8; 8], Decoder>,
+    pub account_compression: HashMap<[u8; 8], Decoder>,
+    pub registry: HashMap<[u8; 8], Decoder>,
+}
+
+/// Returns the dispatch tables, building them on first use.
+pub(crate) fn dispatch_tables() -> &'static DispatchTables {
+    use LightProtocolAction as Action;
+
+    static TABLES: OnceLock<DispatchTables> = OnceLock::new();
+
+    TABLES.get_or_init(|| {
+        let compressed_token: [([u8; 8], Decoder); 10] = [
+            (constants::DISCRIMINATOR_TOKEN_INTERFACE_MINT_TO, decode_token_interface_mint_to),
+            (constants::DISCRIMINATOR_TOKEN_INTERFACE_TRANSFER, |_| Action::TokenInterfaceTransfer { amount: None }),
+            (constants::DISCRIMINATOR_BATCH_COMPRESS, decode_batch_compress),
+            (constants::DISCRIMINATOR_TOKEN_INTERFACE_APPROVE, |_| Action::TokenInterfaceApprove),
+            (constants::DISCRIMINATOR_TOKEN_INTERFACE_REVOKE, |_| Action::TokenInterfaceRevoke),
+            (constants::DISCRIMINATOR_TOKEN_INTERFACE_FREEZE, |_| Action::TokenInterfaceFreeze),
+            (constants::DISCRIMINATOR_CTOKEN_THAW, |_| Action::CTokenThaw),
+            (constants::DISCRIMINATOR_CREATE_TOKEN_POOL, |_| Action::CreateTokenPool),
+            (constants::DISCRIMINATOR_ADD_TOKEN_POOL, |_| Action::AddTokenPool),
+            (constants::DISCRIMINATOR_CTOKEN_FREEZE, |_| Action::CTokenFreeze),
+        ];
+
+        let light_system: [([u8; 8], Decoder); 6] = [
+            (constants::DISCRIMINATOR_INVOKE, decode_invoke),
+            (constants::DISCRIMINATOR_INVOKE_CPI, decode_invoke_cpi),
+            (constants::DISCRIMINATOR_INVOKE_CPI_WITH_READ_ONLY, decode_invoke_cpi_with_readonly),
+            (constants::DISCRIMINATOR_INVOKE_CPI_WITH_ACCOUNT_INFO, decode_invoke_cpi_with_account_info),
+            (constants::DISCRIMINATOR_INIT_CPI_CONTEXT_ACCOUNT_INSTRUCTION, decode_init_cpi_context_account),
+            (constants::DISCRIMINATOR_RE_INIT_CPI_CONTEXT_ACCOUNT_INSTRUCTION, |_| Action::ReInitCpiContextAccount),
+        ];
+
+        let account_compression: [([u8; 8], Decoder); 5] = [
+            (constants::DISCRIMINATOR_INSERT_INTO_QUEUES, |_| Action::InsertIntoQueues),
+            (constants::DISCRIMINATOR_INITIALIZE_COMPRESSION_CONFIG, |_| Action::InitializeCompressionConfig),
+            (constants::DISCRIMINATOR_UPDATE_COMPRESSION_CONFIG, |_| Action::UpdateCompressionConfig),
+            (constants::DISCRIMINATOR_DECOMPRESS_ACCOUNTS_IDEMPOTENT, |_| Action::DecompressAccountsIdempotent),
+            (constants::DISCRIMINATOR_COMPRESS_ACCOUNTS_IDEMPOTENT, |_| Action::CompressAccountsIdempotent),
+        ];
+
+        let registry: [([u8; 8], Decoder); 8] = [
+            (constants::DISCRIMINATOR_CREATE_CONFIG_COUNTER, |_| Action::CreateConfigCounter),
+            (constants::DISCRIMINATOR_CREATE_COMPRESSIBLE_CONFIG, |_| Action::CreateCompressibleConfig),
+            (constants::DISCRIMINATOR_REGISTRY_CLAIM, |_| Action::RegistryClaim),
+            (constants::DISCRIMINATOR_COMPRESS_AND_CLOSE, |_| Action::CompressAndClose),
+            (constants::DISCRIMINATOR_REGISTER_FORESTER, |_| Action::RegisterForester),
+            (constants::DISCRIMINATOR_REGISTER_FORESTER_EPOCH, |_| Action::RegisterForesterEpoch),
+            (constants::DISCRIMINATOR_FINALIZE_REGISTRATION, |_| Action::FinalizeRegistration),
+            (constants::DISCRIMINATOR_REPORT_WORK, |_| Action::ReportWork),
+        ];
+
+        DispatchTables {
+            compressed_token: HashMap::from(compressed_token),
+            light_system: HashMap::from(light_system),
+            account_compression: HashMap::from(account_compression),
+            registry: HashMap::from(registry),
+        }
+    })
+}
+
+/// Look up `discriminator` in `table` and decode, or report it as unknown.
+fn dispatch(table: &HashMap<[u8; 8], Decoder>, discriminator: [u8; 8], data: &[u8]) -> LightProtocolAction {
+    match table.get(&discriminator) {
+        Some(decode) => decode(data),
+        None => LightProtocolAction::UnknownEightByte { discriminator },
+    }
+}
+
+/// Dispatch an 8-byte-discriminator program; data shorter than 8 bytes is `Unknown`.
+fn dispatch_eight_byte(table: &HashMap<[u8; 8], Decoder>, data: &[u8]) -> LightProtocolAction {
+    match extract_discriminator_u64(data) {
+        Some(discriminator) => dispatch(table, discriminator, data),
+        None => LightProtocolAction::Unknown { discriminator: 0 },
+    }
+}
 
 /// Parse Light Protocol instruction based on program ID and data.
-pub fn parse_light_instruction(program_id: &PubkeyBase58, data: &[u8]) -> super::models::LightProtocolAction {
-    use super::models::LightProtocolAction as Action;
-    use super::constants;
+pub fn parse_light_instruction(program_id: &PubkeyBase58, data: &[u8]) -> LightProtocolAction {
+    use LightProtocolAction as Action;
     
     let program_id_str = program_id.to_string();
     match program_id_str.as_str() {
@@ -52,25 +144,11 @@ pub fn parse_light_instruction(program_id: &PubkeyBase58, data: &[u8]) -> super:
                     constants::DISCRIMINATOR_WITHDRAW_FUNDING_POOL => Action::WithdrawFundingPool { 
                         amount: decode_u64_at_offset(data, constants::OFFSET_CTOKEN_AMOUNT)
                     },
-                    _ => {
-                        if let Some(disc_8) = extract_discriminator_u64(data) {
-                            match disc_8 {
-                                constants::DISCRIMINATOR_TOKEN_INTERFACE_MINT_TO => decode_token_interface_mint_to(data),
-                                constants::DISCRIMINATOR_TOKEN_INTERFACE_TRANSFER => Action::TokenInterfaceTransfer { amount: None },
-                                constants::DISCRIMINATOR_BATCH_COMPRESS => decode_batch_compress(data),
-                                constants::DISCRIMINATOR_TOKEN_INTERFACE_APPROVE => Action::TokenInterfaceApprove,
-                                constants::DISCRIMINATOR_TOKEN_INTERFACE_REVOKE => Action::TokenInterfaceRevoke,
-                                constants::DISCRIMINATOR_TOKEN_INTERFACE_FREEZE => Action::TokenInterfaceFreeze,
-                                constants::DISCRIMINATOR_CTOKEN_THAW => Action::CTokenThaw,
-                                constants::DISCRIMINATOR_CREATE_TOKEN_POOL => Action::CreateTokenPool,
-                                constants::DISCRIMINATOR_ADD_TOKEN_POOL => Action::AddTokenPool,
-                                constants::DISCRIMINATOR_CTOKEN_FREEZE => Action::CTokenFreeze,
-                                _ => Action::UnknownEightByte { discriminator: disc_8 },
-                            }
-                        } else {
-                            Action::Unknown { discriminator }
-                        }
-                    }
+                    // Not a 1-byte instruction: fall back to the 8-byte Token Interface table
+                    _ => match extract_discriminator_u64(data) {
+                        Some(disc_8) => dispatch(&dispatch_tables().compressed_token, disc_8, data),
+                        None => Action::Unknown { discriminator },
+                    },
                 }
             } else {
                 Action::Unknown { discriminator: 0 }
@@ -80,60 +158,17 @@ pub fn parse_light_instruction(program_id: &PubkeyBase58, data: &[u8]) -> super:
         // ====================================================================
         // LIGHT SYSTEM PROGRAM - 8-BYTE DISCRIMINATORS
         // ====================================================================
-        constants::LIGHT_SYSTEM_PROGRAM_ID => {
-            if let Some(discriminator) = extract_discriminator_u64(data) {
-                match discriminator {
-                    constants::DISCRIMINATOR_INVOKE => decode_invoke(data),
-                    constants::DISCRIMINATOR_INVOKE_CPI => decode_invoke_cpi(data),
-                    constants::DISCRIMINATOR_INVOKE_CPI_WITH_READ_ONLY => decode_invoke_cpi_with_readonly(data),
-                    constants::DISCRIMINATOR_INVOKE_CPI_WITH_ACCOUNT_INFO => decode_invoke_cpi_with_account_info(data),
-                    constants::DISCRIMINATOR_INIT_CPI_CONTEXT_ACCOUNT_INSTRUCTION => Action::InitCpiContextAccount,
-                    constants::DISCRIMINATOR_RE_INIT_CPI_CONTEXT_ACCOUNT_INSTRUCTION => Action::ReInitCpiContextAccount,
-                    _ => Action::UnknownEightByte { discriminator },
-                }
-            } else {
-                Action::Unknown { discriminator: 0 }
-            }
-        }
+        constants::LIGHT_SYSTEM_PROGRAM_ID => dispatch_eight_byte(&dispatch_tables().light_system, data),
         
         // ====================================================================
         // ACCOUNT COMPRESSION PROGRAM - 8-BYTE DISCRIMINATORS
         // ====================================================================
-        constants::ACCOUNT_COMPRESSION_PROGRAM_ID => {
-            if let Some(discriminator) = extract_discriminator_u64(data) {
-                match discriminator {
-                    constants::DISCRIMINATOR_INSERT_INTO_QUEUES => Action::InsertIntoQueues,
-                    constants::DISCRIMINATOR_INITIALIZE_COMPRESSION_CONFIG => Action::InitializeCompressionConfig,
-                    constants::DISCRIMINATOR_UPDATE_COMPRESSION_CONFIG => Action::UpdateCompressionConfig,
-                    constants::DISCRIMINATOR_DECOMPRESS_ACCOUNTS_IDEMPOTENT => Action::DecompressAccountsIdempotent,
-                    constants::DISCRIMINATOR_COMPRESS_ACCOUNTS_IDEMPOTENT => Action::CompressAccountsIdempotent,
-                    _ => Action::UnknownEightByte { discriminator },
-                }
-            } else {
-                Action::Unknown { discriminator: 0 }
-            }
-        }
+        constants::ACCOUNT_COMPRESSION_PROGRAM_ID => dispatch_eight_byte(&dispatch_tables().account_compression, data),
         
         // ====================================================================
         // LIGHT REGISTRY PROGRAM - 8-BYTE DISCRIMINATORS
         // ====================================================================
-        constants::LIGHT_REGISTRY_ID => {
-            if let Some(discriminator) = extract_discriminator_u64(data) {
-                match discriminator {
-                    constants::DISCRIMINATOR_CREATE_CONFIG_COUNTER => Action::CreateConfigCounter,
-                    constants::DISCRIMINATOR_CREATE_COMPRESSIBLE_CONFIG => Action::CreateCompressibleConfig,
-                    constants::DISCRIMINATOR_REGISTRY_CLAIM => Action::RegistryClaim,
-                    constants::DISCRIMINATOR_COMPRESS_AND_CLOSE => Action::CompressAndClose,
-                    constants::DISCRIMINATOR_REGISTER_FORESTER => Action::RegisterForester,
-                    constants::DISCRIMINATOR_REGISTER_FORESTER_EPOCH => Action::RegisterForesterEpoch,
-                    constants::DISCRIMINATOR_FINALIZE_REGISTRATION => Action::FinalizeRegistration,
-                    constants::DISCRIMINATOR_REPORT_WORK => Action::ReportWork,
-                    _ => Action::UnknownEightByte { discriminator },
-                }
-            } else {
-                Action::Unknown { discriminator: 0 }
-            }
-        }
+        constants::LIGHT_REGISTRY_ID => dispatch_eight_byte(&dispatch_tables().registry, data),
         
         // ====================================================================
         // SPL NOOP PROGRAM - No discriminators
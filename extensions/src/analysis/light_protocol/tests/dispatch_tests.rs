@@ -95,3 +95,122 @@ fn test_ctoken_withdraw_funding_pool() {
          panic!("Failed to parse WithdrawFundingPool: {:?}", action);
      }
 }
+
+#[test]
+fn test_eight_byte_dispatch_table_matches_expectations() {
+    use crate::analysis::light_protocol::parsing::dispatch_tables;
+    use LightProtocolAction as Action;
+
+    // (program, discriminator, expected action for discriminator-only data)
+    let cases: Vec<(&str, [u8; 8], Action)> = vec![
+        (constants::COMPRESSED_TOKEN_PROGRAM_ID, constants::DISCRIMINATOR_TOKEN_INTERFACE_MINT_TO, Action::TokenInterfaceMintTo { amount: None }),
+        (constants::COMPRESSED_TOKEN_PROGRAM_ID, constants::DISCRIMINATOR_TOKEN_INTERFACE_TRANSFER, Action::TokenInterfaceTransfer { amount: None }),
+        (constants::COMPRESSED_TOKEN_PROGRAM_ID, constants::DISCRIMINATOR_BATCH_COMPRESS, Action::BatchCompress { amount: None }),
+        (constants::COMPRESSED_TOKEN_PROGRAM_ID, constants::DISCRIMINATOR_TOKEN_INTERFACE_APPROVE, Action::TokenInterfaceApprove),
+        (constants::COMPRESSED_TOKEN_PROGRAM_ID, constants::DISCRIMINATOR_TOKEN_INTERFACE_REVOKE, Action::TokenInterfaceRevoke),
+        (constants::COMPRESSED_TOKEN_PROGRAM_ID, constants::DISCRIMINATOR_TOKEN_INTERFACE_FREEZE, Action::TokenInterfaceFreeze),
+        (constants::COMPRESSED_TOKEN_PROGRAM_ID, constants::DISCRIMINATOR_CTOKEN_THAW, Action::CTokenThaw),
+        (constants::COMPRESSED_TOKEN_PROGRAM_ID, constants::DISCRIMINATOR_CREATE_TOKEN_POOL, Action::CreateTokenPool),
+        (constants::COMPRESSED_TOKEN_PROGRAM_ID, constants::DISCRIMINATOR_ADD_TOKEN_POOL, Action::AddTokenPool),
+        (constants::COMPRESSED_TOKEN_PROGRAM_ID, constants::DISCRIMINATOR_CTOKEN_FREEZE, Action::CTokenFreeze),
+        (constants::LIGHT_SYSTEM_PROGRAM_ID, constants::DISCRIMINATOR_INVOKE, Action::Invoke { lamports: None, from_index: None, to_index: None }),
+        (constants::LIGHT_SYSTEM_PROGRAM_ID, constants::DISCRIMINATOR_INVOKE_CPI, Action::InvokeCpi { lamports: None, from_index: None, to_index: None }),
+        (constants::LIGHT_SYSTEM_PROGRAM_ID, constants::DISCRIMINATOR_INVOKE_CPI_WITH_READ_ONLY, Action::InvokeCpiWithReadOnly { lamports: None, from_index: None, to_index: None }),
+        (constants::LIGHT_SYSTEM_PROGRAM_ID, constants::DISCRIMINATOR_INVOKE_CPI_WITH_ACCOUNT_INFO, Action::InvokeCpiWithAccountInfo { lamports: None, from_index: None, to_index: None }),
+        (constants::LIGHT_SYSTEM_PROGRAM_ID, constants::DISCRIMINATOR_INIT_CPI_CONTEXT_ACCOUNT_INSTRUCTION, Action::InitCpiContextAccount { params: None }),
+        (constants::LIGHT_SYSTEM_PROGRAM_ID, constants::DISCRIMINATOR_RE_INIT_CPI_CONTEXT_ACCOUNT_INSTRUCTION, Action::ReInitCpiContextAccount),
+        (constants::ACCOUNT_COMPRESSION_PROGRAM_ID, constants::DISCRIMINATOR_INSERT_INTO_QUEUES, Action::InsertIntoQueues),
+        (constants::ACCOUNT_COMPRESSION_PROGRAM_ID, constants::DISCRIMINATOR_INITIALIZE_COMPRESSION_CONFIG, Action::InitializeCompressionConfig),
+        (constants::ACCOUNT_COMPRESSION_PROGRAM_ID, constants::DISCRIMINATOR_UPDATE_COMPRESSION_CONFIG, Action::UpdateCompressionConfig),
+        (constants::ACCOUNT_COMPRESSION_PROGRAM_ID, constants::DISCRIMINATOR_DECOMPRESS_ACCOUNTS_IDEMPOTENT, Action::DecompressAccountsIdempotent),
+        (constants::ACCOUNT_COMPRESSION_PROGRAM_ID, constants::DISCRIMINATOR_COMPRESS_ACCOUNTS_IDEMPOTENT, Action::CompressAccountsIdempotent),
+        (constants::LIGHT_REGISTRY_ID, constants::DISCRIMINATOR_CREATE_CONFIG_COUNTER, Action::CreateConfigCounter),
+        (constants::LIGHT_REGISTRY_ID, constants::DISCRIMINATOR_CREATE_COMPRESSIBLE_CONFIG, Action::CreateCompressibleConfig),
+        (constants::LIGHT_REGISTRY_ID, constants::DISCRIMINATOR_REGISTRY_CLAIM, Action::RegistryClaim),
+        (constants::LIGHT_REGISTRY_ID, constants::DISCRIMINATOR_COMPRESS_AND_CLOSE, Action::CompressAndClose),
+        (constants::LIGHT_REGISTRY_ID, constants::DISCRIMINATOR_REGISTER_FORESTER, Action::RegisterForester),
+        (constants::LIGHT_REGISTRY_ID, constants::DISCRIMINATOR_REGISTER_FORESTER_EPOCH, Action::RegisterForesterEpoch),
+        (constants::LIGHT_REGISTRY_ID, constants::DISCRIMINATOR_FINALIZE_REGISTRATION, Action::FinalizeRegistration),
+        (constants::LIGHT_REGISTRY_ID, constants::DISCRIMINATOR_REPORT_WORK, Action::ReportWork),
+    ];
+
+    let tables = dispatch_tables();
+    let table_len = tables.compressed_token.len()
+        + tables.light_system.len()
+        + tables.account_compression.len()
+        + tables.registry.len();
+    assert_eq!(table_len, cases.len(), "every table entry must be covered");
+
+    for (program, disc, expected) in cases {
+        let program_id = PubkeyBase58::try_from(program).unwrap();
+
+        let table = match program {
+            constants::COMPRESSED_TOKEN_PROGRAM_ID => &tables.compressed_token,
+            constants::LIGHT_SYSTEM_PROGRAM_ID => &tables.light_system,
+            constants::ACCOUNT_COMPRESSION_PROGRAM_ID => &tables.account_compression,
+            _ => &tables.registry,
+        };
+        let decode = table.get(&disc).unwrap_or_else(|| panic!("{:?} missing from table", expected));
+        assert_eq!(decode(&disc), expected);
+        assert_eq!(parse_light_instruction(&program_id, &disc), expected);
+    }
+}
+
+#[test]
+fn test_eight_byte_discriminator_is_scoped_to_its_program() {
+    // An Invoke discriminator sent to the registry is not an Invoke
+    let program_id = PubkeyBase58::try_from(constants::LIGHT_REGISTRY_ID).unwrap();
+    let action = parse_light_instruction(&program_id, &constants::DISCRIMINATOR_INVOKE);
+    assert_eq!(
+        action,
+        LightProtocolAction::UnknownEightByte { discriminator: constants::DISCRIMINATOR_INVOKE }
+    );
+}
+
+/// InitCpiContextAccount for the mainnet v1 state tree and its output queue.
+fn init_cpi_context_fixture() -> Vec<u8> {
+    let tree = PubkeyBase58::try_from("smt1NamzXdq4AMqS2fS2F1i5KTYPZRhoHgWx38d8WsT").unwrap();
+    let queue = PubkeyBase58::try_from("nfq1NvQDJ2GEgnS8zt9prAe8rjjpAW1zFkrvZoBR148").unwrap();
+
+    let mut data = constants::DISCRIMINATOR_INIT_CPI_CONTEXT_ACCOUNT_INSTRUCTION.to_vec();
+    data.extend_from_slice(tree.as_bytes());
+    data.extend_from_slice(queue.as_bytes());
+    for len in [10u16, 10, 10, 20, 30] {
+        data.extend_from_slice(&len.to_le_bytes());
+    }
+    data
+}
+
+#[test]
+fn test_init_cpi_context_account_params_decoded() {
+    use crate::analysis::light_protocol::models::CpiContextAccountParams;
+
+    let program_id = PubkeyBase58::try_from(constants::LIGHT_SYSTEM_PROGRAM_ID).unwrap();
+    let action = parse_light_instruction(&program_id, &init_cpi_context_fixture());
+
+    let expected = CpiContextAccountParams {
+        associated_merkle_tree: PubkeyBase58::try_from("smt1NamzXdq4AMqS2fS2F1i5KTYPZRhoHgWx38d8WsT").unwrap(),
+        associated_queue: PubkeyBase58::try_from("nfq1NvQDJ2GEgnS8zt9prAe8rjjpAW1zFkrvZoBR148").unwrap(),
+        new_addresses_len: 10,
+        readonly_addresses_len: 10,
+        readonly_accounts_len: 10,
+        in_account_infos_len: 20,
+        out_account_infos_len: 30,
+    };
+    assert_eq!(action, LightProtocolAction::InitCpiContextAccount { params: Some(expected) });
+    assert!(action.description().contains("smt1NamzXdq4AMqS2fS2F1i5KTYPZRhoHgWx38d8WsT"));
+}
+
+#[test]
+fn test_init_cpi_context_account_layout_mismatch_is_bare() {
+    let program_id = PubkeyBase58::try_from(constants::LIGHT_SYSTEM_PROGRAM_ID).unwrap();
+    let bare = LightProtocolAction::InitCpiContextAccount { params: None };
+
+    let mut truncated = init_cpi_context_fixture();
+    truncated.pop();
+    assert_eq!(parse_light_instruction(&program_id, &truncated), bare);
+
+    let mut trailing = init_cpi_context_fixture();
+    trailing.push(0);
+    assert_eq!(parse_light_instruction(&program_id, &trailing), bare);
+}
@@ -1,32 +1,11 @@
 use crate::analysis::light_protocol::{LightProtocol, constants};
 use solana_tools_lite::extensions::ProtocolAnalyzer;
-use solana_tools_lite::models::analysis::{AnalysisWarning, PrivacyLevel, TxAnalysis};
+use solana_tools_lite::models::analysis::{AnalysisWarning, TxAnalysis};
 use solana_tools_lite::models::hash_base58::HashBase58;
 use solana_tools_lite::models::instruction::Instruction;
 use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
 use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
 
-fn empty_analysis() -> TxAnalysis {
-    TxAnalysis {
-        transfers: vec![],
-        base_fee_lamports: 0,
-        priority_fee_lamports: None,
-        total_fee_lamports: 0,
-        total_sol_send_by_signer: 0,
-        compute_unit_limit: None,
-        compute_unit_price_micro: None,
-        warnings: vec![],
-        message_version: "legacy",
-        privacy_level: PrivacyLevel::Public,
-        extension_actions: vec![],
-        extension_notices: vec![],
-        confidential_ops_count: 0,
-        storage_ops_count: 0,
-        is_fee_payer: false,
-        has_non_sol_assets: false,
-    }
-}
-
 #[test]
 fn test_malformed_instruction_too_short() {
     let analyzer = LightProtocol;
@@ -50,7 +29,7 @@ fn test_malformed_instruction_too_short() {
         instructions: vec![instr],
     });
     
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
     // Should add malformed instruction warning
@@ -85,7 +64,7 @@ fn test_invalid_discriminator() {
         instructions: vec![instr],
     });
     
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
     // Should create Unknown action
@@ -130,7 +109,7 @@ fn test_multiple_instructions_counting() {
         instructions,
     });
     
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
     // Should process all three instructions
@@ -167,7 +146,7 @@ fn test_signer_not_in_accounts() {
         instructions: vec![instr],
     });
     
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
     // Should add extension action and count it (privacy level depends on tx content, not signer role)
@@ -200,7 +179,7 @@ fn test_8byte_discriminator_in_compressed_token_program() {
         instructions: vec![instr],
     });
     
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
     // Should recognize Token Interface Transfer as confidential
@@ -208,3 +187,58 @@ fn test_8byte_discriminator_in_compressed_token_program() {
     assert_eq!(analysis.confidential_ops_count, 1);
     assert_eq!(analysis.storage_ops_count, 0);
 }
+
+#[test]
+fn test_privacy_impact_attributed_per_instruction() {
+    use solana_tools_lite::extensions::analysis::PrivacyImpact;
+
+    let analyzer = LightProtocol;
+    let program_id = PubkeyBase58::try_from(constants::COMPRESSED_TOKEN_PROGRAM_ID).unwrap();
+    let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
+    let system = PubkeyBase58::try_from("11111111111111111111111111111111").unwrap();
+
+    let instructions = vec![
+        Instruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: constants::DISCRIMINATOR_BATCH_COMPRESS.to_vec(),
+        },
+        // Not a Light instruction: no action, but it still occupies index 1
+        Instruction {
+            program_id_index: 2,
+            accounts: vec![0],
+            data: vec![0],
+        },
+        Instruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: vec![constants::DISCRIMINATOR_CTOKEN_MINT_TO],
+        },
+    ];
+
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 0,
+        },
+        account_keys: vec![signer.clone(), program_id, system],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions,
+    });
+
+    let mut analysis = TxAnalysis::default();
+    analyzer.analyze(&message, message.account_keys(), &signer, &mut analysis);
+
+    assert_eq!(
+        analysis.privacy_impacts_by_instruction(),
+        vec![(0, PrivacyImpact::StorageCompression), (2, PrivacyImpact::Confidential)]
+    );
+    // Aggregates are unchanged
+    assert_eq!(analysis.confidential_ops_count, 1);
+    assert_eq!(analysis.storage_ops_count, 1);
+
+    let views = analysis.to_serializable().extension_actions;
+    assert_eq!(views[1].instruction_index, Some(2));
+    assert_eq!(views[1].privacy_impact, PrivacyImpact::Confidential);
+}
@@ -168,6 +168,47 @@ fn test_parse_transfer2_complex() {
     }
 }
 
+#[test]
+fn test_parse_transfer2_lamports_only() {
+    // Pure compress/decompress of lamports: no compressions and no token data
+    let mut data = Vec::new();
+    data.push(constants::DISCRIMINATOR_TRANSFER2);
+    data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]); // fixed fields
+    data.push(0x00); // cpi_context: None
+    data.push(0x00); // compressions: None
+    data.push(0x00); // proof: None
+    data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]); // in_token_data: empty
+    data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]); // out_token_data: empty
+
+    // in_lamports: Some(Vec[5000])
+    data.push(0x01);
+    data.extend_from_slice(&[0x01, 0x00, 0x00, 0x00]);
+    data.extend_from_slice(&5000u64.to_le_bytes());
+
+    // out_lamports: Some(Vec[4000])
+    data.push(0x01);
+    data.extend_from_slice(&[0x01, 0x00, 0x00, 0x00]);
+    data.extend_from_slice(&4000u64.to_le_bytes());
+
+    let action = parse_light_instruction(&compressed_token_program(), &data);
+
+    match &action {
+        LightProtocolAction::Transfer2 { in_lamports, out_lamports, amount } => {
+            assert_eq!(*in_lamports, Some(5000));
+            assert_eq!(*out_lamports, Some(4000));
+            assert_eq!(*amount, None, "no token amount moves");
+        }
+        _ => panic!("Expected Transfer2 action, got {:?}", action),
+    }
+
+    let description = action.description();
+    assert_eq!(
+        description,
+        "Compressed Token - Lamports Transfer (5000 in_lamports, 4000 out_lamports)"
+    );
+    assert!(!description.contains("amount"));
+}
+
 #[test]
 fn test_parse_batch_compress_complex() {
     let mut data = Vec::new();
@@ -22,8 +22,17 @@ pub mod analysis;
 /// # Note
 /// 
 /// This function can be called multiple times safely - only the first call
-/// will register the analyzers (subsequent calls are no-ops).
+/// will register the analyzers (subsequent calls are no-ops). Use [`try_init`]
+/// to find out whether a given call did the registration.
 pub fn init() {
+    try_init();
+}
+
+/// Initialize all protocol analyzers, returning whether this call registered them.
+///
+/// Returns `false` when analyzers were already registered by an earlier `init()` /
+/// `try_init()` or a direct `registry::register` call; the earlier set stays active.
+pub fn try_init() -> bool {
     use std::sync::Arc;
     
     let analyzers: Vec<Arc<dyn solana_tools_lite::extensions::traits::ProtocolAnalyzer>> = vec![
@@ -32,7 +41,7 @@ pub fn init() {
         // Arc::new(analysis::arcium::Arcium),
     ];
     
-    solana_tools_lite::extensions::registry::register(analyzers);
+    solana_tools_lite::extensions::registry::try_register(analyzers)
 }
 
 pub use analysis::LightProtocolAction;
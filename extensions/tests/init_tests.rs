@@ -0,0 +1,12 @@
+use solana_tools_lite::extensions::registry;
+
+#[test]
+fn try_init_registers_only_once() {
+    assert!(registry::get_all_analyzers().is_empty());
+
+    assert!(solana_tools_lite_extensions::try_init());
+    assert_eq!(registry::get_all_analyzers()[0].name(), "Light Protocol");
+
+    assert!(!solana_tools_lite_extensions::try_init());
+    assert_eq!(registry::get_all_analyzers().len(), 1);
+}
@@ -1,77 +1,312 @@
 use serde_json;
 use solana_tools_lite::adapters::io_adapter::{
-    read_input_transaction, read_lookup_tables,
+    ReadOptions, read_input_message, read_input_transaction_as, read_lookup_tables,
 };
-use solana_tools_lite::handlers::analysis::{analyze_transaction, build_signing_summary};
+use solana_tools_lite::crypto::ed25519::signature_from_bytes;
+use solana_tools_lite::handlers::analysis::{
+    AnalysisConfig, analyze_transaction_with, build_signing_summary,
+};
+use serde::Serialize;
+use solana_tools_lite::models::analysis::{EXIT_CODE_CLEAN, Severity, TxAnalysis};
+use solana_tools_lite::models::rent::RentConfig;
 use solana_tools_lite::models::{InputTransaction, PubkeyBase58, Transaction};
+use solana_tools_lite::serde::LookupTableEntry;
+use std::collections::{BTreeMap, HashSet};
 use std::convert::TryFrom;
+use std::time::{Duration, Instant};
 
-use crate::flows::presenter::{Presentable, AnalysisPresenter};
+use crate::flows::presenter::analysis_presenter::order_warnings;
+use crate::flows::presenter::keys::KeyFormat;
+use crate::flows::presenter::{Presentable, AnalysisPresenter, MarkdownPresenter};
+use crate::models::cmds::{AnalysisFormat, ColorMode, InFmt, WarningOrder};
 use crate::shell::error::CliError;
 
+/// Input and analysis settings shared by the `analyze` flows.
+#[derive(Default)]
+pub struct AnalyzeOptions<'a> {
+    /// Path to the input file (when `None`, the adapter may read stdin).
+    pub input: Option<&'a str>,
+    /// Force the input encoding instead of auto-detection.
+    pub input_format: Option<InFmt>,
+    /// How the input transaction is read (`--strict-signatures`).
+    pub read: ReadOptions,
+    /// Path to a lookup tables file used to resolve v0 accounts.
+    pub lookup_tables_path: Option<&'a str>,
+    /// Input is a serialized message without signatures (Base64/Base58).
+    pub message_only: bool,
+    /// Program ids (Base58) whose "unknown program" warning is suppressed.
+    pub trusted_programs: &'a [String],
+    /// Drop the signer-role warnings (`TxAnalysis::drop_signer_check_warnings`).
+    pub ignore_signer_check: bool,
+    /// Analysis time budget in milliseconds; past it the result is partial.
+    pub time_budget_ms: Option<u64>,
+    /// Rent parameters for the rent-exempt minimum of created accounts.
+    pub rent: RentConfig,
+    /// Full or abbreviated keys in the text and Markdown output (`--short-keys`).
+    pub key_format: KeyFormat,
+    /// Order of the warnings in every output (`--sort-warnings`).
+    pub warning_order: WarningOrder,
+    /// Colors of the text summary (`--color`, resolved).
+    pub color: ColorMode,
+}
+
 /// Analyze-transaction flow: thin orchestrator around the analysis handler.
 ///
 /// Parameters
-/// - `input`: optional path to input file (when `None`, handler may read from stdin)
+/// - `opts`: input and analysis settings
 /// - `pubkey`: optional public key to analyze as (Base58); if not provided, uses first signer
-/// - `lookup_tables_path`: optional path to lookup tables file
 /// - `summary_json`: emit analysis summary as JSON to stdout
+/// - `format`: `Markdown` prints a Markdown report to stdout instead of the stderr summary
+///
+/// Returns the severity exit code of the analysis (`TxAnalysis::severity_exit_code`);
+/// the caller decides whether to exit with it (`--exit-code-by-severity`).
 pub fn execute(
-    input: Option<&str>,
+    opts: &AnalyzeOptions,
     pubkey: Option<&str>,
-    lookup_tables_path: Option<&str>,
     summary_json: bool,
-) -> Result<(), CliError> {
-    // 1) Read input transaction (file/stdin) via adapter
-    let input_tx: InputTransaction = read_input_transaction(input)?;
+    format: AnalysisFormat,
+) -> Result<i32, CliError> {
+    let (tx, analysis) = load_and_analyze(opts, pubkey)?;
+
+    if format == AnalysisFormat::Markdown {
+        MarkdownPresenter {
+            analysis: &analysis,
+            key_format: opts.key_format,
+            warning_order: opts.warning_order,
+        }
+        .present(false, false, false)?;
+        return Ok(analysis.severity_exit_code());
+    }
+
+    // 6) Present analysis summary to stderr
+    let analysis_presenter = AnalysisPresenter {
+        analysis: Some(&analysis),
+        summary_payload: None,
+        key_format: opts.key_format,
+        warning_order: opts.warning_order,
+        color: opts.color,
+    };
+
+    analysis_presenter.present(false, false, true)?;
+
+    // 7) Optionally emit JSON summary to stdout
+    if summary_json {
+        let summary = build_signing_summary(&tx, &analysis)?;
+
+        let payload = serde_json::to_string_pretty(&summary)
+            .map_err(|e| CliError::SummaryEncode(e.to_string()))?;
+
+        println!("{}", payload);
+    }
+
+    Ok(analysis.severity_exit_code())
+}
+
+/// Multi-signer variant of `execute` (repeated `--pubkey`): parses the transaction once,
+/// analyzes it as each of `pubkeys` and prints a JSON map keyed by public key to stdout.
+/// The returned exit code is the highest across all signers.
+pub fn execute_multi(opts: &AnalyzeOptions, pubkeys: &[String]) -> Result<i32, CliError> {
+    let analyses = analyze_per_signer(opts, pubkeys)?;
+
+    let views: BTreeMap<&str, _> = analyses
+        .iter()
+        .map(|(pubkey, analysis)| (pubkey.as_str(), analysis.to_serializable()))
+        .collect();
+    let payload = serde_json::to_string_pretty(&views)
+        .map_err(|e| CliError::SummaryEncode(e.to_string()))?;
+    println!("{}", payload);
+
+    Ok(analyses
+        .values()
+        .map(TxAnalysis::severity_exit_code)
+        .max()
+        .unwrap_or(EXIT_CODE_CLEAN))
+}
+
+/// Analyses of one transaction from the perspective of each of `pubkeys`, keyed by the
+/// Base58 public key. The input and lookup tables are read once and shared.
+pub fn analyze_per_signer(
+    opts: &AnalyzeOptions,
+    pubkeys: &[String],
+) -> Result<BTreeMap<String, TxAnalysis>, CliError> {
+    let signers = pubkeys
+        .iter()
+        .map(|pk| parse_pubkey(pk))
+        .collect::<Result<Vec<_>, _>>()?;
+    let loaded = load_input(opts)?;
+
+    Ok(signers
+        .into_iter()
+        .map(|signer| {
+            let analysis = loaded.analyze_as(&signer, opts.ignore_signer_check);
+            (signer.to_string(), analysis)
+        })
+        .collect())
+}
+
+/// Exit codes from `--count-only` stay below the sysexits range used for errors.
+pub const MAX_COUNT_EXIT_CODE: i32 = 63;
 
-    // 2) Convert to Transaction
-    let tx: Transaction = Transaction::try_from(input_tx)
-        .map_err(|e| CliError::Core(solana_tools_lite::ToolError::TransactionParse(e)))?;
+/// Metrics printed by `analyze --count-only`.
+#[derive(Serialize)]
+struct CountSummary {
+    warnings: usize,
+    critical: usize,
+    fee_lamports: u128,
+}
+
+/// Health-check variant of `execute`: prints only warning counts and the fee as JSON
+/// (no presentation) and returns the exit code, the number of critical warnings capped
+/// at `MAX_COUNT_EXIT_CODE`.
+pub fn execute_count_only(opts: &AnalyzeOptions, pubkey: Option<&str>) -> Result<i32, CliError> {
+    let (_, analysis) = load_and_analyze(opts, pubkey)?;
 
-    let message = &tx.message;
+    let critical = analysis
+        .warnings
+        .iter()
+        .filter(|w| w.severity() == Severity::Critical)
+        .count();
+    let counts = CountSummary {
+        warnings: analysis.warnings.len(),
+        critical,
+        fee_lamports: analysis.total_fee_lamports,
+    };
+
+    let payload =
+        serde_json::to_string(&counts).map_err(|e| CliError::SummaryEncode(e.to_string()))?;
+    println!("{}", payload);
+
+    Ok(critical.min(MAX_COUNT_EXIT_CODE as usize) as i32)
+}
+
+/// Steps 1-5 shared by `execute` and `execute_count_only`: read, pick the signer, analyze.
+fn load_and_analyze(
+    opts: &AnalyzeOptions,
+    pubkey: Option<&str>,
+) -> Result<(Transaction, TxAnalysis), CliError> {
+    // 1-2, 4) Read input and lookup tables
+    let loaded = load_input(opts)?;
 
     // 3) Determine the public key to analyze as
     let analyze_pubkey = if let Some(pk_str) = pubkey {
-        PubkeyBase58::try_from(pk_str)
-            .map_err(|e| CliError::Core(solana_tools_lite::ToolError::InvalidInput(
-                format!("Invalid pubkey: {}", e)
-            )))?
+        parse_pubkey(pk_str)?
     } else {
         // Use first signer from message header
-        let account_keys = message.account_keys();
-
-        account_keys
-            .get(0)
+        loaded
+            .tx
+            .message
+            .account_keys()
+            .first()
             .cloned()
             .ok_or_else(|| CliError::Core(solana_tools_lite::ToolError::InvalidInput(
                 "No accounts in message".to_string()
             )))?
     };
 
-    // 4) Read lookup tables if provided
-    let tables = lookup_tables_path.map(read_lookup_tables).transpose()?;
-
     // 5) Analyze the transaction
-    let analysis = analyze_transaction(message, &analyze_pubkey, tables.as_ref());
+    let analysis = loaded.analyze_as(&analyze_pubkey, opts.ignore_signer_check);
 
-    // 6) Present analysis summary to stderr
-    let analysis_presenter = AnalysisPresenter {
-        analysis: Some(&analysis),
-        summary_payload: None,
-    };
+    Ok((loaded.tx, analysis))
+}
 
-    analysis_presenter.present(false, false, true)?;
+/// Transaction, lookup tables and analysis limits, read once per run.
+struct LoadedInput {
+    tx: Transaction,
+    tables: Option<LookupTableEntry>,
+    allowlist: HashSet<PubkeyBase58>,
+    deadline: Option<Instant>,
+    rent: RentConfig,
+    warning_order: WarningOrder,
+}
 
-    // 7) Optionally emit JSON summary to stdout
-    if summary_json {
-        let summary = build_signing_summary(&tx, &analysis)?;
+/// Validate the trusted programs, then read the input (file/stdin) and lookup tables.
+fn load_input(opts: &AnalyzeOptions) -> Result<LoadedInput, CliError> {
+    let allowlist = parse_trusted_programs(opts.trusted_programs)?;
+    let deadline = opts
+        .time_budget_ms
+        .map(|ms| Instant::now() + Duration::from_millis(ms));
 
-        let payload = serde_json::to_string_pretty(&summary)
-            .map_err(|e| CliError::SummaryEncode(e.to_string()))?;
+    let tx = load_transaction(opts.input, opts.input_format, opts.read, opts.message_only)?;
+    let tables = opts.lookup_tables_path.map(read_lookup_tables).transpose()?;
 
-        println!("{}", payload);
+    Ok(LoadedInput {
+        tx,
+        tables,
+        allowlist,
+        deadline,
+        rent: opts.rent,
+        warning_order: opts.warning_order,
+    })
+}
+
+impl LoadedInput {
+    /// Analyze the loaded transaction as `signer`, with warnings in presentation order.
+    fn analyze_as(&self, signer: &PubkeyBase58, ignore_signer_check: bool) -> TxAnalysis {
+        let config = AnalysisConfig {
+            rent: self.rent,
+            ..Default::default()
+        };
+        let mut analysis = analyze_transaction_with(
+            &self.tx.message,
+            signer,
+            self.tables.as_ref(),
+            Some(&self.allowlist),
+            self.deadline,
+            &config,
+        );
+        order_warnings(&mut analysis, self.warning_order);
+        if ignore_signer_check {
+            analysis.drop_signer_check_warnings();
+        }
+        analysis
     }
+}
+
+/// Read the input as a transaction, or wrap a bare message (`message_only`) as an
+/// unsigned one with placeholder signatures.
+fn load_transaction(
+    input: Option<&str>,
+    input_format: Option<InFmt>,
+    read: ReadOptions,
+    message_only: bool,
+) -> Result<Transaction, CliError> {
+    let tx: Transaction = if message_only {
+        // Wrap the bare message as an unsigned transaction (placeholder signatures)
+        let message = read_input_message(input, input_format.map(Into::into), read)?;
+        let required = message.header().num_required_signatures as usize;
+        Transaction {
+            signatures: vec![signature_from_bytes(&[0u8; 64]); required],
+            message,
+        }
+    } else {
+        let input_tx: InputTransaction =
+            read_input_transaction_as(input, input_format.map(Into::into), read)?;
+        Transaction::try_from(input_tx)
+            .map_err(|e| CliError::Core(solana_tools_lite::ToolError::TransactionParse(e)))?
+    };
+    Ok(tx)
+}
+
+fn parse_pubkey(value: &str) -> Result<PubkeyBase58, CliError> {
+    PubkeyBase58::try_from(value).map_err(|e| {
+        CliError::Core(solana_tools_lite::ToolError::InvalidInput(format!(
+            "Invalid pubkey: {}",
+            e
+        )))
+    })
+}
 
-    Ok(())
+/// Parse `--trust-program` values into the analysis allowlist.
+pub(crate) fn parse_trusted_programs(values: &[String]) -> Result<HashSet<PubkeyBase58>, CliError> {
+    values
+        .iter()
+        .map(|value| {
+            PubkeyBase58::try_from(value.as_str()).map_err(|e| {
+                CliError::Core(solana_tools_lite::ToolError::InvalidInput(format!(
+                    "Invalid trusted program id '{}': {}",
+                    value, e
+                )))
+            })
+        })
+        .collect()
 }
@@ -5,11 +5,17 @@ use crate::shell::error::CliError;
 
 /// Base58 flow: delegates to the pure handler and presents the result.
 pub fn execute(action: &Base58Action, json: bool) -> Result<(), CliError> {
-    let result = match action {
-        Base58Action::Encode { input } => base58::encode(input)?,
-        Base58Action::Decode { input } => base58::decode(input)?,
-    };
+    match action {
+        Base58Action::Encode { input } => base58::encode(input)?.present(json, false, false)?,
+        Base58Action::Decode {
+            input,
+            as_transaction: false,
+        } => base58::decode(input)?.present(json, false, false)?,
+        Base58Action::Decode {
+            input,
+            as_transaction: true,
+        } => base58::decode_as_transaction(input)?.present(json, false, false)?,
+    }
 
-    result.present(json, false, false)?;
     Ok(())
 }
@@ -0,0 +1,17 @@
+use crate::models::cmds::CompletionShell;
+use crate::shell::cli::Cli;
+use clap::CommandFactory;
+
+/// Completions flow: prints the completion script for `shell` to stdout.
+pub fn execute(shell: CompletionShell) {
+    print!("{}", render(shell));
+}
+
+/// Completion script for `shell`, generated from the `Cli` definition.
+pub fn render(shell: CompletionShell) -> String {
+    let mut command = Cli::command();
+    let bin_name = command.get_name().to_string();
+    let mut buf = Vec::new();
+    clap_complete::generate(clap_complete::Shell::from(shell), &mut command, bin_name, &mut buf);
+    String::from_utf8_lossy(&buf).into_owned()
+}
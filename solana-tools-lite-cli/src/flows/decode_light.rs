@@ -0,0 +1,125 @@
+use extensions::analysis::light_protocol::constants::supported_programs;
+use extensions::analysis::light_protocol::parsing::parse_light_instruction;
+use extensions::LightProtocolAction;
+use serde::Serialize;
+use solana_tools_lite::bs58;
+use solana_tools_lite::data_encoding::{BASE64, HEXLOWER_PERMISSIVE};
+use solana_tools_lite::models::PubkeyBase58;
+use solana_tools_lite::ToolError;
+
+use crate::flows::presenter::Presentable;
+use crate::models::cmds::DataEncoding;
+use crate::shell::error::CliError;
+
+/// Parsed Light Protocol instruction, ready for presentation.
+#[derive(Debug, Serialize)]
+pub struct DecodeLightResult {
+    pub program_id: String,
+    pub data_len: usize,
+    /// One-line summary (same text the analyzer shows)
+    pub description: String,
+    /// Every field of the parsed action, or "unknown discriminator: 0x..."
+    pub action: String,
+}
+
+impl DecodeLightResult {
+    /// Plain-text rendering used by the presenter.
+    pub fn render_text(&self) -> String {
+        format!(
+            "Program: {}\nData:    {} bytes\nSummary: {}\n{}",
+            self.program_id, self.data_len, self.description, self.action
+        )
+    }
+}
+
+/// Decode-light flow: parse raw instruction data with the Light Protocol decoder.
+///
+/// Parameters
+/// - `program`: Light Protocol program id (Base58)
+/// - `data`: instruction data text
+/// - `encoding`: force the data encoding; otherwise `0x` means hex, then Base58, then Base64
+/// - `json`: emit the result as JSON
+pub fn execute(
+    program: &str,
+    data: &str,
+    encoding: Option<DataEncoding>,
+    json: bool,
+) -> Result<(), CliError> {
+    let result = decode(program, data, encoding)?;
+    result.present(json, false, false)
+}
+
+/// Decode `data` for `program` without printing anything.
+pub fn decode(
+    program: &str,
+    data: &str,
+    encoding: Option<DataEncoding>,
+) -> Result<DecodeLightResult, CliError> {
+    let program_id = PubkeyBase58::try_from(program)
+        .map_err(|e| invalid_input(format!("Invalid program id: {}", e)))?;
+
+    let light_programs = supported_programs().map_err(CliError::Core)?;
+    if !light_programs.contains(&program_id) {
+        return Err(invalid_input(format!(
+            "{} is not a Light Protocol program",
+            program_id
+        )));
+    }
+
+    let bytes = decode_data(data.trim(), encoding)?;
+    let action = parse_light_instruction(&program_id, &bytes);
+
+    Ok(DecodeLightResult {
+        program_id: program_id.to_string(),
+        data_len: bytes.len(),
+        description: action.description(),
+        action: format_action(&action),
+    })
+}
+
+fn decode_data(text: &str, encoding: Option<DataEncoding>) -> Result<Vec<u8>, CliError> {
+    let decode_hex = |s: &str| {
+        let digits = s.strip_prefix("0x").unwrap_or(s);
+        HEXLOWER_PERMISSIVE
+            .decode(digits.as_bytes())
+            .map_err(|e| invalid_input(format!("Invalid hex data: {}", e)))
+    };
+    let decode_base58 = |s: &str| {
+        bs58::decode(s)
+            .into_vec()
+            .map_err(|e| invalid_input(format!("Invalid Base58 data: {}", e)))
+    };
+    let decode_base64 = |s: &str| {
+        BASE64
+            .decode(s.as_bytes())
+            .map_err(|e| invalid_input(format!("Invalid Base64 data: {}", e)))
+    };
+
+    match encoding {
+        Some(DataEncoding::Hex) => decode_hex(text),
+        Some(DataEncoding::Base58) => decode_base58(text),
+        Some(DataEncoding::Base64) => decode_base64(text),
+        None if text.starts_with("0x") => decode_hex(text),
+        None => decode_base58(text).or_else(|_| {
+            decode_base64(text)
+                .map_err(|_| invalid_input("Data is not valid Base58, Base64 or 0x-hex".to_string()))
+        }),
+    }
+}
+
+fn format_action(action: &LightProtocolAction) -> String {
+    match action {
+        LightProtocolAction::Unknown { discriminator } => {
+            format!("unknown discriminator: 0x{:02x}", discriminator)
+        }
+        LightProtocolAction::UnknownEightByte { discriminator } => {
+            let hex: String = discriminator.iter().map(|b| format!("{:02x}", b)).collect();
+            format!("unknown discriminator: 0x{}", hex)
+        }
+        known => format!("{:#?}", known),
+    }
+}
+
+fn invalid_input(message: String) -> CliError {
+    CliError::Core(ToolError::InvalidInput(message))
+}
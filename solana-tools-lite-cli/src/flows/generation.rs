@@ -2,12 +2,13 @@ use crate::constants::DEFAULT_WALLET_FILENAME;
 use crate::flows::presenter::Presentable;
 use solana_tools_lite::adapters::io_adapter as io;
 use solana_tools_lite::handlers::generate;
-use solana_tools_lite::models::results::GenResult;
+use solana_tools_lite::errors::ToolError;
+use solana_tools_lite::models::results::{DeriveManyResult, GenResult};
 use std::path::{Path, PathBuf};
 use crate::shell::error::CliError;
 use serde_json;
 
-/// High-level generation flow: orchestrates handler call, file saving, and presentation.
+/// High-level generation flow: reads inputs, calls the pure handler, saves and presents.
 ///
 /// Parameters
 /// * `mnemonic_path`  – read mnemonic from file or stdin ("-"); when `None`, a new mnemonic is generated
@@ -24,7 +25,11 @@ pub fn execute(
     out_path: Option<&str>,
     force: bool,
 ) -> Result<(), CliError> {
-    let result = generate::handle(mnemonic_path, passphrase_path)?;
+    let mnemonic = mnemonic_path.map(io::read_mnemonic).transpose()?;
+    let passphrase = passphrase_path.map(io::read_passphrase).transpose()?;
+
+    let wallet = generate::handle(mnemonic.as_deref(), passphrase.as_deref(), true)?;
+    let result = GenResult::try_from(wallet)?;
     let saved_path = save_to_file(&result, out_path, force)?;
 
     let print_stderr = out_path.is_some();
@@ -33,6 +38,114 @@ pub fn execute(
     Ok(())
 }
 
+/// Generation flow for a raw BIP-39 seed: derives the wallet without a mnemonic.
+///
+/// Parameters
+/// * `seed_hex_path` – read the 64-byte seed as hex from file or stdin ("-")
+/// * the remaining parameters behave as in `execute`
+pub fn execute_from_seed(
+    seed_hex_path: &str,
+    json: bool,
+    show_secret: bool,
+    out_path: Option<&str>,
+    force: bool,
+) -> Result<(), CliError> {
+    let seed_hex = io::read_seed_hex(seed_hex_path)?;
+
+    let wallet = generate::handle_seed(&seed_hex, true)?;
+    let result = GenResult::try_from(wallet)?;
+    let saved_path = save_to_file(&result, out_path, force)?;
+
+    let print_stderr = out_path.is_some();
+    print_result(&result, json, show_secret, print_stderr, &saved_path)?;
+
+    Ok(())
+}
+
+/// Derive-only flow: print the address for an existing mnemonic without saving anything.
+///
+/// Parameters
+/// * `mnemonic_path`  – read mnemonic from file or stdin ("-")
+/// * `passphrase_path` – read BIP‑39 passphrase from file or stdin ("-"); when `None`, uses empty passphrase
+/// * `json`           – print result as JSON (`--json`)
+pub fn execute_derive_only(
+    mnemonic_path: &str,
+    passphrase_path: Option<&str>,
+    json: bool,
+) -> Result<(), CliError> {
+    let result = generate::derive_only(mnemonic_path, passphrase_path)?;
+    result.present(json, false, false)?;
+    Ok(())
+}
+
+/// Multi-account generation flow: derives accounts `0..count` and saves one wallet per account.
+///
+/// Parameters
+/// * `mnemonic_path`  – read mnemonic from file or stdin ("-")
+/// * `passphrase_path` – read BIP‑39 passphrase from file or stdin ("-"); when `None`, uses empty passphrase
+/// * `count`          – number of accounts to derive
+/// * `json`           – print result as JSON (`--json`)
+/// * `show_secret`    – include secret keys in the printed table (`--unsafe-show-secret`)
+/// * `out_dir`        – directory for the `wallet-<index>.json` files (current directory when `None`)
+/// * `force`          – override wallet files that exist
+pub fn execute_many(
+    mnemonic_path: &str,
+    passphrase_path: Option<&str>,
+    count: u32,
+    json: bool,
+    show_secret: bool,
+    out_dir: Option<&str>,
+    force: bool,
+) -> Result<(), CliError> {
+    let mnemonic = io::read_mnemonic(mnemonic_path)?;
+    let passphrase = passphrase_path.map(io::read_passphrase).transpose()?;
+
+    let mut result = generate::derive_accounts(&mnemonic, passphrase.as_deref(), count, true)?;
+    save_accounts(&mut result, out_dir, force)?;
+
+    result.present(json, show_secret, out_dir.is_some())?;
+    Ok(())
+}
+
+/// Derive-only variant of `execute_many`: prints the table without secrets or files.
+pub fn execute_derive_many(
+    mnemonic_path: &str,
+    passphrase_path: Option<&str>,
+    count: u32,
+    json: bool,
+) -> Result<(), CliError> {
+    let mnemonic = io::read_mnemonic(mnemonic_path)?;
+    let passphrase = passphrase_path.map(io::read_passphrase).transpose()?;
+
+    let result = generate::derive_accounts(&mnemonic, passphrase.as_deref(), count, false)?;
+    result.present(json, false, false)?;
+    Ok(())
+}
+
+fn save_accounts(
+    result: &mut DeriveManyResult,
+    out_dir: Option<&str>,
+    force: bool,
+) -> Result<(), CliError> {
+    let dir = Path::new(out_dir.unwrap_or("."));
+    if !dir.is_dir() {
+        return Err(ToolError::InvalidInput(format!(
+            "--output must be an existing directory with --count: {}",
+            dir.display()
+        ))
+        .into());
+    }
+
+    for account in &mut result.accounts {
+        let target = dir.join(format!("wallet-{}.json", account.index));
+        let payload = serde_json::to_string_pretty(account)
+            .map_err(|e| CliError::PresentationEncode(e.to_string()))?;
+        io::write_secret_file(&target, &payload, force)?;
+        account.output_path = Some(target.display().to_string());
+    }
+    Ok(())
+}
+
 fn save_to_file(
     result: &GenResult,
     out_path: Option<&str>,
@@ -0,0 +1,39 @@
+use solana_tools_lite::adapters::io_adapter::{ReadOptions, read_input_transaction_as};
+use solana_tools_lite::errors::ToolError;
+use solana_tools_lite::handlers::inspect;
+use solana_tools_lite::models::Transaction;
+use solana_tools_lite::models::network::Network;
+
+use crate::flows::presenter::keys::KeyFormat;
+use crate::flows::presenter::{InspectPresenter, Presentable};
+use crate::models::cmds::InFmt;
+use crate::shell::error::CliError;
+
+/// Inspect flow: decode a transaction and print its structure (no analysis).
+///
+/// Parameters
+/// - `input`: optional path to input file (stdin when `None` or `Some("-")`)
+/// - `input_format`: force the input encoding instead of auto-detection
+/// - `read`: how the transaction is read (`--strict-signatures`)
+/// - `network`: cluster whose program labels are shown
+/// - `key_format`: full or abbreviated keys in the text output
+/// - `json`: print the structure as pretty JSON
+pub fn execute(
+    input: Option<&str>,
+    input_format: Option<InFmt>,
+    read: ReadOptions,
+    network: Network,
+    key_format: KeyFormat,
+    json: bool,
+) -> Result<(), CliError> {
+    let input_tx = read_input_transaction_as(input, input_format.map(Into::into), read)?;
+    let tx = Transaction::try_from(input_tx).map_err(ToolError::TransactionParse)?;
+
+    let result = inspect::handle(&tx, network);
+    InspectPresenter {
+        result: &result,
+        key_format,
+    }
+    .present(json, false, false)?;
+    Ok(())
+}
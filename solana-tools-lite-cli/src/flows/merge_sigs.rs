@@ -0,0 +1,55 @@
+use solana_tools_lite::adapters::io_adapter::{
+    ReadOptions, read_input_transaction_as, write_signed_transaction,
+};
+use solana_tools_lite::errors::ToolError;
+use solana_tools_lite::models::Transaction;
+use solana_tools_lite::serde::fmt::OutputFormat;
+
+use crate::models::cmds::OutFmt;
+use crate::shell::error::CliError;
+
+/// Merge-signatures flow: combine signed copies of one transaction into a single transaction.
+///
+/// Parameters
+/// - `inputs`: paths to at least two signed copies (the first one sets the default output format)
+/// - `read`: how the copies are read (`--strict-signatures`)
+/// - `output`: optional output path (stdout when `None` or `Some("-")` via adapter)
+/// - `pretty_json`: pretty JSON when output format is JSON
+/// - `out_override`: force output format (json|base64|base58); otherwise mirrors the first input
+/// - `force`: overwrite the output file when it exists
+pub fn execute(
+    inputs: &[String],
+    read: ReadOptions,
+    output: Option<&str>,
+    pretty_json: bool,
+    out_override: Option<OutFmt>,
+    force: bool,
+) -> Result<(), CliError> {
+    // Same rule as the clap definition (`num_args = 2..`)
+    if inputs.len() < 2 {
+        return Err(ToolError::InvalidInput("merge-sigs requires at least two inputs".into()).into());
+    }
+    let (first, rest) = (&inputs[0], &inputs[1..]);
+
+    let first_tx = read_input_transaction_as(Some(first), None, read)?;
+    let default_format = first_tx.default_output_format(pretty_json);
+    let mut tx = Transaction::try_from(first_tx).map_err(ToolError::TransactionParse)?;
+
+    for path in rest {
+        let other = Transaction::try_from(read_input_transaction_as(Some(path), None, read)?)
+            .map_err(ToolError::TransactionParse)?;
+        tx.merge_signatures(&other)?;
+    }
+
+    let chosen_format = match out_override {
+        Some(OutFmt::Json) => OutputFormat::Json {
+            pretty: pretty_json,
+        },
+        Some(OutFmt::Base64) => OutputFormat::Base64,
+        Some(OutFmt::Base58) => OutputFormat::Base58,
+        None => default_format,
+    };
+
+    write_signed_transaction(&tx, chosen_format, output, force)?;
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use serde::Serialize;
+use solana_tools_lite::adapters::io_adapter::{ReadOptions, read_input_transaction_as};
+use solana_tools_lite::crypto::hash::{HashAlgo, digest};
+use solana_tools_lite::errors::ToolError;
+use solana_tools_lite::models::Transaction;
+use solana_tools_lite::utils::hex_encode;
+
+use crate::models::cmds::{HashAlgoArg, InFmt};
+use crate::shell::error::CliError;
+
+/// `message-hash --json` output.
+#[derive(Serialize)]
+struct MessageHashOutput {
+    algorithm: &'static str,
+    digest: String,
+}
+
+/// Message-hash flow: digest of the transaction's `message_signing_bytes`, printed as hex
+/// (or `{algorithm, digest}` JSON with `json`) to stdout.
+///
+/// Parameters
+/// - `input`: optional path to input file (stdin when `None` or `Some("-")`)
+/// - `input_format`: force the input encoding instead of auto-detection
+/// - `read`: how the transaction is read (`--strict-signatures`)
+/// - `hash_algo`: digest algorithm (sha256 by default on the command line)
+/// - `json`: print `{algorithm, digest}` as JSON
+pub fn execute(
+    input: Option<&str>,
+    input_format: Option<InFmt>,
+    read: ReadOptions,
+    hash_algo: HashAlgoArg,
+    json: bool,
+) -> Result<(), CliError> {
+    let algo = HashAlgo::from(hash_algo);
+    let digest = message_hash(input, input_format, read, algo)?;
+
+    if json {
+        let output = MessageHashOutput {
+            algorithm: algo.name(),
+            digest,
+        };
+        let payload = serde_json::to_string_pretty(&output)
+            .map_err(|e| CliError::PresentationEncode(e.to_string()))?;
+        println!("{}", payload);
+    } else {
+        println!("{}", digest);
+    }
+    Ok(())
+}
+
+/// Hex digest of the message bytes of the transaction read from `input`.
+pub fn message_hash(
+    input: Option<&str>,
+    input_format: Option<InFmt>,
+    read: ReadOptions,
+    algo: HashAlgo,
+) -> Result<String, CliError> {
+    let input_tx = read_input_transaction_as(input, input_format.map(Into::into), read)?;
+    let tx = Transaction::try_from(input_tx).map_err(ToolError::TransactionParse)?;
+    Ok(hex_encode(&digest(algo, &tx.message_signing_bytes())))
+}
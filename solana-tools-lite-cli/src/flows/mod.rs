@@ -1,6 +1,12 @@
 pub mod analyze;
 pub mod base58;
+pub mod completions;
+#[cfg(feature = "protocol-extensions")]
+pub mod decode_light;
 pub mod generation;
+pub mod inspect;
+pub mod merge_sigs;
+pub mod message_hash;
 pub mod presenter;
 pub mod sign;
 pub mod sign_tx;
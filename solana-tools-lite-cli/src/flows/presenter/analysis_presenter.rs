@@ -1,15 +1,44 @@
 //! Presentation rules for transaction signing summaries.
 
-use crate::flows::presenter::{emit_line, Presentable};
+use crate::flows::presenter::color::{paint, Style};
+use crate::flows::presenter::keys::KeyFormat;
+use crate::flows::presenter::{emit_line, emit_styled_line, Presentable};
+use crate::models::cmds::{ColorMode, WarningOrder};
 use crate::shell::error::CliError;
 use solana_tools_lite::constants::compute_budget;
-use solana_tools_lite::models::analysis::{AnalysisWarning, TokenProgramKind, TxAnalysis};
-use solana_tools_lite::utils::format_sol;
+use solana_tools_lite::models::analysis::{
+    AnalysisWarning, PrecompileKind, Severity, TokenProgramKind, TokenSetupAction, TxAnalysis,
+    WsolUnwrap,
+};
+use solana_tools_lite::handlers::analysis::priority_fee_for;
+use solana_tools_lite::utils::{format_micro_lamports, format_sol};
+
+/// Put the analysis warnings in `order`; call before building JSON summaries from it.
+pub fn order_warnings(analysis: &mut TxAnalysis, order: WarningOrder) {
+    if order == WarningOrder::Severity {
+        analysis.sort_warnings();
+    }
+}
+
+/// The analysis warnings in `order`, for text presenters.
+pub(crate) fn ordered_warnings(analysis: &TxAnalysis, order: WarningOrder) -> Vec<&AnalysisWarning> {
+    let mut warnings: Vec<&AnalysisWarning> = analysis.warnings.iter().collect();
+    if order == WarningOrder::Severity {
+        warnings.sort_by_cached_key(|w| w.order_key());
+    }
+    warnings
+}
 
 /// Bundles analysis and an optional JSON summary payload.
 pub struct AnalysisPresenter<'a> {
     pub analysis: Option<&'a TxAnalysis>,
     pub summary_payload: Option<&'a str>,
+    /// Full or abbreviated keys in the text summary (`--short-keys`).
+    pub key_format: KeyFormat,
+    /// Order of the listed warnings (`--sort-warnings`).
+    pub warning_order: WarningOrder,
+    /// Whether the text summary is colored (`--color`, resolved once).
+    pub color: ColorMode,
 }
 
 impl Presentable for AnalysisPresenter<'_> {
@@ -20,7 +49,7 @@ impl Presentable for AnalysisPresenter<'_> {
         to_stderr: bool,
     ) -> Result<(), CliError> {
         if let Some(analysis) = self.analysis {
-            emit_summary(analysis);
+            emit_summary(analysis, self.key_format, self.warning_order, self.color);
         }
         
         if json {
@@ -32,22 +61,29 @@ impl Presentable for AnalysisPresenter<'_> {
     }
 }
 
-fn emit_summary(analysis: &TxAnalysis) {
+fn emit_summary(analysis: &TxAnalysis, keys: KeyFormat, order: WarningOrder, color: ColorMode) {
     for (i, t) in analysis.transfers.iter().enumerate() {
         eprintln!("==================================================");
         eprintln!("Instruction #{}: System Program (Transfer)", i + 1);
         eprintln!(
-            "  From:   {}{}",
-            t.from,
-            if t.from_is_signer { " (signer)" } else { "" }
+            "  From:   {}{}{}",
+            keys.key_str(&t.from),
+            if t.from_is_signer { " (signer)" } else { "" },
+            if t.from_writable { "" } else { " (readonly)" }
+        );
+        eprintln!(
+            "  To:     {}{}{}",
+            keys.key_str(&t.to),
+            if t.to_is_fee_payer { " (fee payer)" } else { "" },
+            if t.to_writable { "" } else { " (readonly)" }
         );
-        eprintln!("  To:     {}", t.to);
         eprintln!("  Amount: {} ({} lamports)", format_sol(t.lamports as u128), t.lamports);
         // eprintln!("          ({} lamports)", t.lamports);
     }
 
     eprintln!("--------------------------------------------------");
     eprintln!("TRANSACTION SUMMARY");
+    eprintln!("Blockhash:      {}", analysis.recent_blockhash);
     eprintln!("Non-SOL Assets: {}", if analysis.has_non_sol_assets { "Yes (SPL/Token-2022 detected)" } else { "No" });
     eprintln!(
         "Network Fee:    {} ({} lamports)",
@@ -56,7 +92,8 @@ fn emit_summary(analysis: &TxAnalysis) {
     );
     
     if analysis.is_fee_payer {
-        eprintln!("                !!! YOU ARE THE FEE PAYER !!!");
+        let alert = paint("!!! YOU ARE THE FEE PAYER !!!", Style::Danger, color, true);
+        emit_styled_line(&format!("                {}", alert), color, true);
     }
     
     if let Some((pf, est)) = analysis.priority_fee_lamports {
@@ -79,13 +116,7 @@ fn emit_summary(analysis: &TxAnalysis) {
     }
     
     if let Some(price) = analysis.compute_unit_price_micro {
-        let limit = analysis
-            .compute_unit_limit
-            .unwrap_or(compute_budget::DEFAULT_COMPUTE_UNIT_LIMIT);
-        eprintln!(
-            "Compute Budget: price={} micro-lamports, limit={}",
-            price, limit
-        );
+        eprintln!("Compute Budget: {}", compute_budget_text(price, analysis.compute_unit_limit));
     }
     let total_cost = analysis.total_fee_lamports + analysis.total_sol_send_by_signer;
     
@@ -95,6 +126,24 @@ fn emit_summary(analysis: &TxAnalysis) {
             format_sol(analysis.total_sol_send_by_signer as u128),
             analysis.total_sol_send_by_signer
         );
+        // Break the total down when the signer pays more than one recipient
+        if analysis.signer_outflows_by_recipient.len() > 1 {
+            let mut outflows: Vec<_> = analysis.signer_outflows_by_recipient.iter().collect();
+            outflows.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+            for (recipient, lamports) in outflows {
+                eprintln!("  to {}: {}", keys.key_str(recipient), format_sol(*lamports as u128));
+            }
+        }
+    }
+
+    // Wrapped and unwrapped back to the signer in this transaction
+    let returned = analysis.sol_returned_to_signer();
+    if returned > 0 {
+        eprintln!(
+            "NET SEND:       {} ({} returned by wSOL unwrap)",
+            format_sol(analysis.net_sol_send_by_signer()),
+            format_sol(returned)
+        );
     }
     
     eprintln!("MAX TOTAL COST: {}", format_sol(total_cost));
@@ -117,11 +166,60 @@ fn emit_summary(analysis: &TxAnalysis) {
         eprintln!("--------------------------------------------------");
     }
 
+    // Token mint/account initialization (context)
+    if !analysis.token_setup.is_empty() {
+        eprintln!("TOKEN SETUP:");
+        for action in &analysis.token_setup {
+            eprintln!("  - {}", token_setup_to_message(action, keys));
+        }
+        eprintln!("--------------------------------------------------");
+    }
+
+    if !analysis.created_accounts.is_empty() {
+        eprintln!("CREATED ACCOUNTS:");
+        for created in &analysis.created_accounts {
+            eprintln!(
+                "  - {} ({} bytes, owner {}): funded {}, rent-exempt minimum {}",
+                keys.key(&created.account),
+                created.space,
+                keys.key(&created.owner),
+                format_sol(created.lamports as u128),
+                format_sol(created.rent_exempt_minimum as u128)
+            );
+        }
+        eprintln!("--------------------------------------------------");
+    }
+
+    if !analysis.wsol_unwraps.is_empty() {
+        eprintln!("WRAPPED SOL UNWRAPS:");
+        for unwrap in &analysis.wsol_unwraps {
+            eprintln!("  - {}", wsol_unwrap_to_message(unwrap, keys));
+        }
+        eprintln!("--------------------------------------------------");
+    }
+
+    if !analysis.anchor_actions.is_empty() {
+        eprintln!("ANCHOR INSTRUCTIONS:");
+        for action in &analysis.anchor_actions {
+            eprintln!("  - {} (program {})", action.ix_name, keys.key(&action.program));
+        }
+        eprintln!("--------------------------------------------------");
+    }
+
     // Extension Protocol Actions
     if !analysis.extension_actions.is_empty() {
         eprintln!("EXTENSION PROTOCOLS DETECTED:");
         for action in &analysis.extension_actions {
-            eprintln!("  - {}: {}", action.protocol_name(), action.description());
+            match action.instruction_index() {
+                Some(index) => eprintln!(
+                    "  - instruction #{}: {:?} - {}: {}",
+                    index + 1,
+                    action.privacy_impact(),
+                    action.protocol_name(),
+                    action.description()
+                ),
+                None => eprintln!("  - {}: {}", action.protocol_name(), action.description()),
+            }
         }
         eprintln!("--------------------------------------------------");
     }
@@ -135,21 +233,99 @@ fn emit_summary(analysis: &TxAnalysis) {
     }
 
     if !analysis.warnings.is_empty() {
-        eprintln!("WARNINGS:");
-        for w in &analysis.warnings {
-            eprintln!("- {}", warning_to_message(w));
+        emit_styled_line(&paint("WARNINGS:", Style::Header, color, true), color, true);
+        for w in ordered_warnings(analysis, order) {
+            let text = warning_to_message(w, keys);
+            let message = match warning_style(w) {
+                Some(style) => paint(&text, style, color, true),
+                None => text,
+            };
+            emit_styled_line(&format!("- {}", message), color, true);
         }
         eprintln!("--------------------------------------------------");
     }
 }
 
-fn warning_to_message(warning: &AnalysisWarning) -> String {
+/// Compute Budget price in both units plus the limit and the resulting priority fee
+/// (`price_micro * limit / 1_000_000`); the default limit applies when none is set.
+pub(crate) fn compute_budget_text(price_micro: u64, limit: Option<u32>) -> String {
+    let limit = limit.unwrap_or(compute_budget::DEFAULT_COMPUTE_UNIT_LIMIT);
+    format!(
+        "price={} micro-lamports/CU ({} lamports/CU), limit={} CU, priority fee={} lamports",
+        price_micro,
+        format_micro_lamports(price_micro),
+        limit,
+        priority_fee_for(price_micro, limit)
+    )
+}
+
+pub(crate) fn wsol_unwrap_to_message(unwrap: &WsolUnwrap, keys: KeyFormat) -> String {
+    let destination = if unwrap.to_signer {
+        "you".to_string()
+    } else {
+        keys.key(&unwrap.destination)
+    };
+    let mut message = format!(
+        "Close wSOL account {}: its SOL balance goes to {}",
+        keys.key(&unwrap.account),
+        destination
+    );
+    if unwrap.wrapped_lamports > 0 {
+        message.push_str(&format!(
+            " (includes {} wrapped in this transaction)",
+            format_sol(unwrap.wrapped_lamports as u128)
+        ));
+    }
+    message
+}
+
+pub(crate) fn token_setup_to_message(action: &TokenSetupAction, keys: KeyFormat) -> String {
+    match action {
+        TokenSetupAction::InitializeMint {
+            mint,
+            decimals,
+            mint_authority,
+            freeze_authority,
+        } => format!(
+            "Initialize mint {} ({} decimals, mint authority {}, freeze authority {})",
+            keys.key(mint),
+            decimals,
+            keys.key(mint_authority),
+            freeze_authority
+                .as_ref()
+                .map(|pk| keys.key(pk))
+                .unwrap_or_else(|| "none".to_string())
+        ),
+        TokenSetupAction::InitializeAccount { account, mint, owner } => format!(
+            "Initialize token account {} for mint {} (owner {})",
+            keys.key(account),
+            keys.key(mint),
+            keys.key(owner)
+        ),
+    }
+}
+
+fn warning_style(warning: &AnalysisWarning) -> Option<Style> {
+    match warning.severity() {
+        Severity::Critical => Some(Style::Danger),
+        Severity::Warn => Some(Style::Warning),
+        Severity::Info => None,
+    }
+}
+
+pub(crate) fn warning_to_message(warning: &AnalysisWarning, keys: KeyFormat) -> String {
     match warning {
+        AnalysisWarning::UnusedLookupTable { table } => {
+            format!(
+                "Lookup table {} is listed in the tables file but not used by this transaction; check that --tables matches it",
+                keys.key(table)
+            )
+        }
         AnalysisWarning::LookupTableNotProvided => {
             "Address table lookups present but lookup table was not provided; some accounts may be unresolved".to_string()
         }
         AnalysisWarning::LookupTableMissing(key) => {
-            format!("Lookup table {} missing or incomplete; some accounts may be unresolved", key)
+            format!("Lookup table {} missing or incomplete; some accounts may be unresolved", keys.key(key))
         }
         AnalysisWarning::TokenTransferDetected(kind) => {
             let label = match kind {
@@ -162,12 +338,108 @@ fn warning_to_message(warning: &AnalysisWarning) -> String {
                 label
             )
         }
+        AnalysisWarning::TokenAccountClosedToOther { destination } => {
+            format!("Token account is closed and its rent is sent to {}, which is not your key", keys.key(destination))
+        }
+        AnalysisWarning::SetAuthorityToSelf { authority_type } => {
+            format!("Token {:?} authority is assigned to your key", authority_type)
+        }
+        AnalysisWarning::SetAuthorityToOther {
+            authority_type,
+            new_authority,
+        } => {
+            format!(
+                "Token {:?} authority is handed to {}, which is not your key",
+                authority_type,
+                keys.key(new_authority)
+            )
+        }
+        AnalysisWarning::TokenDelegateApproved { delegate, amount } => {
+            format!(
+                "Token approval lets {} move up to {} base units from your token account",
+                keys.key(delegate),
+                amount
+            )
+        }
+        AnalysisWarning::StakeWithdrawToOther { recipient, lamports } => {
+            format!(
+                "Stake withdrawal of {} lamports is sent to {}, which is neither your key nor the withdraw authority",
+                lamports,
+                keys.key(recipient)
+            )
+        }
+        AnalysisWarning::PriorityFeeEstimated { assumed_limit } => {
+            format!(
+                "Priority fee is an estimate: no compute unit limit is set, assuming the default {} CU (the actual fee may differ)",
+                assumed_limit
+            )
+        }
+        AnalysisWarning::MultisigProgramDetected { program } => {
+            format!(
+                "Multisig program {} is invoked; your signature may approve a vault transaction rather than execute it directly",
+                keys.key(program)
+            )
+        }
+        AnalysisWarning::InstructionNoAccounts { instruction_index } => {
+            format!(
+                "Instruction #{} passes no accounts; most programs need at least one (possibly malformed)",
+                instruction_index + 1
+            )
+        }
+        AnalysisWarning::DuplicateAccountKey { key } => {
+            format!(
+                "Account {} is listed more than once; the network rejects such transactions and the analysis may attribute roles incorrectly",
+                keys.key(key)
+            )
+        }
+        AnalysisWarning::InstructionDataTruncatedForAnalysis {
+            instruction_index,
+            data_len,
+            scanned_len,
+        } => {
+            format!(
+                "Instruction #{} carries {} bytes of data; only the first {} were decoded, so its details may be incomplete",
+                instruction_index + 1,
+                data_len,
+                scanned_len
+            )
+        }
+        AnalysisWarning::AnalysisTruncatedByDeadline => {
+            "Analysis stopped at the time budget; the results above are incomplete".to_string()
+        }
+        AnalysisWarning::AccountOrderingSuspect { index } => {
+            format!(
+                "Account #{} is out of the canonical order for its role; the network may reject this transaction or it may be mis-analyzed",
+                index
+            )
+        }
+        AnalysisWarning::TransactionTooLarge { size } => {
+            format!(
+                "Transaction is {} bytes, over the 1232-byte packet limit; the network will reject it",
+                size
+            )
+        }
         AnalysisWarning::UnknownProgram { program_id } => {
-            format!("Unknown program encountered: {}", program_id)
+            format!("Unknown program encountered: {}", keys.key(program_id))
         }
         AnalysisWarning::SignerNotRequired => {
             "!!! SECURITY WARNING !!! Your signature is NOT REQUIRED for this transaction. This might be a phishing attempt if you were asked to sign it.".to_string()
         }
+        AnalysisWarning::SignerNotReferencedByInstructions => {
+            "Your signature is required, but no instruction uses your account; you may only be paying the fee or acting as a dummy signer.".to_string()
+        }
+        AnalysisWarning::SignerAccountCreatedAndDebited { owner } => {
+            format!("This transaction creates your account (owned by {}) and then moves funds out of it; the account you sign for is not a wallet you already hold.", keys.key(owner))
+        }
+        AnalysisWarning::SignerFromLookupTable => {
+            "Your key appears only via an address lookup table. Lookup table accounts can never sign, so this signature cannot be valid.".to_string()
+        }
+        AnalysisWarning::SignerKeyOffCurve { index } => {
+            format!("Required signer #{} is not a valid Ed25519 public key (possibly a PDA); it can never sign, so this transaction cannot be valid.", index)
+        }
+        AnalysisWarning::FeePayerNotSignable { fee_payer } => {
+            format!("Fee payer {} is not a valid Ed25519 public key (likely a PDA); it can never sign or pay fees, so this transaction cannot be valid.", keys.key(fee_payer))
+        }
         AnalysisWarning::CpiLimit => {
             "Analysis limited to top-level instructions. CPI (Cross-Program Invocations) not analyzed.".to_string()
         }
@@ -177,5 +449,15 @@ fn warning_to_message(warning: &AnalysisWarning) -> String {
         AnalysisWarning::MalformedInstruction => {
             "One or more protocol instructions are malformed (too short or corrupted data)".to_string()
         }
+        AnalysisWarning::RepeatedInstruction { count } => {
+            format!("Identical instruction repeated {} times. This pattern is common in drainer transactions.", count)
+        }
+        AnalysisWarning::PrecompileSignatureInstruction { kind } => {
+            let label = match kind {
+                PrecompileKind::Ed25519 => "Ed25519",
+                PrecompileKind::Secp256k1 => "Secp256k1",
+            };
+            format!("{} signature verification precompile included; the transaction checks an embedded signature (common in oracle/relayer flows)", label)
+        }
     }
 }
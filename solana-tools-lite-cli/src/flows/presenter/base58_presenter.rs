@@ -2,7 +2,7 @@
 
 use crate::flows::presenter::{emit_line, pretty_print_json, Presentable};
 use crate::shell::error::CliError;
-use solana_tools_lite::models::results::Base58Result;
+use solana_tools_lite::models::results::{Base58Result, Base58TransactionResult};
 
 impl Presentable for Base58Result {
     fn present(
@@ -19,3 +19,20 @@ impl Presentable for Base58Result {
         Ok(())
     }
 }
+
+impl Presentable for Base58TransactionResult {
+    fn present(
+        &self,
+        json: bool,
+        _show_secret: bool,
+        to_stderr: bool,
+    ) -> Result<(), CliError> {
+        match (json, &self.transaction, &self.output) {
+            (true, _, _) => pretty_print_json(self, to_stderr)?,
+            (false, Some(tx), _) => pretty_print_json(tx, to_stderr)?,
+            (false, None, Some(text)) => emit_line(text, to_stderr),
+            (false, None, None) => {}
+        }
+        Ok(())
+    }
+}
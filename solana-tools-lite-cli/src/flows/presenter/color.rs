@@ -0,0 +1,88 @@
+//! Terminal color handling shared by all presenters.
+//!
+//! The mode is resolved once at startup (`--color` / `NO_COLOR`) and handed to the
+//! presenters that style their output.
+
+use crate::models::cmds::ColorMode;
+use std::borrow::Cow;
+use std::io::IsTerminal;
+
+/// Text styles used by presenters.
+#[derive(Clone, Copy, Debug)]
+pub enum Style {
+    /// Bold red, for security-critical lines.
+    Danger,
+    /// Yellow, for regular warnings.
+    Warning,
+    /// Bold, for section headers.
+    Header,
+}
+
+impl Style {
+    fn code(self) -> &'static str {
+        match self {
+            Style::Danger => "\x1b[1;31m",
+            Style::Warning => "\x1b[33m",
+            Style::Header => "\x1b[1m",
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+/// Whether colors should be written to the given stream (`auto` checks for a TTY).
+pub fn color_enabled(mode: ColorMode, to_stderr: bool) -> bool {
+    match mode {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => {
+            if to_stderr {
+                std::io::stderr().is_terminal()
+            } else {
+                std::io::stdout().is_terminal()
+            }
+        }
+    }
+}
+
+/// Wrap `text` in the ANSI codes for `style` when `mode` enables colors for the stream.
+pub fn paint(text: &str, style: Style, mode: ColorMode, to_stderr: bool) -> String {
+    if color_enabled(mode, to_stderr) {
+        format!("{}{}{}", style.code(), text, RESET)
+    } else {
+        text.to_string()
+    }
+}
+
+/// Prepare a line for output: keep it as-is with colors on, strip ANSI codes otherwise.
+pub fn render_line(line: &str, mode: ColorMode, to_stderr: bool) -> Cow<'_, str> {
+    if color_enabled(mode, to_stderr) {
+        Cow::Borrowed(line)
+    } else {
+        strip_ansi(line)
+    }
+}
+
+/// Remove ANSI CSI escape sequences (e.g. `ESC[1;31m`) from `s`.
+pub fn strip_ansi(s: &str) -> Cow<'_, str> {
+    if !s.contains('\x1b') {
+        return Cow::Borrowed(s);
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' && chars.peek() == Some(&'[') {
+            chars.next();
+            // Parameters and intermediates, terminated by a final byte in '@'..='~'
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    Cow::Owned(out)
+}
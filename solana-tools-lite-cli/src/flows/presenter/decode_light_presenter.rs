@@ -0,0 +1,21 @@
+//! Presentation rules for decoded Light Protocol instructions.
+
+use crate::flows::decode_light::DecodeLightResult;
+use crate::flows::presenter::{emit_line, pretty_print_json, Presentable};
+use crate::shell::error::CliError;
+
+impl Presentable for DecodeLightResult {
+    fn present(
+        &self,
+        json: bool,
+        _show_secret: bool,
+        to_stderr: bool,
+    ) -> Result<(), CliError> {
+        if json {
+            pretty_print_json(self, to_stderr)?;
+        } else {
+            emit_line(&self.render_text(), to_stderr);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+//! Presentation rules for multi-account derivation results.
+
+use crate::flows::presenter::{emit_line, pretty_print_json, Presentable};
+use crate::shell::error::CliError;
+use solana_tools_lite::models::results::{DeriveManyResult, DerivedAccount};
+
+const INDEX_HEADER: &str = "Index";
+const PATH_HEADER: &str = "Derivation Path";
+const PUBKEY_HEADER: &str = "Public Key";
+const SECRET_HEADER: &str = "Secret Key";
+const FILE_HEADER: &str = "File";
+
+impl Presentable for DeriveManyResult {
+    fn present(
+        &self,
+        json: bool,
+        show_secret: bool,
+        to_stderr: bool,
+    ) -> Result<(), CliError> {
+        if json {
+            if show_secret {
+                pretty_print_json(self, to_stderr)?;
+            } else {
+                pretty_print_json(&redacted(self), to_stderr)?;
+            }
+        } else {
+            emit_line(&render_table(self, show_secret), to_stderr);
+        }
+        Ok(())
+    }
+}
+
+/// Render `index | derivation path | pubkey` as an aligned table, one row per account.
+///
+/// A secret key column is added only with `show_secret`; a file column when any account
+/// was written to a file.
+pub fn render_table(result: &DeriveManyResult, show_secret: bool) -> String {
+    let show_files = result.accounts.iter().any(|a| a.output_path.is_some());
+
+    let mut header = vec![INDEX_HEADER, PATH_HEADER, PUBKEY_HEADER];
+    if show_secret {
+        header.push(SECRET_HEADER);
+    }
+    if show_files {
+        header.push(FILE_HEADER);
+    }
+
+    let rows: Vec<Vec<String>> = result
+        .accounts
+        .iter()
+        .map(|account| {
+            let mut row = vec![
+                account.index.to_string(),
+                account.derivation_path.clone(),
+                account.public_key.clone(),
+            ];
+            if show_secret {
+                row.push(account.secret_key.clone().unwrap_or_default());
+            }
+            if show_files {
+                row.push(account.output_path.clone().unwrap_or_default());
+            }
+            row
+        })
+        .collect();
+
+    let widths: Vec<usize> = header
+        .iter()
+        .enumerate()
+        .map(|(col, title)| {
+            rows.iter()
+                .map(|row| row[col].len())
+                .chain([title.len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let header: Vec<String> = header.iter().map(|title| title.to_string()).collect();
+    std::iter::once(&header)
+        .chain(rows.iter())
+        .map(|cells| format_row(cells, &widths))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_row(cells: &[String], widths: &[usize]) -> String {
+    cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, &width)| format!("{cell:<width$}"))
+        .collect::<Vec<_>>()
+        .join(" | ")
+        .trim_end()
+        .to_string()
+}
+
+fn redacted(result: &DeriveManyResult) -> DeriveManyResult {
+    DeriveManyResult {
+        accounts: result
+            .accounts
+            .iter()
+            .map(|account| DerivedAccount {
+                secret_key: None,
+                ..account.clone()
+            })
+            .collect(),
+    }
+}
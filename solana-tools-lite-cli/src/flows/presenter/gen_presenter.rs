@@ -2,7 +2,7 @@
 
 use crate::flows::presenter::{emit_line, pretty_print_json, Presentable};
 use crate::shell::error::CliError;
-use solana_tools_lite::models::results::GenResult;
+use solana_tools_lite::models::results::{DeriveResult, GenResult};
 use std::fmt;
 
 impl Presentable for GenResult {
@@ -26,6 +26,28 @@ impl Presentable for GenResult {
     }
 }
 
+impl Presentable for DeriveResult {
+    fn present(
+        &self,
+        json: bool,
+        _show_secret: bool,
+        to_stderr: bool,
+    ) -> Result<(), CliError> {
+        if json {
+            pretty_print_json(self, to_stderr)?;
+        } else {
+            emit_line(
+                &format!(
+                    "Public Key: {}\nDerivation Path: {}",
+                    self.public_key, self.derivation_path
+                ),
+                to_stderr,
+            );
+        }
+        Ok(())
+    }
+}
+
 struct GenDisplay<'a> {
     result: &'a GenResult,
     show_secret: bool,
@@ -34,16 +56,23 @@ struct GenDisplay<'a> {
 impl<'a> fmt::Display for GenDisplay<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.show_secret {
+            if let Some(mnemonic) = &self.result.mnemonic {
+                writeln!(f, "Mnemonic: {}", mnemonic)?;
+            }
             write!(
                 f,
-                "Mnemonic: {}\nPublic Key: {}\nSecret Key: {}\nSeed Hex: {}",
-                self.result.mnemonic,
+                "Public Key: {}\nSecret Key: {}\nSeed Hex: {}\nMaster Fingerprint: {}",
                 self.result.public_key,
                 self.result.secret_key,
-                self.result.seed_hex
+                self.result.seed_hex,
+                self.result.master_fingerprint
             )
         } else {
-            write!(f, "Public Key: {}", self.result.public_key)
+            write!(
+                f,
+                "Public Key: {}\nMaster Fingerprint: {}",
+                self.result.public_key, self.result.master_fingerprint
+            )
         }
     }
 }
@@ -0,0 +1,93 @@
+//! Presentation rules for the structural transaction dump (`inspect`).
+
+use crate::flows::presenter::keys::KeyFormat;
+use crate::flows::presenter::{emit_line, pretty_print_json, Presentable};
+use crate::shell::error::CliError;
+use solana_tools_lite::models::results::{AccountRegion, InspectResult};
+use solana_tools_lite::models::transaction::SignatureStatus;
+
+/// Structural dump of a transaction with the key format for the text output.
+pub struct InspectPresenter<'a> {
+    pub result: &'a InspectResult,
+    /// Full or abbreviated keys in the text output (`--short-keys`).
+    pub key_format: KeyFormat,
+}
+
+impl Presentable for InspectPresenter<'_> {
+    fn present(
+        &self,
+        json: bool,
+        _show_secret: bool,
+        to_stderr: bool,
+    ) -> Result<(), CliError> {
+        if json {
+            pretty_print_json(self.result, to_stderr)?;
+        } else {
+            emit_line(&render_text(self.result, self.key_format), to_stderr);
+        }
+        Ok(())
+    }
+}
+
+fn render_text(result: &InspectResult, keys: KeyFormat) -> String {
+    let mut lines = vec!["Signatures:".to_string()];
+    for (i, sig) in result.signatures.iter().enumerate() {
+        let status = match sig.status {
+            SignatureStatus::Valid => "valid",
+            SignatureStatus::Invalid => "invalid",
+            SignatureStatus::Unsigned => "unsigned",
+        };
+        lines.push(format!("  #{} {} ({})", i + 1, sig.signature, status));
+    }
+
+    let header = &result.header;
+    lines.push(format!("Version: {}", result.version));
+    lines.push(format!(
+        "Header: {} required signature(s), {} readonly signed, {} readonly unsigned",
+        header.num_required_signatures,
+        header.num_readonly_signed_accounts,
+        header.num_readonly_unsigned_accounts
+    ));
+
+    lines.push("Account keys:".to_string());
+    for account in &result.account_keys {
+        let region = match account.region {
+            AccountRegion::WritableSigner => "writable-signer",
+            AccountRegion::ReadonlySigner => "readonly-signer",
+            AccountRegion::Writable => "writable",
+            AccountRegion::Readonly => "readonly",
+        };
+        lines.push(format!("  #{} {} {}", account.index, keys.key_str(&account.pubkey), region));
+    }
+
+    lines.push(format!("Recent blockhash: {}", result.recent_blockhash));
+
+    lines.push("Instructions:".to_string());
+    for (i, instr) in result.instructions.iter().enumerate() {
+        let label = instr
+            .program_label
+            .map(|label| format!(" ({label})"))
+            .unwrap_or_default();
+        lines.push(format!(
+            "  #{} program {}{}",
+            i + 1,
+            instr.program_id.as_deref().map(|key| keys.key_str(key)).unwrap_or_else(|| "<out of range>".to_string()),
+            label
+        ));
+        lines.push(format!("     accounts: {:?}", instr.accounts));
+        lines.push(format!("     data: {}", instr.data_hex));
+    }
+
+    if !result.address_table_lookups.is_empty() {
+        lines.push("Address table lookups:".to_string());
+        for lookup in &result.address_table_lookups {
+            lines.push(format!(
+                "  {} writable {:?} readonly {:?}",
+                keys.key_str(&lookup.account_key),
+                lookup.writable_indexes, lookup.readonly_indexes
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
@@ -0,0 +1,40 @@
+//! Public key formatting for human-readable output.
+//!
+//! `--short-keys` abbreviates keys in text presenters (`abcd…wxyz`); JSON output always
+//! carries full keys.
+
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+
+/// How text presenters print public keys; set per presenter from `--short-keys`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeyFormat {
+    /// Full Base58 key.
+    #[default]
+    Full,
+    /// Abbreviated key (`PubkeyBase58::short`).
+    Short,
+}
+
+impl KeyFormat {
+    /// `Short` when `--short-keys` is given, `Full` otherwise.
+    pub fn from_short_keys(short: bool) -> Self {
+        if short { Self::Short } else { Self::Full }
+    }
+
+    /// Key as shown by text presenters.
+    pub fn key(self, key: &PubkeyBase58) -> String {
+        match self {
+            Self::Full => key.to_string(),
+            Self::Short => key.short(),
+        }
+    }
+
+    /// Like `key` for a Base58 string; labels that are not keys (e.g. "Compressed State")
+    /// are returned unchanged.
+    pub fn key_str(self, key: &str) -> String {
+        match PubkeyBase58::try_from(key) {
+            Ok(pk) => self.key(&pk),
+            Err(_) => key.to_string(),
+        }
+    }
+}
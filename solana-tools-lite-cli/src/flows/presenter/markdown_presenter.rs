@@ -0,0 +1,174 @@
+//! Markdown rendering of a transaction analysis (`analyze --format markdown`).
+
+use crate::flows::presenter::analysis_presenter::{
+    compute_budget_text, ordered_warnings, token_setup_to_message, warning_to_message,
+    wsol_unwrap_to_message,
+};
+use crate::flows::presenter::keys::KeyFormat;
+use crate::flows::presenter::{emit_line, Presentable};
+use crate::models::cmds::WarningOrder;
+use crate::shell::error::CliError;
+use solana_tools_lite::models::analysis::{Severity, TxAnalysis};
+use solana_tools_lite::utils::format_sol;
+
+/// Renders an analysis as Markdown tables and a warnings list, for pasting into reports.
+pub struct MarkdownPresenter<'a> {
+    pub analysis: &'a TxAnalysis,
+    /// Full or abbreviated keys in warning and unwrap messages (`--short-keys`).
+    pub key_format: KeyFormat,
+    /// Order of the listed warnings (`--sort-warnings`).
+    pub warning_order: WarningOrder,
+}
+
+impl Presentable for MarkdownPresenter<'_> {
+    fn present(
+        &self,
+        _json: bool,
+        _show_secret: bool,
+        to_stderr: bool,
+    ) -> Result<(), CliError> {
+        emit_line(&render_markdown(self.analysis, self.key_format, self.warning_order), to_stderr);
+        Ok(())
+    }
+}
+
+/// Markdown document: summary table, transfers table, setup/extension lists and warnings.
+///
+/// Warnings are listed in `order` and prefixed with a severity marker.
+pub fn render_markdown(analysis: &TxAnalysis, keys: KeyFormat, order: WarningOrder) -> String {
+    let mut out = vec!["# Transaction Analysis".to_string(), String::new()];
+
+    let (privacy_label, privacy_desc) = analysis
+        .privacy_level
+        .display_info(analysis.confidential_ops_count, analysis.storage_ops_count);
+    let priority_fee = analysis.priority_fee_lamports.map(|(lamports, _)| lamports).unwrap_or(0);
+    let total_cost = analysis.total_fee_lamports + analysis.total_sol_send_by_signer;
+
+    out.push("## Summary".to_string());
+    out.push(String::new());
+    out.push("| Field | Value |".to_string());
+    out.push("| --- | --- |".to_string());
+    let mut row = |field: &str, value: String| out.push(format!("| {field} | {} |", escape(&value)));
+    row("Message version", analysis.message_version.to_string());
+    row("Recent blockhash", format!("`{}`", analysis.recent_blockhash));
+    row("Fee payer", if analysis.is_fee_payer { "Yes".into() } else { "No".into() });
+    row("Network fee", lamports_cell(analysis.base_fee_lamports));
+    row("Priority fee", lamports_cell(priority_fee));
+    if let Some(price) = analysis.compute_unit_price_micro {
+        row("Compute budget", compute_budget_text(price, analysis.compute_unit_limit));
+    }
+    row("Total fee", lamports_cell(analysis.total_fee_lamports));
+    row("You send", lamports_cell(analysis.total_sol_send_by_signer));
+    if analysis.sol_returned_to_signer() > 0 {
+        row("Net send (after wSOL unwrap)", lamports_cell(analysis.net_sol_send_by_signer()));
+    }
+    row("Max total cost", lamports_cell(total_cost));
+    row("Non-SOL assets", if analysis.has_non_sol_assets { "Yes".into() } else { "No".into() });
+    row("Privacy level", format!("{privacy_label} ({privacy_desc})"));
+    out.push(String::new());
+
+    if !analysis.transfers.is_empty() {
+        out.push("## Transfers".to_string());
+        out.push(String::new());
+        out.push("| # | From | To | Amount |".to_string());
+        out.push("| --- | --- | --- | --- |".to_string());
+        for (i, t) in analysis.transfers.iter().enumerate() {
+            out.push(format!(
+                "| {} | `{}`{} | `{}`{} | {} |",
+                i + 1,
+                t.from,
+                if t.from_is_signer { " (signer)" } else { "" },
+                t.to,
+                if t.to_is_fee_payer { " (fee payer)" } else { "" },
+                lamports_cell(t.lamports as u128)
+            ));
+        }
+        out.push(String::new());
+    }
+
+    if !analysis.token_setup.is_empty() {
+        out.push("## Token Setup".to_string());
+        out.push(String::new());
+        for action in &analysis.token_setup {
+            out.push(format!("- {}", escape(&token_setup_to_message(action, keys))));
+        }
+        out.push(String::new());
+    }
+
+    if !analysis.wsol_unwraps.is_empty() {
+        out.push("## Wrapped SOL Unwraps".to_string());
+        out.push(String::new());
+        for unwrap in &analysis.wsol_unwraps {
+            out.push(format!("- {}", escape(&wsol_unwrap_to_message(unwrap, keys))));
+        }
+        out.push(String::new());
+    }
+
+    if !analysis.created_accounts.is_empty() {
+        out.push("## Created Accounts".to_string());
+        out.push(String::new());
+        out.push("| Account | Owner | Space | Funded | Rent-exempt minimum |".to_string());
+        out.push("| --- | --- | --- | --- | --- |".to_string());
+        for created in &analysis.created_accounts {
+            out.push(format!(
+                "| `{}` | `{}` | {} | {} | {} |",
+                created.account,
+                created.owner,
+                created.space,
+                lamports_cell(created.lamports as u128),
+                lamports_cell(created.rent_exempt_minimum as u128)
+            ));
+        }
+        out.push(String::new());
+    }
+
+    if !analysis.extension_actions.is_empty() {
+        out.push("## Extension Actions".to_string());
+        out.push(String::new());
+        out.push("| Instruction | Protocol | Action | Description | Privacy |".to_string());
+        out.push("| --- | --- | --- | --- | --- |".to_string());
+        for action in &analysis.extension_actions {
+            out.push(format!(
+                "| {} | {} | {} | {} | {:?} |",
+                action.instruction_index().map(|i| format!("#{}", i + 1)).unwrap_or_default(),
+                action.protocol_name(),
+                action.action_name(),
+                escape(&action.description()),
+                action.privacy_impact()
+            ));
+        }
+        out.push(String::new());
+    }
+
+    out.push("## Warnings".to_string());
+    out.push(String::new());
+    if analysis.warnings.is_empty() {
+        out.push("_None_".to_string());
+    }
+    for warning in ordered_warnings(analysis, order) {
+        out.push(format!(
+            "- {} {}",
+            severity_marker(warning.severity()),
+            escape(&warning_to_message(warning, keys))
+        ));
+    }
+
+    out.join("\n")
+}
+
+fn severity_marker(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "🔴 **Critical**",
+        Severity::Warn => "🟡 **Warn**",
+        Severity::Info => "🔵 Info",
+    }
+}
+
+fn lamports_cell(lamports: u128) -> String {
+    format!("{} ({} lamports)", format_sol(lamports), lamports)
+}
+
+/// Keep free text from breaking table cells.
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
@@ -2,11 +2,21 @@ pub(crate) mod presenter_trait;
 pub use presenter_trait::Presentable;
 
 mod base58_presenter;
+pub mod derive_many_presenter;
+#[cfg(feature = "protocol-extensions")]
+mod decode_light_presenter;
 mod gen_presenter;
+mod inspect_presenter;
+pub mod keys;
+pub mod qr;
+pub mod markdown_presenter;
 mod sign_presenter;
 pub mod analysis_presenter;
+pub mod color;
 mod verify_presenter;
 mod utils;
 
 pub use analysis_presenter::AnalysisPresenter;
-pub(crate) use utils::{emit_line, pretty_print_json};
+pub use inspect_presenter::InspectPresenter;
+pub use markdown_presenter::MarkdownPresenter;
+pub(crate) use utils::{emit_line, emit_styled_line, pretty_print_json};
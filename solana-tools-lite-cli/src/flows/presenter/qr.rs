@@ -0,0 +1,46 @@
+//! QR rendering of signed transactions for air-gapped transfer (`sign-tx --qr`).
+
+use crate::shell::error::CliError;
+use qrcode::QrCode;
+use qrcode::render::unicode::Dense1x2;
+
+/// Longest payload per QR code. A maximal transaction (1232 bytes, 1644 Base64 chars)
+/// fits one version 40 code, but codes that dense rarely scan off a terminal; larger
+/// payloads are split instead.
+pub const QR_CHUNK_CHARS: usize = 1000;
+
+/// Split `payload` into QR-sized chunks. A payload that fits is returned unchanged;
+/// otherwise each chunk is prefixed with a `{index}/{total}:` header (1-based) so the
+/// receiver can reassemble them in order.
+pub fn qr_chunks(payload: &str) -> Vec<String> {
+    let parts: Vec<&[u8]> = payload.as_bytes().chunks(QR_CHUNK_CHARS).collect();
+    if parts.len() <= 1 {
+        return vec![payload.to_string()];
+    }
+    let total = parts.len();
+    parts
+        .iter()
+        .enumerate()
+        .map(|(i, part)| format!("{}/{}:{}", i + 1, total, String::from_utf8_lossy(part)))
+        .collect()
+}
+
+/// Render one chunk as a unicode QR code (two modules per character cell).
+pub fn render_qr(chunk: &str) -> Result<String, CliError> {
+    let code = QrCode::new(chunk.as_bytes())
+        .map_err(|e| CliError::PresentationEncode(format!("QR code: {e}")))?;
+    Ok(code.render::<Dense1x2>().quiet_zone(true).build())
+}
+
+/// Print the QR code(s) of `payload` to stdout, labelling each when it is split.
+pub fn print_qr_codes(payload: &str) -> Result<(), CliError> {
+    let chunks = qr_chunks(payload);
+    let total = chunks.len();
+    for (i, chunk) in chunks.iter().enumerate() {
+        if total > 1 {
+            println!("QR {}/{}", i + 1, total);
+        }
+        println!("{}", render_qr(chunk)?);
+    }
+    Ok(())
+}
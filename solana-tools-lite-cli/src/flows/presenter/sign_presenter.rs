@@ -2,7 +2,7 @@
 
 use crate::flows::presenter::{emit_line, pretty_print_json, Presentable};
 use crate::shell::error::CliError;
-use solana_tools_lite::models::results::SignResult;
+use solana_tools_lite::models::results::{SignPlanResult, SignResult};
 
 impl Presentable for SignResult {
     fn present(
@@ -19,3 +19,15 @@ impl Presentable for SignResult {
         Ok(())
     }
 }
+
+/// The plan is meant for scripts: it is JSON regardless of `--json`.
+impl Presentable for SignPlanResult {
+    fn present(
+        &self,
+        _json: bool,
+        _show_secret: bool,
+        to_stderr: bool,
+    ) -> Result<(), CliError> {
+        pretty_print_json(self, to_stderr)
+    }
+}
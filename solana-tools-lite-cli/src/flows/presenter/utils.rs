@@ -1,4 +1,6 @@
 use serde::Serialize;
+use crate::flows::presenter::color::render_line;
+use crate::models::cmds::ColorMode;
 use crate::shell::error::CliError;
 
 /// Pretty-prints any serializable struct as JSON.
@@ -13,7 +15,15 @@ pub(crate) fn pretty_print_json<T: Serialize>(
     Ok(())
 }
 
+/// Prints a line to stdout/stderr without ANSI codes, for presenters that do not style
+/// their output.
 pub(crate) fn emit_line(line: &str, to_stderr: bool) {
+    emit_styled_line(line, ColorMode::Never, to_stderr);
+}
+
+/// Prints a line to stdout/stderr, stripping ANSI codes unless `color` enables them.
+pub(crate) fn emit_styled_line(line: &str, color: ColorMode, to_stderr: bool) {
+    let line = render_line(line, color, to_stderr);
     if to_stderr {
         eprintln!("{line}");
     } else {
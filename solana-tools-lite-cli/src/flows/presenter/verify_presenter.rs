@@ -2,7 +2,7 @@
 
 use crate::flows::presenter::{Presentable, emit_line, pretty_print_json};
 use crate::shell::error::CliError;
-use solana_tools_lite::models::results::VerifyResult;
+use solana_tools_lite::models::results::{VerifyResult, VerifyTxResult, VerifyTxStatus};
 
 impl Presentable for VerifyResult {
     fn present(
@@ -14,8 +14,49 @@ impl Presentable for VerifyResult {
         if json {
             return pretty_print_json(self, to_stderr);
         }
-        emit_line("[✓] Signature is valid", to_stderr);
+        match self.candidate_index {
+            Some(index) => emit_line(
+                &format!("[✓] Signature is valid for candidate #{}: {}", index + 1, self.pubkey),
+                to_stderr,
+            ),
+            None => emit_line("[✓] Signature is valid", to_stderr),
+        }
+
+        Ok(())
+    }
+}
+
+impl Presentable for VerifyTxResult {
+    fn present(
+        &self,
+        json: bool,
+        _show_secret: bool,
+        to_stderr: bool,
+    ) -> Result<(), CliError> {
+        if json {
+            return pretty_print_json(self, to_stderr);
+        }
+        emit_line(&verify_tx_line(self), to_stderr);
 
         Ok(())
     }
 }
+
+/// One-line text outcome of a `verify-tx` check.
+pub fn verify_tx_line(result: &VerifyTxResult) -> String {
+    let slot = result.signer_index.map(|index| index + 1).unwrap_or_default();
+    match result.status {
+        VerifyTxStatus::Valid => {
+            format!("[✓] Signature #{} is valid for {}", slot, result.pubkey)
+        }
+        VerifyTxStatus::Invalid => {
+            format!("[✗] Signature #{} does not verify for {}", slot, result.pubkey)
+        }
+        VerifyTxStatus::Unsigned => {
+            format!("[✗] Signer #{} ({}) has not signed", slot, result.pubkey)
+        }
+        VerifyTxStatus::NotASigner => {
+            format!("[✗] {} is not a signer of this transaction", result.pubkey)
+        }
+    }
+}
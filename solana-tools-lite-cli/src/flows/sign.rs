@@ -9,6 +9,7 @@ use crate::shell::error::CliError;
 /// - `message`: optional message to sign (as provided by CLI layer)
 /// - `message_file`: optional path to message file (stdin "-" handled in IO layer)
 /// - `secret_key_path`: path to secret key file (stdin "-" is rejected in IO layer)
+/// - `raw`: blind-sign the exact message bytes; refused when they decode as a transaction
 /// - `json`: if true, pretty-print JSON result; otherwise print only the Base58 signature
 pub fn execute(
     message: Option<&str>,
@@ -16,16 +17,25 @@ pub fn execute(
     secret_key_path: &str,
     output: Option<&str>,
     force: bool,
+    raw: bool,
     json: bool,
 ) -> Result<(), CliError> {
-    // Resolve message from inline or file/stdin via adapter helper
-    let message_content = io::read_message(message, message_file)?;
-
     // Read & parse signing key in the flow
     let signing_key = io::read_and_parse_secret_key(secret_key_path)?;
 
-    // Sign message
-    let result = sign_message::handle(&message_content, &signing_key)?;
+    let result = if raw {
+        let bytes = io::read_message_bytes(message, message_file)?;
+        eprintln!(
+            "WARNING: blind-signing {} raw bytes. Only do this if you know exactly what they are; \
+             a signature over raw bytes can authorize anything the verifier accepts.",
+            bytes.len()
+        );
+        sign_message::handle_raw(&bytes, &signing_key)?
+    } else {
+        // Resolve message from inline or file/stdin via adapter helper
+        let message_content = io::read_message(message, message_file)?;
+        sign_message::handle(&message_content, &signing_key)?
+    };
 
     // Persist full JSON artifact to file only if requested (independent of `json`)
     let saved_path = io::save_pretty_json(&result, output, force, "sign.json")?;
@@ -1,110 +1,302 @@
 use serde::Serialize;
 use serde_json;
 use solana_tools_lite::adapters::io_adapter::{
-    read_and_parse_secret_key, read_input_transaction, read_lookup_tables, write_signed_transaction,
+    ReadOptions, read_and_parse_secret_key, read_input_transaction_as, read_lookup_tables,
+    resolve_final_path_with_default, write_signed_transaction,
 };
-use solana_tools_lite::handlers::analysis::{analyze_input_transaction, build_signing_summary};
-use solana_tools_lite::handlers::sign_tx::handle as handle_sign_transaction;
-use solana_tools_lite::models::analysis::{SigningSummary, TxAnalysis};
+use solana_tools_lite::bs58;
+use solana_tools_lite::data_encoding::{BASE64, HEXLOWER};
+use solana_tools_lite::errors::{IoError, SignError, ToolError};
+use solana_tools_lite::handlers::analysis::{
+    AnalysisConfig, analyze_transaction_with, build_signing_summary,
+};
+use solana_tools_lite::handlers::sign_tx::{
+    SignOptions, check_sign_gates, plan as sign_plan, prepare_and_sign_input,
+};
+use solana_tools_lite::codec::serialize_transaction;
+use solana_tools_lite::models::analysis::{SerializableTxAnalysis, SigningSummary, TxAnalysis};
 use solana_tools_lite::serde::fmt::OutputFormat;
-use solana_tools_lite::models::{PubkeyBase58, Transaction};
+use solana_tools_lite::models::rent::RentConfig;
+use solana_tools_lite::models::results::SignOutcome;
+use solana_tools_lite::models::{Message, PubkeyBase58, Transaction};
+use std::cell::RefCell;
 
+use crate::flows::analyze::parse_trusted_programs;
+use crate::flows::presenter::analysis_presenter::order_warnings;
+use crate::flows::presenter::qr::print_qr_codes;
+use crate::flows::presenter::keys::KeyFormat;
 use crate::flows::presenter::{Presentable, AnalysisPresenter};
-use crate::models::cmds::OutFmt;
+use crate::models::cmds::{ColorMode, DataEncoding, InFmt, OutFmt, WarningOrder};
 use crate::shell::error::CliError;
 
+/// Settings of the sign-transaction flow (`sign-tx` flags after config resolution).
+#[derive(Default)]
+pub struct SignTxOptions<'a> {
+    /// Path to the input file (when `None`, the adapter may read stdin).
+    pub input: Option<&'a str>,
+    /// Force the input encoding instead of auto-detection.
+    pub input_format: Option<InFmt>,
+    /// How the input transaction is read (`--strict-signatures`).
+    pub read: ReadOptions,
+    /// Output path (stdout when `None` or `Some("-")` via adapter).
+    pub output: Option<&'a str>,
+    /// Directory for the signed tx; the filename is generated from its signature.
+    pub output_dir: Option<&'a str>,
+    /// Pretty JSON when the output format is JSON.
+    pub pretty_json: bool,
+    /// Force the output format (json|base64|base58); otherwise mirrors the input format.
+    pub out_override: Option<OutFmt>,
+    /// Overwrite an existing output file.
+    pub force: bool,
+    /// Path to a lookup tables file used to resolve v0 accounts.
+    pub lookup_tables_path: Option<&'a str>,
+    /// Skip the interactive confirmation.
+    pub assume_yes: bool,
+    /// Print the exact bytes passed to Ed25519 to stderr in this encoding.
+    pub show_message_bytes: Option<DataEncoding>,
+    /// Rent parameters for the rent-exempt minimum of created accounts.
+    pub rent: RentConfig,
+    /// Full or abbreviated keys in the review summary (`--short-keys`).
+    pub key_format: KeyFormat,
+    /// Order of the warnings in the review and JSON summaries (`--sort-warnings`).
+    pub warning_order: WarningOrder,
+    /// Colors of the review summary (`--color`, resolved).
+    pub color: ColorMode,
+    /// Refuse when the total fee exceeds this many lamports.
+    pub max_fee: Option<u64>,
+    /// Refuse unless the signer is (`true`) or is not (`false`) the fee payer.
+    pub require_fee_payer: Option<bool>,
+    /// Program ids (Base58) whose "unknown program" warning is suppressed.
+    pub trusted_programs: &'a [String],
+    /// Refuse when a program is neither known nor in `trusted_programs`.
+    pub deny_unknown_programs: bool,
+    /// Emit a machine-readable summary to stdout (requires an output file for the signed tx).
+    pub summary_json: bool,
+    /// Emit `{analysis, summary, signed}` as one object to stdout (requires `output`).
+    pub combined_json: bool,
+    /// Print the signed tx (Base64) as QR code(s) to stdout instead of the encoded copy.
+    pub qr: bool,
+}
+
 /// Sign-transaction flow: thin orchestrator around the handler.
 ///
 /// Parameters
-/// - `input`: optional path to input file (when `None`, handler may read from stdin)
 /// - `keypair_path`: path to keypair file (stdin disabled for secrets in adapter)
-/// - `output`: optional output path (stdout when `None` or `Some("-")` via adapter)
-/// - `pretty_json`: pretty JSON when output format is JSON
-/// - `out_override`: force output format (json|base64|base58); otherwise mirrors input format
-/// - `summary_json`: emit a machine-readable summary to stdout (requires `output` for the signed tx)
+/// - `opts`: input, output and gating settings
+///
+/// Returns the severity exit code of the pre-signing analysis (`TxAnalysis::severity_exit_code`).
 pub fn execute(
-    input: Option<&str>,
     keypair_path: &str,
-    output: Option<&str>,
-    pretty_json: bool,
-    out_override: Option<OutFmt>,
-    force: bool,
-    lookup_tables_path: Option<&str>,
-    assume_yes: bool,
-    max_fee: Option<u64>,
-    summary_json: bool,
-) -> Result<(), CliError> {
-    if summary_json && output.map(|o| o == "-").unwrap_or(true) {
-        return Err(CliError::SummaryRequiresOutput);
+    opts: &SignTxOptions,
+) -> Result<i32, CliError> {
+    let summary_flag = match (opts.summary_json, opts.combined_json) {
+        (_, true) => Some("--combined-json"),
+        (true, false) => Some("--summary-json"),
+        (false, false) => None,
+    };
+    if let Some(flag) = summary_flag
+        && opts.output_dir.is_none()
+        && opts.output.map(|o| o == "-").unwrap_or(true)
+    {
+        return Err(CliError::SummaryRequiresOutput { flag });
     }
+    let allowlist = parse_trusted_programs(opts.trusted_programs)?;
+
     // 1) Read input transaction (file/stdin) via adapter
-    let input_tx = read_input_transaction(input)?;
+    let input_tx = read_input_transaction_as(opts.input, opts.input_format.map(Into::into), opts.read)?;
 
     // 2) Resolve default output format from input type (mirrors input format)
-    let default_format = input_tx.default_output_format(pretty_json);
+    let default_format = input_tx.default_output_format(opts.pretty_json);
 
     // 3) Read + parse signing key
     let signing_key = read_and_parse_secret_key(keypair_path)?;
 
-    let signing_pubkey = PubkeyBase58::from(signing_key.verifying_key().to_bytes());
-
     // 4) Optional: expand v0 accounts with lookup tables
-    let tables = lookup_tables_path.map(read_lookup_tables).transpose()?;
+    let tables = opts.lookup_tables_path.map(read_lookup_tables).transpose()?;
 
-    // 5) Analyze unsigned transaction via analyze_input_transaction
-    let analysis = analyze_input_transaction(&input_tx, &signing_pubkey, tables.as_ref())?;
-    let analysis_presenter = AnalysisPresenter {
-        analysis: Some(&analysis),
-        summary_payload: None,
+    // Exact signing input for external review, printed after the analysis
+    let message_bytes = opts
+        .show_message_bytes
+        .map(|encoding| {
+            let tx = Transaction::try_from(&input_tx).map_err(ToolError::TransactionParse)?;
+            Ok::<_, CliError>(render_message_bytes(&tx.message, encoding))
+        })
+        .transpose()?;
+
+    // 5-8) Analyze, show, gate (fee limit, fee payer role, prompt) and sign in the core;
+    // presenter and stdin errors are kept aside and reported first
+    let flow_error: RefCell<Option<CliError>> = RefCell::new(None);
+    let review = |analysis: &mut TxAnalysis| {
+        order_warnings(analysis, opts.warning_order);
+        let analysis_presenter = AnalysisPresenter {
+            analysis: Some(analysis),
+            summary_payload: None,
+            key_format: opts.key_format,
+            warning_order: opts.warning_order,
+            color: opts.color,
+        };
+        if let Err(e) = analysis_presenter.present(false, false, true) {
+            flow_error.borrow_mut().get_or_insert(e);
+        }
+        if let Some(bytes) = &message_bytes {
+            eprintln!("Message bytes to sign:");
+            eprintln!("{bytes}");
+        }
     };
-    
-    analysis_presenter.present(false, false, true)?;
-
-    // 6) Enforce fee limit for CI/pipeline safety
-    if let Some(limit) = max_fee {
-        if analysis.total_fee_lamports > limit as u128 {
-            return Err(CliError::FeeLimitExceeded {
-                fee_lamports: analysis.total_fee_lamports,
-                max_lamports: limit,
-            });
+    let confirm = |analysis: &TxAnalysis| match confirm_stdin(analysis.has_critical_warnings()) {
+        Ok(accepted) => accepted,
+        Err(e) => {
+            flow_error.borrow_mut().get_or_insert(e);
+            false
         }
+    };
+    let sign_opts = SignOptions {
+        tables: tables.as_ref(),
+        allowlist: Some(&allowlist),
+        rent: opts.rent,
+        max_fee: opts.max_fee,
+        require_fee_payer: opts.require_fee_payer,
+        deny_unknown_programs: opts.deny_unknown_programs,
+        review: Some(&review),
+        confirm: if opts.assume_yes { None } else { Some(&confirm) },
+    };
+    let signed = prepare_and_sign_input(input_tx, std::slice::from_ref(&signing_key), &sign_opts);
+    if let Some(e) = flow_error.take() {
+        return Err(e);
     }
-
-    // 7) Interactive confirm unless --yes
-    if !assume_yes && !confirm_stdin()? {
-        return Err(CliError::UserRejected);
-    }
-
-    // 8) Sign the tx
-    let result = handle_sign_transaction(input_tx, &signing_key)?;
+    let SignOutcome {
+        analysis, signed_tx, ..
+    } = signed.map_err(sign_gate_error)?;
 
     // 9) Choose output format (override or mirror input)
-    let chosen_format = match out_override {
+    let chosen_format = match opts.out_override {
         Some(OutFmt::Json) => OutputFormat::Json {
-            pretty: pretty_json,
+            pretty: opts.pretty_json,
         },
         Some(OutFmt::Base64) => OutputFormat::Base64,
         Some(OutFmt::Base58) => OutputFormat::Base58,
         None => default_format,
     };
 
+    // --output-dir: name the file after the signed transaction
+    let generated_path = opts
+        .output_dir
+        .map(|dir| output_dir_path(dir, &signed_tx, chosen_format))
+        .transpose()?;
+    let output = generated_path.as_deref().or(opts.output);
+
     // Optional JSON summary (prepared before writing the tx)
-    let summary_payload =
-        prepare_summary_payload(summary_json, &result.signed_tx, &analysis, output)?;
+    let summary_payload = if opts.combined_json {
+        Some(combined_json_payload(&signed_tx, &analysis, output)?)
+    } else {
+        prepare_summary_payload(opts.summary_json, &signed_tx, &analysis, output)?
+    };
 
-    // 10) Write out via adapter (file or stdout), respecting force for files
-    write_signed_transaction(&result.signed_tx, chosen_format, output, force)?;
+    // 10) Write out via adapter (file or stdout), respecting force for files;
+    // with --qr the codes take the place of the stdout copy
+    let to_stdout = output.map(|o| o == "-").unwrap_or(true);
+    if !(opts.qr && to_stdout) {
+        write_signed_transaction(&signed_tx, chosen_format, output, opts.force)?;
+    }
+    if opts.qr {
+        print_qr_codes(&BASE64.encode(&serialize_transaction(&signed_tx)))?;
+    }
 
     if let Some(payload) = summary_payload.as_deref() {
         let summary_presenter = AnalysisPresenter {
             analysis: None,
             summary_payload: Some(payload),
+            key_format: opts.key_format,
+            warning_order: opts.warning_order,
+            color: opts.color,
         };
 
         summary_presenter.present(true, false, false)?;
     }
 
-    Ok(())
+    Ok(analysis.severity_exit_code())
+}
+
+/// Signing plan flow (`sign-tx --plan`): report which required signer slots the keys fill.
+///
+/// Keys are read from `keypair_paths` only to derive their pubkeys; nothing is signed or
+/// written. The transaction is analyzed for the first key and must pass the same gates as
+/// signing (`max_fee`, `require_fee_payer`); the plan is then printed as JSON to stdout.
+pub fn execute_plan(keypair_paths: &[String], opts: &SignTxOptions) -> Result<(), CliError> {
+    let allowlist = parse_trusted_programs(opts.trusted_programs)?;
+    let input_tx = read_input_transaction_as(opts.input, opts.input_format.map(Into::into), opts.read)?;
+    let tx = Transaction::try_from(input_tx).map_err(ToolError::TransactionParse)?;
+    tx.message.sanitize()?;
+
+    let pubkeys = keypair_paths
+        .iter()
+        .map(|path| {
+            let key = read_and_parse_secret_key(path)?;
+            Ok(PubkeyBase58::from(key.verifying_key().to_bytes()))
+        })
+        .collect::<Result<Vec<_>, ToolError>>()?;
+
+    if let Some(first) = pubkeys.first() {
+        let tables = opts.lookup_tables_path.map(read_lookup_tables).transpose()?;
+        let config = AnalysisConfig {
+            rent: opts.rent,
+            ..Default::default()
+        };
+        let analysis =
+            analyze_transaction_with(&tx.message, first, tables.as_ref(), Some(&allowlist), None, &config);
+        let gates = SignOptions {
+            max_fee: opts.max_fee,
+            require_fee_payer: opts.require_fee_payer,
+            deny_unknown_programs: opts.deny_unknown_programs,
+            ..Default::default()
+        };
+        check_sign_gates(&analysis, &gates).map_err(sign_gate_error)?;
+    }
+
+    sign_plan(&tx, &pubkeys).present(true, false, false)
+}
+
+/// Encode the message bytes every signer signs (`Transaction::message_signing_bytes`).
+pub fn render_message_bytes(message: &Message, encoding: DataEncoding) -> String {
+    let bytes = solana_tools_lite::codec::serialize_message(message);
+    match encoding {
+        DataEncoding::Base58 => bs58::encode(bytes).into_string(),
+        DataEncoding::Base64 => BASE64.encode(&bytes),
+        DataEncoding::Hex => HEXLOWER.encode(&bytes),
+    }
+}
+
+/// Filesystem-safe name for a signed transaction: its first non-empty signature
+/// (Base58 is alphanumeric), or a timestamp when no signature is present.
+pub fn signed_tx_filename(tx: &Transaction, format: OutputFormat) -> String {
+    let extension = match format {
+        OutputFormat::Json { .. } => "json",
+        OutputFormat::Base64 => "b64",
+        OutputFormat::Base58 => "b58",
+    };
+    let stem = tx
+        .signatures
+        .iter()
+        .map(|sig| sig.to_bytes())
+        .find(|bytes| bytes.iter().any(|&b| b != 0))
+        .map(|bytes| bs58::encode(bytes).into_string())
+        .unwrap_or_else(|| {
+            let secs = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default();
+            format!("signed-{secs}")
+        });
+
+    format!("{stem}.{extension}")
+}
+
+fn output_dir_path(dir: &str, tx: &Transaction, format: OutputFormat) -> Result<String, CliError> {
+    std::fs::create_dir_all(dir)
+        .map_err(|e| ToolError::Io(IoError::with_path(e, dir)))?;
+    let filename = signed_tx_filename(tx, format);
+    let path = resolve_final_path_with_default(Some(dir), &filename);
+    Ok(path.to_string_lossy().into_owned())
 }
 
 #[derive(Serialize)]
@@ -135,10 +327,69 @@ fn prepare_summary_payload(
     Ok(Some(payload))
 }
 
-fn confirm_stdin() -> Result<bool, CliError> {
+#[derive(Serialize)]
+struct CombinedReport<'a> {
+    analysis: SerializableTxAnalysis<'a>,
+    summary: CliSigningSummary<'a>,
+    signed: SignedEncodings,
+}
+
+#[derive(Serialize)]
+struct SignedEncodings {
+    base64: String,
+    base58: String,
+}
+
+/// One JSON object with the full analysis, the signing summary and the signed transaction
+/// encodings (`--combined-json`).
+pub fn combined_json_payload(
+    tx: &Transaction,
+    analysis: &TxAnalysis,
+    output: Option<&str>,
+) -> Result<String, CliError> {
+    let summary = build_signing_summary(tx, analysis)?;
+    let raw = serialize_transaction(tx);
+    let report = CombinedReport {
+        analysis: analysis.to_serializable(),
+        summary: CliSigningSummary {
+            core_summary: &summary,
+            output_path: output,
+        },
+        signed: SignedEncodings {
+            base64: BASE64.encode(&raw),
+            base58: bs58::encode(&raw).into_string(),
+        },
+    };
+
+    serde_json::to_string_pretty(&report).map_err(|e| CliError::SummaryEncode(e.to_string()))
+}
+
+/// Gate refusals of the core keep their dedicated CLI errors.
+fn sign_gate_error(err: ToolError) -> CliError {
+    match err {
+        ToolError::Sign(SignError::FeeLimitExceeded {
+            fee_lamports,
+            max_lamports,
+        }) => CliError::FeeLimitExceeded {
+            fee_lamports,
+            max_lamports,
+        },
+        ToolError::Sign(SignError::FeePayerMismatch { required }) => {
+            CliError::FeePayerMismatch { required }
+        }
+        ToolError::Sign(SignError::Rejected) => CliError::UserRejected,
+        other => other.into(),
+    }
+}
+
+fn confirm_stdin(critical: bool) -> Result<bool, CliError> {
     use std::io::{self, Write};
     
-    eprint!("Sign this transaction? [y/N] ");
+    if critical {
+        eprint!("CRITICAL warnings above. Sign this transaction anyway? [y/N] ");
+    } else {
+        eprint!("Sign this transaction? [y/N] ");
+    }
 
     // ensure prompt is visible before reading input
     io::stderr().flush().ok();
@@ -1,19 +1,29 @@
 use crate::flows::presenter::Presentable;
+use crate::models::cmds::InFmt;
 use solana_tools_lite::adapters::io_adapter as io;
-use solana_tools_lite::adapters::io_adapter::{read_message, read_pubkey, read_signature};
+use solana_tools_lite::adapters::io_adapter::{
+    ReadOptions, read_input_transaction_as, read_message, read_pubkey, read_signature,
+};
+use solana_tools_lite::errors::{ToolError, VerifyError};
 use solana_tools_lite::handlers::verify;
-use solana_tools_lite::models::results::VerifyResult;
+use solana_tools_lite::models::Transaction;
+use solana_tools_lite::models::results::{VerifyResult, VerifyTxStatus};
 use std::path::Path;
 use crate::shell::error::CliError;
 
 /// Verify flow: calls domain handler and prints result.
 /// Returns Ok(()) on valid signature; returns an error to trigger non-zero exit on invalid.
+///
+/// Several public keys (repeated `--pubkey` or one per line in `pubkey_file`) are treated
+/// as candidate signers; the first that validates is reported.
+///
+/// The signature and public keys may be Base58 or Base64 (auto-detected by decoded length).
 pub fn execute(
     message: Option<&str>,
     message_file: Option<&str>,
     signature: Option<&str>,
     signature_file: Option<&str>,
-    pubkey: Option<&str>,
+    pubkeys: &[String],
     pubkey_file: Option<&str>,
     output: Option<&str>,
     force: bool,
@@ -21,10 +31,29 @@ pub fn execute(
 ) -> Result<(), CliError> {
     // Resolve inputs using IO helpers
     let msg = read_message(message, message_file)?;
-    let sig = read_signature(signature, signature_file)?;
-    let pk = read_pubkey(pubkey, pubkey_file)?;
+    let sig = verify::signature_to_base58(&read_signature(signature, signature_file)?)
+        .map_err(ToolError::from)?;
+    let candidates: Vec<String> = match pubkey_file {
+        Some(file) => {
+            let keys = read_pubkey(None, Some(file))?;
+            // Report an empty file as such rather than as a malformed or failing key
+            if keys.is_empty() {
+                return Err(ToolError::InvalidInput(format!("public key file {file} is empty")).into());
+            }
+            keys.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(verify::pubkey_to_base58)
+                .collect::<Result<_, _>>()
+        }
+        None => pubkeys.iter().map(|pk| verify::pubkey_to_base58(pk)).collect(),
+    }
+    .map_err(ToolError::from)?;
 
-    let result = verify::handle(&msg, &sig, &pk)?;
+    let result = match candidates.as_slice() {
+        [pk] => verify::handle(&msg, &sig, pk)?,
+        _ => verify::handle_candidates(&msg, &sig, &candidates)?,
+    };
 
     // Persist full JSON artifact to file only if requested
     let saved_path = io::save_pretty_json(&result, output, force, "verification.json")?;
@@ -35,6 +64,30 @@ pub fn execute(
     Ok(())
 }
 
+/// Verify-tx flow: checks the signature slot of `pubkey` in a transaction.
+///
+/// Prints the outcome (valid / invalid / not-a-signer / unsigned) and returns
+/// `VerificationFailed` for anything but a valid signature, so the exit code is non-zero.
+pub fn execute_tx(
+    input: Option<&str>,
+    input_format: Option<InFmt>,
+    read: ReadOptions,
+    pubkey: &str,
+    json: bool,
+) -> Result<(), CliError> {
+    let input_tx = read_input_transaction_as(input, input_format.map(Into::into), read)?;
+    let tx = Transaction::try_from(input_tx).map_err(ToolError::TransactionParse)?;
+
+    let pubkey = verify::pubkey_to_base58(pubkey).map_err(ToolError::from)?;
+    let result = verify::handle_transaction(&tx, &pubkey)?;
+    result.present(json, false, false)?;
+
+    if result.status != VerifyTxStatus::Valid {
+        return Err(ToolError::Verify(VerifyError::VerificationFailed).into());
+    }
+    Ok(())
+}
+
 fn print_result(
     result: &VerifyResult,
     json: bool,
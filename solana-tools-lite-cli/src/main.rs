@@ -1,9 +1,11 @@
 use clap::Parser;
+use solana_tools_lite::adapters::io_adapter::read_rent_config;
+use solana_tools_lite::models::rent::RentConfig;
 use solana_tools_lite_cli::flows;
 use solana_tools_lite_cli::shell::cli::Cli;
 use solana_tools_lite_cli::shell::config::ConfigResolver;
 use solana_tools_lite_cli::shell::error::{fail_invalid_input, report_cli_error};
-use solana_tools_lite_cli::models::cmds::Commands;
+use solana_tools_lite_cli::models::cmds::{AnalysisFormat, Commands};
 
 fn main() {
     // Initialize protocol extensions if feature is enabled
@@ -11,30 +13,84 @@ fn main() {
     extensions::init();
     
     let cli = Cli::parse();
+    solana_tools_lite_cli::shell::logger::init();
 
     // Global JSON resolution
     let json = ConfigResolver::resolve_json(cli.json);
+    let color = ConfigResolver::resolve_color(cli.color);
+    let read = solana_tools_lite::adapters::io_adapter::ReadOptions {
+        strict: cli.strict_signatures,
+        max_stdin_size: ConfigResolver::resolve_max_input_size(cli.max_input_size)
+            .unwrap_or(solana_tools_lite::layers::io::DEFAULT_MAX_STDIN_SIZE),
+    };
+    let key_format = flows::presenter::keys::KeyFormat::from_short_keys(cli.short_keys);
 
     match &cli.command {
         Commands::Gen {
             mnemonic,
+            seed_hex,
             passphrase,
             unsafe_show_secret,
             output,
             force,
+            derive_only,
+            count,
         } => {
-            // Force save can be set via --force or ENV for consistency
-            let force_resolved = ConfigResolver::resolve_force(*force);
+            // --derive-only and --count require --mnemonic (enforced by clap)
+            if let (Some(count), Some(mnemonic_path)) = (*count, mnemonic.as_deref()) {
+                let result = if *derive_only {
+                    flows::generation::execute_derive_many(
+                        mnemonic_path,
+                        passphrase.as_deref(),
+                        count,
+                        json,
+                    )
+                } else {
+                    flows::generation::execute_many(
+                        mnemonic_path,
+                        passphrase.as_deref(),
+                        count,
+                        json,
+                        *unsafe_show_secret,
+                        output.as_deref(),
+                        ConfigResolver::resolve_force(*force),
+                    )
+                };
+                if let Err(e) = result {
+                    report_cli_error("gen", e);
+                }
+            } else if let Some(seed_path) = seed_hex.as_deref() {
+                let force_resolved = ConfigResolver::resolve_force(*force);
 
-            if let Err(e) = flows::generation::execute(
-                mnemonic.as_deref(),
-                passphrase.as_deref(),
-                json,
-                *unsafe_show_secret,
-                output.as_deref(),
-                force_resolved,
-            ) {
-                report_cli_error("gen", e);
+                if let Err(e) = flows::generation::execute_from_seed(
+                    seed_path,
+                    json,
+                    *unsafe_show_secret,
+                    output.as_deref(),
+                    force_resolved,
+                ) {
+                    report_cli_error("gen", e);
+                }
+            } else if let (true, Some(mnemonic_path)) = (*derive_only, mnemonic.as_deref()) {
+                if let Err(e) =
+                    flows::generation::execute_derive_only(mnemonic_path, passphrase.as_deref(), json)
+                {
+                    report_cli_error("gen", e);
+                }
+            } else {
+                // Force save can be set via --force or ENV for consistency
+                let force_resolved = ConfigResolver::resolve_force(*force);
+
+                if let Err(e) = flows::generation::execute(
+                    mnemonic.as_deref(),
+                    passphrase.as_deref(),
+                    json,
+                    *unsafe_show_secret,
+                    output.as_deref(),
+                    force_resolved,
+                ) {
+                    report_cli_error("gen", e);
+                }
             }
         }
 
@@ -44,6 +100,7 @@ fn main() {
             keypair,
             output,
             force,
+            raw,
         } => {
             let kp_path = require_keypair("sign", keypair.clone());
             let force_resolved = ConfigResolver::resolve_force(*force);
@@ -54,6 +111,7 @@ fn main() {
                 &kp_path,
                 output.as_deref(),
                 force_resolved,
+                *raw,
                 json,
             ) {
                 report_cli_error("sign", e);
@@ -77,7 +135,7 @@ fn main() {
                 from_file.as_deref(),
                 signature.as_deref(),
                 signature_file.as_deref(),
-                pubkey.as_deref(),
+                pubkey,
                 pubkey_file.as_deref(),
                 output.as_deref(),
                 force_resolved,
@@ -87,6 +145,16 @@ fn main() {
             }
         }
 
+        Commands::VerifyTx {
+            input,
+            input_format,
+            pubkey,
+        } => {
+            if let Err(e) = flows::verify::execute_tx(Some(input), *input_format, read, pubkey, json) {
+                report_cli_error("verify-tx", e);
+            }
+        }
+
         Commands::Base58 { action } => {
             if let Err(e) = flows::base58::execute(action, json) {
                 report_cli_error("base58", e);
@@ -95,53 +163,195 @@ fn main() {
 
         Commands::SignTx {
             input,
+            input_format,
             keypair,
             output,
+            output_dir,
             output_format,
             force,
             lookup_tables,
             assume_yes,
             max_fee,
+            require_fee_payer,
+            trust_program,
+            deny_unknown_programs,
+            show_message_bytes,
             summary_json,
+            combined_json,
+            qr,
+            plan,
+            exit_code_by_severity,
         } => {
-            let kp_path = require_keypair("sign-tx", keypair.clone());
-
             let out_fmt = ConfigResolver::resolve_output_format(*output_format);
             let force_resolved = ConfigResolver::resolve_force(*force);
             let yes_resolved = ConfigResolver::resolve_yes(*assume_yes);
             let fee_resolved = ConfigResolver::resolve_max_fee(*max_fee);
 
-            if let Err(e) = flows::sign_tx::execute(
-                Some(input.as_str()),
-                &kp_path,
-                output.as_deref(),
-                json,
-                out_fmt,
-                force_resolved,
-                lookup_tables.as_deref(),
-                yes_resolved,
-                fee_resolved,
-                *summary_json,
-            ) {
-                report_cli_error("sign-tx", e);
+            let opts = flows::sign_tx::SignTxOptions {
+                input: Some(input.as_str()),
+                input_format: *input_format,
+                read,
+                output: output.as_deref(),
+                output_dir: output_dir.as_deref(),
+                pretty_json: json,
+                out_override: out_fmt,
+                force: force_resolved,
+                lookup_tables_path: lookup_tables.as_deref(),
+                assume_yes: yes_resolved,
+                show_message_bytes: *show_message_bytes,
+                rent: load_rent_config(cli.rent_config.as_deref()),
+                key_format,
+                warning_order: cli.sort_warnings,
+                color,
+                max_fee: fee_resolved,
+                require_fee_payer: *require_fee_payer,
+                trusted_programs: trust_program,
+                deny_unknown_programs: *deny_unknown_programs,
+                summary_json: *summary_json,
+                combined_json: *combined_json,
+                qr: *qr,
+            };
+
+            if let Some(extra) = plan {
+                let keypairs: Vec<String> = ConfigResolver::resolve_keypair(keypair.clone())
+                    .into_iter()
+                    .chain(extra.iter().cloned())
+                    .collect();
+                if keypairs.is_empty() {
+                    fail_invalid_input(
+                        "sign-tx",
+                        "--plan needs at least one keypair (use --keypair, SOLANA_SIGNER_KEYPAIR env or --plan FILE...)",
+                    );
+                }
+                if let Err(e) = flows::sign_tx::execute_plan(&keypairs, &opts) {
+                    report_cli_error("sign-tx", e);
+                }
+                return;
+            }
+
+            let kp_path = require_keypair("sign-tx", keypair.clone());
+
+            match flows::sign_tx::execute(&kp_path, &opts) {
+                Ok(code) if *exit_code_by_severity => std::process::exit(code),
+                Ok(_) => {}
+                Err(e) => report_cli_error("sign-tx", e),
+            }
+        }
+
+        Commands::MergeSigs {
+            inputs,
+            output,
+            output_format,
+            force,
+        } => {
+            let out_fmt = ConfigResolver::resolve_output_format(*output_format);
+            let force_resolved = ConfigResolver::resolve_force(*force);
+
+            if let Err(e) =
+                flows::merge_sigs::execute(inputs, read, output.as_deref(), json, out_fmt, force_resolved)
+            {
+                report_cli_error("merge-sigs", e);
+            }
+        }
+
+        Commands::Inspect { input, input_format, network } => {
+            if let Err(e) = flows::inspect::execute(Some(input), *input_format, read, (*network).into(), key_format, json) {
+                report_cli_error("inspect", e);
+            }
+        }
+
+        Commands::MessageHash {
+            input,
+            input_format,
+            hash_algo,
+        } => {
+            if let Err(e) = flows::message_hash::execute(Some(input), *input_format, read, *hash_algo, json) {
+                report_cli_error("message-hash", e);
             }
         }
 
         Commands::Analyze {
             input,
+            input_format,
             lookup_tables,
             pubkey,
+            message_only,
+            trust_program,
+            ignore_signer_check,
+            time_budget,
             summary_json,
+            count_only,
+            format,
+            exit_code_by_severity,
         } => {
-            if let Err(e) = flows::analyze::execute(
-                Some(input.as_str()),
-                pubkey.as_deref(),
-                lookup_tables.as_deref(),
+            let opts = flows::analyze::AnalyzeOptions {
+                input: Some(input.as_str()),
+                input_format: *input_format,
+                read,
+                lookup_tables_path: lookup_tables.as_deref(),
+                message_only: *message_only,
+                trusted_programs: trust_program,
+                ignore_signer_check: *ignore_signer_check,
+                time_budget_ms: *time_budget,
+                rent: load_rent_config(cli.rent_config.as_deref()),
+                key_format,
+                warning_order: cli.sort_warnings,
+                color,
+            };
+            if pubkey.len() > 1 {
+                if *count_only || *summary_json || *format == AnalysisFormat::Markdown {
+                    fail_invalid_input(
+                        "analyze",
+                        "repeated --pubkey prints a JSON map of analyses; it cannot be combined with --count-only, --summary-json or --format markdown",
+                    );
+                }
+                match flows::analyze::execute_multi(&opts, pubkey) {
+                    Ok(code) if *exit_code_by_severity => std::process::exit(code),
+                    Ok(_) => {}
+                    Err(e) => report_cli_error("analyze", e),
+                }
+                return;
+            }
+            if *count_only {
+                match flows::analyze::execute_count_only(&opts, pubkey.first().map(String::as_str)) {
+                    Ok(code) => std::process::exit(code),
+                    Err(e) => report_cli_error("analyze", e),
+                }
+            }
+            match flows::analyze::execute(
+                &opts,
+                pubkey.first().map(String::as_str),
                 *summary_json,
+                *format,
             ) {
-                report_cli_error("analyze", e);
+                Ok(code) if *exit_code_by_severity => std::process::exit(code),
+                Ok(_) => {}
+                Err(e) => report_cli_error("analyze", e),
             }
         }
+
+        #[cfg(feature = "protocol-extensions")]
+        Commands::DecodeLight {
+            program,
+            data,
+            encoding,
+        } => {
+            if let Err(e) = flows::decode_light::execute(program, data, *encoding, json) {
+                report_cli_error("decode-light", e);
+            }
+        }
+
+        Commands::Completions { shell } => flows::completions::execute(*shell),
+    }
+}
+
+/// Rent parameters from `--rent-config`, or Solana's values; only commands that analyze
+/// a transaction read the file.
+fn load_rent_config(path: Option<&str>) -> RentConfig {
+    match path {
+        Some(path) => read_rent_config(path)
+            .unwrap_or_else(|e| fail_invalid_input("rent-config", &e.to_string())),
+        None => RentConfig::default(),
     }
 }
 
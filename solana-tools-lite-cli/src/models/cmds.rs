@@ -1,4 +1,7 @@
 use clap::{ArgGroup, Subcommand};
+use solana_tools_lite::crypto::hash::HashAlgo;
+use solana_tools_lite::models::network::Network;
+use solana_tools_lite::serde::InputFormat;
 
 #[derive(Subcommand, Debug)]
 pub enum Commands {
@@ -7,6 +10,13 @@ pub enum Commands {
         /// Read mnemonic from file or stdin ("-"). If omitted, a new mnemonic is generated.
         #[arg(long, value_name = "FILE")]
         mnemonic: Option<String>,
+        /// Read a raw 64-byte BIP-39 seed (128 hex chars) from file or stdin ("-") instead of a mnemonic
+        #[arg(
+            long = "seed-hex",
+            value_name = "FILE",
+            conflicts_with_all = ["mnemonic", "passphrase", "derive_only"]
+        )]
+        seed_hex: Option<String>,
         /// Read passphrase from file or stdin ("-"). Optional.
         #[arg(long, value_name = "FILE")]
         passphrase: Option<String>,
@@ -19,6 +29,22 @@ pub enum Commands {
         /// Force save(override) a wallet file [env: SOLANA_TOOLS_LITE_FORCE]
         #[arg(long, short, default_value = "false")]
         force: bool,
+        /// Only print the address derived from --mnemonic; nothing is written
+        #[arg(
+            long = "derive-only",
+            default_value = "false",
+            requires = "mnemonic",
+            conflicts_with_all = ["output", "unsafe_show_secret"]
+        )]
+        derive_only: bool,
+        /// Derive accounts 0..N (m/44'/501'/<index>'/0') from --mnemonic; each is saved as wallet-<index>.json in the --output directory
+        #[arg(
+            long,
+            value_name = "N",
+            requires = "mnemonic",
+            value_parser = clap::value_parser!(u32).range(1..)
+        )]
+        count: Option<u32>,
     },
 
     /// Sign a message
@@ -32,7 +58,7 @@ pub enum Commands {
         #[arg(long = "from-file", value_name = "FILE", group = "data_source")]
         from_file: Option<String>,
 
-        /// Path to keypair file (stdin disabled for secrets) [env: SOLANA_SIGNER_KEYPAIR]
+        /// Path to keypair file, or env:VAR to read it from an environment variable (stdin disabled for secrets) [env: SOLANA_SIGNER_KEYPAIR]
         #[arg(long, short = 'k')]
         keypair: Option<String>,
 
@@ -43,6 +69,10 @@ pub enum Commands {
         /// Force save(override) a file [env: SOLANA_TOOLS_LITE_FORCE]
         #[arg(long, short, default_value = "false")]
         force: bool,
+
+        /// Blind-sign the exact bytes (file read as binary); refused if they decode as a transaction
+        #[arg(long, default_value = "false")]
+        raw: bool,
     },
 
     /// Verify a signature
@@ -72,11 +102,11 @@ pub enum Commands {
         #[arg(long = "signature-file", value_name = "FILE", group = "sig_src")]
         signature_file: Option<String>,
 
-        /// Public key (Base58, inline)
+        /// Public key (Base58, inline). Repeat to try several candidate signers.
         #[arg(long, group = "pk_src")]
-        pubkey: Option<String>,
+        pubkey: Vec<String>,
 
-        /// Read public key from file or stdin ("-")
+        /// Read public key(s) from file or stdin ("-"), one per line
         #[arg(long = "pubkey-file", value_name = "FILE", group = "pk_src")]
         pubkey_file: Option<String>,
 
@@ -89,6 +119,21 @@ pub enum Commands {
         force: bool,
     },
 
+    /// Check one signer's signature slot in a transaction (JSON/Base64/Base58)
+    VerifyTx {
+        /// Path to input transaction (UI JSON/Base64/Base58)
+        #[arg(long, short = 'i')]
+        input: String,
+
+        /// Force input format (json|base64|base58) instead of auto-detection; decode errors are fatal
+        #[arg(long = "input-format", value_enum)]
+        input_format: Option<InFmt>,
+
+        /// Signer public key (Base58) whose slot is checked
+        #[arg(long)]
+        pubkey: String,
+    },
+
     /// Base58 encode/decode
     Base58 {
         #[command(subcommand)]
@@ -101,11 +146,15 @@ pub enum Commands {
         #[arg(long, short = 'i')]
         input: String,
 
+        /// Force input format (json|base64|base58) instead of auto-detection; decode errors are fatal
+        #[arg(long = "input-format", value_enum)]
+        input_format: Option<InFmt>,
+
         /// Optional lookup table file (JSON map: table address -> array of addresses)
         #[arg(long = "tables", value_name = "FILE")]
         lookup_tables: Option<String>,
 
-        /// Path to keypair file (stdin disabled for secrets) [env: SOLANA_SIGNER_KEYPAIR]
+        /// Path to keypair file, or env:VAR to read it from an environment variable (stdin disabled for secrets) [env: SOLANA_SIGNER_KEYPAIR]
         #[arg(long, short = 'k')]
         keypair: Option<String>,
 
@@ -113,6 +162,10 @@ pub enum Commands {
         #[arg(long, short = 'o')]
         output: Option<String>,
 
+        /// Write the signed tx into this directory, named after its first signature
+        #[arg(long = "output-dir", value_name = "DIR", conflicts_with = "output")]
+        output_dir: Option<String>,
+
         /// Force output format (json|base64|base58). If not specified, we mirror the input format. [env: SOLANA_TOOLS_LITE_OUTPUT_FORMAT]
         #[arg(long = "output-format", value_enum)]
         output_format: Option<OutFmt>,
@@ -129,9 +182,95 @@ pub enum Commands {
         #[arg(long = "max-fee", value_name = "LAMPORTS")]
         max_fee: Option<u64>,
 
+        /// Refuse to sign unless the signer is (true) or is not (false) the fee payer
+        #[arg(long = "require-fee-payer", value_name = "BOOL")]
+        require_fee_payer: Option<bool>,
+
+        /// Trusted program id (Base58); suppresses its "unknown program" warning. Repeatable.
+        #[arg(long = "trust-program", value_name = "PUBKEY")]
+        trust_program: Vec<String>,
+
+        /// Refuse to sign when the transaction calls a program that is neither known nor trusted
+        #[arg(long = "deny-unknown-programs", action = clap::ArgAction::SetTrue)]
+        deny_unknown_programs: bool,
+
+        /// Print the exact message bytes to be signed (base58|base64|hex) to stderr before the prompt
+        #[arg(long = "show-message-bytes", value_enum, value_name = "ENCODING")]
+        show_message_bytes: Option<DataEncoding>,
+
         /// Emit signing summary as JSON to stdout (requires --output for signed tx)
         #[arg(long = "summary-json", default_value = "false")]
         summary_json: bool,
+
+        /// Emit {analysis, summary, signed} as one JSON object to stdout (requires --output for signed tx)
+        #[arg(long = "combined-json", conflicts_with = "summary_json")]
+        combined_json: bool,
+
+        /// Print the signed tx (Base64) as QR code(s) to stdout for air-gapped transfer; split into numbered codes when large
+        #[arg(long = "qr", conflicts_with_all = ["summary_json", "combined_json"])]
+        qr: bool,
+
+        /// Dry run: report which required signer slots --keypair and these extra keypairs fill, as JSON; nothing is signed (--max-fee / --require-fee-payer still apply)
+        #[arg(
+            long = "plan",
+            value_name = "KEYPAIR",
+            num_args = 0..,
+            conflicts_with_all = ["output", "output_dir", "summary_json", "combined_json", "qr"]
+        )]
+        plan: Option<Vec<String>>,
+
+        /// Exit with the highest warning severity: 0 clean, 3 info only, 4 warn, 5 critical
+        #[arg(long = "exit-code-by-severity", conflicts_with = "plan")]
+        exit_code_by_severity: bool,
+    },
+
+    /// Merge independently signed copies of the same transaction
+    MergeSigs {
+        /// Signed copies to merge (UI JSON/Base64/Base58); at least two
+        #[arg(value_name = "FILE", required = true, num_args = 2..)]
+        inputs: Vec<String>,
+
+        /// Optional output file (if not set, print to stdout)
+        #[arg(long, short = 'o')]
+        output: Option<String>,
+
+        /// Force output format (json|base64|base58). If not specified, we mirror the first input. [env: SOLANA_TOOLS_LITE_OUTPUT_FORMAT]
+        #[arg(long = "output-format", value_enum)]
+        output_format: Option<OutFmt>,
+
+        /// Force save(override) the output file when it exists [env: SOLANA_TOOLS_LITE_FORCE]
+        #[arg(long, short, default_value = "false")]
+        force: bool,
+    },
+
+    /// Dump the decoded structure of a transaction (JSON/Base64/Base58) without analysis
+    Inspect {
+        /// Path to input transaction (UI JSON/Base64/Base58)
+        #[arg(long, short = 'i')]
+        input: String,
+
+        /// Force input format (json|base64|base58) instead of auto-detection; decode errors are fatal
+        #[arg(long = "input-format", value_enum)]
+        input_format: Option<InFmt>,
+
+        /// Cluster whose known-program labels are shown (mainnet|devnet|testnet|localnet)
+        #[arg(long = "network", value_enum, value_name = "NETWORK", default_value = "mainnet")]
+        network: NetworkArg,
+    },
+
+    /// Print a digest of the message bytes signers sign, for coordinating off-chain
+    MessageHash {
+        /// Path to input transaction (UI JSON/Base64/Base58)
+        #[arg(long, short = 'i')]
+        input: String,
+
+        /// Force input format (json|base64|base58) instead of auto-detection; decode errors are fatal
+        #[arg(long = "input-format", value_enum)]
+        input_format: Option<InFmt>,
+
+        /// Digest algorithm applied to the message bytes
+        #[arg(long = "hash-algo", value_enum, default_value = "sha256")]
+        hash_algo: HashAlgoArg,
     },
 
     /// Analyze a transaction file (JSON/Base64/Base58)
@@ -140,17 +279,78 @@ pub enum Commands {
         #[arg(long, short = 'i')]
         input: String,
 
+        /// Force input format (json|base64|base58) instead of auto-detection; decode errors are fatal
+        #[arg(long = "input-format", value_enum)]
+        input_format: Option<InFmt>,
+
         /// Optional lookup table file (JSON map: table address -> array of addresses)
         #[arg(long = "tables", value_name = "FILE")]
         lookup_tables: Option<String>,
 
         /// Public key to analyze as (Base58). If not provided, uses first signer from message.
+        /// Repeat to analyze as several signers; prints a JSON map of analyses keyed by pubkey.
         #[arg(long, short = 'p')]
-        pubkey: Option<String>,
+        pubkey: Vec<String>,
+
+        /// Treat input as a serialized message without signatures (Base64/Base58)
+        #[arg(long = "message-only", default_value = "false")]
+        message_only: bool,
+
+        /// Trusted program id (Base58); suppresses its "unknown program" warning. Repeatable.
+        #[arg(long = "trust-program", value_name = "PUBKEY")]
+        trust_program: Vec<String>,
+
+        /// Drop the "signer not required" and "signer not referenced" warnings (placeholder --pubkey)
+        #[arg(long = "ignore-signer-check")]
+        ignore_signer_check: bool,
+
+        /// Stop analysis after this many milliseconds; the partial result carries a warning
+        #[arg(long = "time-budget", value_name = "MS")]
+        time_budget: Option<u64>,
 
         /// Emit analysis summary as JSON to stdout
         #[arg(long = "summary-json", default_value = "false")]
         summary_json: bool,
+
+        /// Print only {warnings, critical, fee_lamports} as JSON; the exit code is the critical count (max 63)
+        #[arg(long = "count-only", default_value = "false", conflicts_with = "summary_json")]
+        count_only: bool,
+
+        /// Analysis report format: text (stderr) or markdown (stdout, for pasting into reports)
+        #[arg(
+            long = "format",
+            value_enum,
+            default_value = "text",
+            conflicts_with_all = ["summary_json", "count_only"]
+        )]
+        format: AnalysisFormat,
+
+        /// Exit with the highest warning severity: 0 clean, 3 info only, 4 warn, 5 critical
+        #[arg(long = "exit-code-by-severity", conflicts_with = "count_only")]
+        exit_code_by_severity: bool,
+    },
+
+    /// Decode Light Protocol instruction data and print the parsed action
+    #[cfg(feature = "protocol-extensions")]
+    DecodeLight {
+        /// Light Protocol program id (Base58)
+        #[arg(long)]
+        program: String,
+
+        /// Instruction data (Base58; `0x`-prefixed hex and Base64 are detected too)
+        #[arg(long)]
+        data: String,
+
+        /// Force the data encoding instead of auto-detection
+        #[arg(long, value_enum)]
+        encoding: Option<DataEncoding>,
+    },
+
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Target shell
+        #[arg(value_enum)]
+        shell: CompletionShell,
     },
 }
 
@@ -163,6 +363,9 @@ pub enum Base58Action {
     Decode {
         #[arg(short, long)]
         input: String,
+        /// Try to parse the decoded bytes as a wire transaction and print its structure
+        #[arg(long = "as-transaction", default_value = "false")]
+        as_transaction: bool,
     },
 }
 
@@ -172,3 +375,112 @@ pub enum OutFmt {
     Base64,
     Base58,
 }
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataEncoding {
+    Base58,
+    Base64,
+    Hex,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InFmt {
+    Json,
+    Base64,
+    Base58,
+}
+
+impl From<InFmt> for InputFormat {
+    fn from(fmt: InFmt) -> Self {
+        match fmt {
+            InFmt::Json => InputFormat::Json,
+            InFmt::Base64 => InputFormat::Base64,
+            InFmt::Base58 => InputFormat::Base58,
+        }
+    }
+}
+
+/// Report format of `analyze --format`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnalysisFormat {
+    /// Human-readable summary on stderr.
+    Text,
+    /// Markdown tables and a warnings list on stdout.
+    Markdown,
+}
+
+/// Order of analysis warnings in presenter and summary output.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WarningOrder {
+    /// Most severe first, then by warning kind and detail (stable across versions).
+    Severity,
+    /// Detection order.
+    #[default]
+    None,
+}
+
+/// Cluster whose program labels `inspect` shows (`--network`).
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NetworkArg {
+    Mainnet,
+    Devnet,
+    Testnet,
+    Localnet,
+}
+
+impl From<NetworkArg> for Network {
+    fn from(network: NetworkArg) -> Self {
+        match network {
+            NetworkArg::Mainnet => Network::Mainnet,
+            NetworkArg::Devnet => Network::Devnet,
+            NetworkArg::Testnet => Network::Testnet,
+            NetworkArg::Localnet => Network::Localnet,
+        }
+    }
+}
+
+/// Digest algorithms for `message-hash`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashAlgoArg {
+    Sha256,
+    Blake3,
+    Keccak256,
+}
+
+impl From<HashAlgoArg> for HashAlgo {
+    fn from(algo: HashAlgoArg) -> Self {
+        match algo {
+            HashAlgoArg::Sha256 => HashAlgo::Sha256,
+            HashAlgoArg::Blake3 => HashAlgo::Blake3,
+            HashAlgoArg::Keccak256 => HashAlgo::Keccak256,
+        }
+    }
+}
+
+/// Shells supported by `completions`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+    Powershell,
+}
+
+impl From<CompletionShell> for clap_complete::Shell {
+    fn from(shell: CompletionShell) -> Self {
+        match shell {
+            CompletionShell::Bash => clap_complete::Shell::Bash,
+            CompletionShell::Zsh => clap_complete::Shell::Zsh,
+            CompletionShell::Fish => clap_complete::Shell::Fish,
+            CompletionShell::Powershell => clap_complete::Shell::PowerShell,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorMode {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
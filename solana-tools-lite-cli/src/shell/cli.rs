@@ -1,4 +1,4 @@
-use crate::models::cmds::Commands;
+use crate::models::cmds::{ColorMode, Commands, WarningOrder};
 use clap::Parser;
 
 #[derive(Parser, Debug)]
@@ -14,6 +14,49 @@ pub struct Cli {
         help = "Output as JSON (pretty) [env: SOLANA_TOOLS_LITE_JSON]"
     )]
     pub json: bool,
+    #[arg(
+        global = true,
+        long = "color",
+        value_enum,
+        value_name = "WHEN",
+        help = "Colorize output (auto|always|never); auto detects a TTY [env: NO_COLOR]"
+    )]
+    pub color: Option<ColorMode>,
+    #[arg(
+        global = true,
+        long = "max-input-size",
+        value_name = "BYTES",
+        help = "Maximum transaction or message input read from stdin (default 4 MB) [env: SOLANA_TOOLS_LITE_MAX_INPUT_SIZE]"
+    )]
+    pub max_input_size: Option<u64>,
+    #[arg(
+        global = true,
+        long = "sort-warnings",
+        value_enum,
+        value_name = "ORDER",
+        default_value = "none",
+        help = "Order analysis warnings (severity|none); none (default) keeps detection order"
+    )]
+    pub sort_warnings: WarningOrder,
+    #[arg(
+        global = true,
+        long = "strict-signatures",
+        help = "Reject transactions whose signature count differs from the header, whose signatures are not 64-byte Base58, or that carry trailing data"
+    )]
+    pub strict_signatures: bool,
+    #[arg(
+        global = true,
+        long = "rent-config",
+        value_name = "FILE",
+        help = "Rent parameters JSON {lamports_per_byte_year, exemption_threshold} for CreateAccount rent estimates (default: Solana's)"
+    )]
+    pub rent_config: Option<String>,
+    #[arg(
+        global = true,
+        long = "short-keys",
+        help = "Abbreviate public keys in human-readable output (e.g. 7xKX…Vq2d); JSON keeps full keys"
+    )]
+    pub short_keys: bool,
     #[command(subcommand)]
     pub command: Commands,
 }
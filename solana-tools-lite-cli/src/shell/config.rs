@@ -1,4 +1,4 @@
-use crate::models::cmds::OutFmt;
+use crate::models::cmds::{ColorMode, OutFmt};
 use std::env;
 
 /// Resolve global and command-specific configuration from environment variables.
@@ -19,6 +19,15 @@ impl ConfigResolver {
         })
     }
 
+    /// Resolve the stdin size limit (bytes) with fallback to environment.
+    pub fn resolve_max_input_size(explicit: Option<u64>) -> Option<u64> {
+        explicit.or_else(|| {
+            env::var("SOLANA_TOOLS_LITE_MAX_INPUT_SIZE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+        })
+    }
+
     /// Resolve output format with fallback to environment.
     pub fn resolve_output_format(explicit: Option<OutFmt>) -> Option<OutFmt> {
         explicit.or_else(|| {
@@ -62,4 +71,14 @@ impl ConfigResolver {
             .map(|v| v == "true" || v == "1")
             .unwrap_or(false)
     }
+
+    /// Resolve color mode; a non-empty `NO_COLOR` disables colors unless `--color` is given.
+    pub fn resolve_color(explicit: Option<ColorMode>) -> ColorMode {
+        explicit.unwrap_or_else(|| {
+            match env::var("NO_COLOR") {
+                Ok(v) if !v.is_empty() => ColorMode::Never,
+                _ => ColorMode::Auto,
+            }
+        })
+    }
 }
@@ -9,13 +9,15 @@ use thiserror::Error;
 pub enum CliError {
     #[error(transparent)]
     Core(#[from] ToolError),
-    #[error("--summary-json requires --output (file) to keep signed tx off stdout")]
-    SummaryRequiresOutput,
+    #[error("{flag} requires --output (file) to keep signed tx off stdout")]
+    SummaryRequiresOutput { flag: &'static str },
     #[error("Fee {fee_lamports} exceeds max-fee limit {max_lamports} lamports")]
     FeeLimitExceeded {
         fee_lamports: u128,
         max_lamports: u64,
     },
+    #[error("Signer fee payer role does not match --require-fee-payer {required}")]
+    FeePayerMismatch { required: bool },
     #[error("User rejected signing")]
     UserRejected,
     #[error("failed to encode summary json: {0}")]
@@ -30,8 +32,10 @@ impl AsExitCode for CliError {
     fn as_exit_code(&self) -> i32 {
         match self {
             CliError::Core(err) => err.as_exit_code(),
-            CliError::SummaryRequiresOutput | CliError::UserRejected => ExitCode::Usage.as_i32(),
-            CliError::FeeLimitExceeded { .. } => ExitCode::DataErr.as_i32(),
+            CliError::SummaryRequiresOutput { .. } | CliError::UserRejected => ExitCode::Usage.as_i32(),
+            CliError::FeeLimitExceeded { .. } | CliError::FeePayerMismatch { .. } => {
+                ExitCode::DataErr.as_i32()
+            }
             CliError::SummaryEncode(_) | CliError::PresentationEncode(_) => {
                 ExitCode::Software.as_i32()
             }
@@ -55,8 +59,8 @@ pub fn report_cli_error(context: &str, err: CliError) -> ! {
 fn format_cli_error(err: &CliError) -> String {
     match err {
         CliError::Core(core) => format_user_friendly(core),
-        CliError::SummaryRequiresOutput => {
-            "--summary-json requires --output (file) to keep signed tx off stdout".to_string()
+        CliError::SummaryRequiresOutput { flag } => {
+            format!("{flag} requires --output (file) to keep signed tx off stdout")
         }
         CliError::FeeLimitExceeded {
             fee_lamports,
@@ -65,6 +69,7 @@ fn format_cli_error(err: &CliError) -> String {
             "Fee {} exceeds max-fee limit {} lamports",
             fee_lamports, max_lamports
         ),
+        CliError::FeePayerMismatch { required } => fee_payer_mismatch_message(*required),
         CliError::UserRejected => "User rejected signing".to_string(),
         CliError::SummaryEncode(msg) => format!("failed to encode summary json: {msg}"),
         CliError::PresentationEncode(msg) => format!("failed to encode json output: {msg}"),
@@ -72,6 +77,14 @@ fn format_cli_error(err: &CliError) -> String {
     }
 }
 
+fn fee_payer_mismatch_message(required: bool) -> String {
+    if required {
+        "Refusing to sign: --require-fee-payer true but the signer is not the fee payer".to_string()
+    } else {
+        "Refusing to sign: --require-fee-payer false but the signer is the fee payer".to_string()
+    }
+}
+
 fn format_user_friendly(err: &ToolError) -> String {
     match err {
         ToolError::Bip39(e) => format_bip39(e),
@@ -82,7 +95,7 @@ fn format_user_friendly(err: &ToolError) -> String {
         ToolError::TransactionParse(e) => format_tx_parse(e),
         ToolError::Deserialize(e) => format_deserialize(e),
         ToolError::Verify(e) => format_verify(e),
-        ToolError::Io(io_err) => format_io(io_err),
+        ToolError::Io(io_err) => format!("{}{}", err.display_chain(), io_hint(io_err)),
         ToolError::FileExists { path } => {
             format!(
                 "Cannot create file '{}': already exists\nHint: Use --force to overwrite",
@@ -90,6 +103,9 @@ fn format_user_friendly(err: &ToolError) -> String {
             )
         }
         ToolError::InvalidInput(msg) => msg.clone(),
+        ToolError::AmountOverflow { field } => {
+            format!("Amount overflow: {} does not fit in u64 lamports", field)
+        }
         ToolError::ConfigurationError(msg) => format!("Configuration error: {}", msg),
     }
 }
@@ -119,6 +135,10 @@ fn format_sign(e: &SignError) -> String {
                 .to_string()
         }
         SignError::InvalidKeyLength => "Secret key must be exactly 32 bytes".to_string(),
+        SignError::InvalidKeypairJson(reason) => format!(
+            "Invalid keypair JSON: {}\nHint: Use {{\"secretKey\": \"<Base58>\"}} or {{\"keypair\": [..64 bytes]}}",
+            reason
+        ),
         SignError::SigningFailed(msg) => {
             format!(
                 "Failed to sign transaction: {}\nHint: Verify your secret key is valid",
@@ -137,6 +157,18 @@ fn format_sign(e: &SignError) -> String {
                 e
             )
         }
+        SignError::FeeLimitExceeded {
+            fee_lamports,
+            max_lamports,
+        } => format!(
+            "Fee {} exceeds max-fee limit {} lamports",
+            fee_lamports, max_lamports
+        ),
+        SignError::FeePayerMismatch { required } => fee_payer_mismatch_message(*required),
+        SignError::UnknownProgramDenied { program_id } => {
+            format!("Refusing to sign: unknown program {}", program_id)
+        }
+        SignError::Rejected => "User rejected signing".to_string(),
     }
 }
 
@@ -203,6 +235,12 @@ fn format_tx_parse(e: &TransactionParseError) -> String {
         TransactionParseError::InvalidSignatureFormat(msg) => {
             format!("Invalid signature format: {}", msg)
         }
+        TransactionParseError::TooManySignatures { found, required } => {
+            format!(
+                "Transaction carries {} signatures but its header requires {}\nHint: Extra signatures cannot be encoded on the wire; remove them",
+                found, required
+            )
+        }
         TransactionParseError::InvalidBlockhashLength(len) => {
             format!("Invalid blockhash length: expected 32 bytes, got {}", len)
         }
@@ -229,6 +267,10 @@ fn format_deserialize(e: &DeserializeError) -> String {
                 msg
             )
         }
+        DeserializeError::VersionMismatch { expected, found } => format!(
+            "Message version mismatch: expected a {} message, found {}\nHint: The 0x80 prefix byte marks a versioned (v0) message",
+            expected, found
+        ),
     }
 }
 
@@ -255,20 +297,14 @@ fn format_verify(e: &VerifyError) -> String {
     }
 }
 
-
-fn format_io(err: &solana_tools_lite::errors::IoError) -> String {
-    // Core error already formats nicely as "io(path: error)" or "io(error)"
-    // We just want to append a hint if possible.
-    
-    let hint = match err.kind() {
+/// Hint appended to an I/O error's `display_chain`, by error kind.
+fn io_hint(err: &solana_tools_lite::errors::IoError) -> &'static str {
+    match err.kind() {
         io::ErrorKind::NotFound => "\nHint: Check that the file path is correct",
         io::ErrorKind::PermissionDenied => {
             "\nHint: Ensure you have read/write permissions for this file"
         }
         io::ErrorKind::AlreadyExists => "\nHint: Use --force to overwrite existing files",
         _ => "",
-    };
-
-    // Use the core Display repr which includes path and source error
-    format!("{}{}", err, hint)
+    }
 }
@@ -0,0 +1,51 @@
+//! Minimal stderr logger for diagnostics emitted by the library through the `log` facade.
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::env;
+
+/// Level used when `RUST_LOG` is unset or not a plain level name.
+const DEFAULT_LEVEL: LevelFilter = LevelFilter::Warn;
+
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let label = match record.level() {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        eprintln!("[{}] {}", label, record.args());
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// Parse a `RUST_LOG` value (`off`, `error`, `warn`, `info`, `debug`, `trace`; case-insensitive).
+pub fn parse_level(value: &str) -> Option<LevelFilter> {
+    value.trim().parse().ok()
+}
+
+/// Install the stderr logger at the level named by `RUST_LOG` (default `warn`).
+///
+/// Calling it twice is harmless: the second installation is ignored.
+pub fn init() {
+    let level = env::var("RUST_LOG")
+        .ok()
+        .and_then(|v| parse_level(&v))
+        .unwrap_or(DEFAULT_LEVEL);
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(level);
+    }
+}
@@ -1,11 +1,15 @@
 use solana_tools_lite::models::input_transaction::{
-    InputTransaction, UiRawMessage, UiRawMessageV0, UiTransaction, UiCompiledInstruction,
+    InputTransaction, UiRawMessage, UiRawMessageLegacy, UiRawMessageV0, UiTransaction,
+    UiCompiledInstruction,
 };
 use solana_tools_lite::models::message::MessageHeader;
 use solana_tools_lite::bs58;
 use solana_tools_lite::crypto::signing::SigningKey;
-use solana_tools_lite_cli::flows::analyze;
+use solana_tools_lite_cli::flows::analyze::{self, AnalyzeOptions};
+use solana_tools_lite_cli::models::cmds::{AnalysisFormat, InFmt};
 use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
 use tempfile::TempDir;
 
 fn build_v0_tx_json(signer_pk: &str) -> String {
@@ -68,7 +72,7 @@ fn analyze_flow_reads_transaction_and_outputs_summary() {
     // Use the function as it exists in production (without signer arg if I reverted correctly)
     // Wait, Step 10750 shows analyze_transaction DOES accept signer: 
     // pub fn analyze_transaction(message: &Message, signer: &PubkeyBase58, tables: Option<&LookupTableEntry>) -> TxAnalysis
-    let analysis = solana_tools_lite::handlers::analysis::analyze_transaction(&tx.message, &signer, None);
+    let analysis = solana_tools_lite::handlers::analysis::analyze_transaction(&tx.message, &signer, None, None, None);
     
     // 4. Simulate summary generation
     // Step 10750 shows build_signing_summary DOES NOT accept signer (it was reverted):
@@ -96,10 +100,273 @@ fn analyze_flow_execution_smoke_test() {
     let tx_path = dir.path().join("tx.json");
     fs::write(&tx_path, tx_json).unwrap();
     
+    let opts = AnalyzeOptions {
+        input: Some(tx_path.to_str().unwrap()),
+        ..Default::default()
+    };
     analyze::execute(
-        Some(tx_path.to_str().unwrap()),
-        None,
+        &opts,
         None,
         true, // summary_json = true
+        AnalysisFormat::Text,
     ).expect("analyze flow should succeed without panic");
 }
+
+#[test]
+fn analyze_flow_message_only_input() {
+    use solana_tools_lite::codec::serialize_message;
+    use solana_tools_lite::models::Transaction;
+
+    let dir = TempDir::new().unwrap();
+    let key = SigningKey::from_bytes(&[1u8; 32]);
+    let signer_pk = bs58::encode(key.verifying_key().to_bytes()).into_string();
+
+    // Serialize only the message (no signatures short-vec), like web3.js `message.serialize()`
+    let input_tx: InputTransaction = serde_json::from_str(&build_v0_tx_json(&signer_pk)).unwrap();
+    let tx = Transaction::try_from(input_tx).unwrap();
+    let wire = serialize_message(&tx.message);
+    let message_b64 = solana_tools_lite::data_encoding::BASE64.encode(&wire);
+    let message_b58 = bs58::encode(&wire).into_string();
+
+    // Auto-detection tries Base64 first, like transaction input; this Base58 text also
+    // passes as Base64, so its format is forced
+    let inputs = [
+        ("message.b64", message_b64, None),
+        ("message.b58", message_b58, Some(InFmt::Base58)),
+    ];
+    for (name, encoded, input_format) in inputs {
+        let msg_path = dir.path().join(name);
+        fs::write(&msg_path, encoded).unwrap();
+
+        let opts = AnalyzeOptions {
+            input: Some(msg_path.to_str().unwrap()),
+            input_format,
+            message_only: true,
+            ..Default::default()
+        };
+        let analyses = analyze::analyze_per_signer(&opts, std::slice::from_ref(&signer_pk))
+            .expect("message-only analyze should succeed");
+        let analysis = &analyses[&signer_pk];
+
+        assert_eq!(analysis.message_version, "v0", "{name}");
+        assert_eq!(analysis.recent_blockhash, bs58::encode([9u8; 32]).into_string(), "{name}");
+        assert_eq!(analysis.transfers.len(), 1, "{name}");
+        assert_eq!(analysis.transfers[0].lamports, 1_000, "{name}");
+        assert!(analysis.is_fee_payer, "{name}");
+
+        analyze::execute(&opts, None, true, AnalysisFormat::Text)
+            .expect("message-only analyze should succeed");
+    }
+}
+
+#[test]
+fn analyze_per_signer_keys_analyses_by_pubkey() {
+    let dir = TempDir::new().unwrap();
+    let key = SigningKey::from_bytes(&[1u8; 32]);
+    let fee_payer = bs58::encode(key.verifying_key().to_bytes()).into_string();
+    let other = bs58::encode([19u8; 32]).into_string();
+
+    let tx_path = dir.path().join("tx.json");
+    fs::write(&tx_path, build_v0_tx_json(&fee_payer)).unwrap();
+
+    let opts = AnalyzeOptions {
+        input: Some(tx_path.to_str().unwrap()),
+        ..Default::default()
+    };
+    let analyses = analyze::analyze_per_signer(&opts, &[fee_payer.clone(), other.clone()])
+    .expect("multi-signer analysis should succeed");
+
+    assert_eq!(analyses.len(), 2);
+    assert!(analyses[&fee_payer].is_fee_payer);
+    assert!(!analyses[&other].is_fee_payer);
+}
+
+#[test]
+fn analyze_ignore_signer_check_drops_signer_warnings() {
+    use solana_tools_lite::models::analysis::AnalysisWarning;
+
+    let dir = TempDir::new().unwrap();
+    let key = SigningKey::from_bytes(&[1u8; 32]);
+    let fee_payer = bs58::encode(key.verifying_key().to_bytes()).into_string();
+    let placeholder = bs58::encode([19u8; 32]).into_string();
+
+    let tx_path = dir.path().join("tx.json");
+    fs::write(&tx_path, build_v0_tx_json(&fee_payer)).unwrap();
+
+    let analyze_as_placeholder = |ignore_signer_check| {
+        let opts = AnalyzeOptions {
+            input: Some(tx_path.to_str().unwrap()),
+            ignore_signer_check,
+            ..Default::default()
+        };
+        analyze::analyze_per_signer(&opts, std::slice::from_ref(&placeholder))
+        .expect("analysis should succeed")
+        .remove(&placeholder)
+        .unwrap()
+    };
+    let is_signer_check = |w: &AnalysisWarning| {
+        matches!(
+            w,
+            AnalysisWarning::SignerNotRequired | AnalysisWarning::SignerNotReferencedByInstructions
+        )
+    };
+
+    let checked = analyze_as_placeholder(false);
+    assert!(checked.warnings.iter().any(is_signer_check));
+
+    let ignored = analyze_as_placeholder(true);
+    assert!(!ignored.warnings.iter().any(is_signer_check));
+    let others = |a: &solana_tools_lite::models::analysis::TxAnalysis| {
+        a.warnings.iter().filter(|w| !is_signer_check(w)).count()
+    };
+    assert_eq!(others(&ignored), others(&checked));
+}
+
+#[test]
+fn analyze_flow_rejects_invalid_trusted_program() {
+    let trusted = ["not-a-pubkey".to_string()];
+    let opts = AnalyzeOptions {
+        input: Some("tx.json"), // not read: trusted programs are validated first
+        trusted_programs: &trusted,
+        ..Default::default()
+    };
+    let err = analyze::execute(&opts, None, false, AnalysisFormat::Text)
+    .expect_err("invalid --trust-program must be rejected");
+
+    assert!(format!("{err}").contains("Invalid trusted program id"));
+}
+
+/// CloseAccount and Approve, both in favour of a third party: two critical warnings.
+fn write_critical_tx(dir: &TempDir) -> PathBuf {
+    let key = SigningKey::from_bytes(&[1u8; 32]);
+    let signer_pk = bs58::encode(key.verifying_key().to_bytes()).into_string();
+    let token_account = bs58::encode([21u8; 32]).into_string();
+    let attacker = bs58::encode([22u8; 32]).into_string();
+
+    let mut approve = vec![4u8];
+    approve.extend_from_slice(&1_000u64.to_le_bytes());
+    let ui_tx = UiTransaction {
+        signatures: vec![bs58::encode([0u8; 64]).into_string()],
+        message: UiRawMessage::Legacy(UiRawMessageLegacy {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![
+                signer_pk,
+                token_account,
+                attacker,
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA".to_string(),
+            ],
+            recent_blockhash: bs58::encode([9u8; 32]).into_string(),
+            instructions: vec![
+                UiCompiledInstruction {
+                    program_id_index: 3,
+                    accounts: vec![1, 2, 0],
+                    data: bs58::encode([9u8]).into_string(),
+                },
+                UiCompiledInstruction {
+                    program_id_index: 3,
+                    accounts: vec![1, 2, 0],
+                    data: bs58::encode(&approve).into_string(),
+                },
+            ],
+        }),
+    };
+    let tx_path = dir.path().join("tx.json");
+    fs::write(&tx_path, serde_json::to_string(&ui_tx).unwrap()).unwrap();
+    tx_path
+}
+
+#[test]
+fn analyze_count_only_exit_code_is_critical_count() {
+    let dir = TempDir::new().unwrap();
+    let tx_path = write_critical_tx(&dir);
+
+    let opts = AnalyzeOptions {
+        input: Some(tx_path.to_str().unwrap()),
+        ..Default::default()
+    };
+    let code = analyze::execute_count_only(&opts, None)
+    .expect("count-only analysis");
+    assert_eq!(code, 2);
+}
+
+#[test]
+fn analyze_exit_code_by_severity_sets_process_exit_code() {
+    let dir = TempDir::new().unwrap();
+    let tx_path = write_critical_tx(&dir);
+
+    let status = Command::new(env!("CARGO_BIN_EXE_solana-tools-lite"))
+        .args(["analyze", "--input", tx_path.to_str().unwrap(), "--exit-code-by-severity"])
+        .output()
+        .expect("run analyze")
+        .status;
+    assert_eq!(status.code(), Some(5));
+
+    // Without the flag a successful analysis still exits 0
+    let status = Command::new(env!("CARGO_BIN_EXE_solana-tools-lite"))
+        .args(["analyze", "--input", tx_path.to_str().unwrap()])
+        .output()
+        .expect("run analyze")
+        .status;
+    assert_eq!(status.code(), Some(0));
+}
+
+#[test]
+fn analyze_max_input_size_caps_the_transaction_read_from_stdin() {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let signer_pk = bs58::encode(SigningKey::from_bytes(&[1u8; 32]).verifying_key().to_bytes())
+        .into_string();
+    let tx_json = build_v0_tx_json(&signer_pk);
+
+    let run = |extra: &[&str]| {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_solana-tools-lite"))
+            .args(["analyze", "--input", "-"])
+            .args(extra)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("run analyze");
+        // The reader may stop early once the cap is exceeded
+        let _ = child.stdin.take().unwrap().write_all(tx_json.as_bytes());
+        child.wait_with_output().expect("wait for analyze")
+    };
+
+    assert!(run(&[]).status.success());
+
+    let capped = run(&["--max-input-size", "16"]);
+    assert!(!capped.status.success());
+    assert!(String::from_utf8_lossy(&capped.stderr).contains("too large"));
+}
+
+#[test]
+fn analyze_strict_signatures_rejects_a_wrong_signature_count() {
+    let dir = TempDir::new().unwrap();
+    let signer_pk = bs58::encode(SigningKey::from_bytes(&[1u8; 32]).verifying_key().to_bytes())
+        .into_string();
+    // No signature slot for a header that requires one
+    let mut tx: serde_json::Value = serde_json::from_str(&build_v0_tx_json(&signer_pk)).unwrap();
+    tx["signatures"].as_array_mut().unwrap().clear();
+    let tx_path = dir.path().join("missing_sig.json");
+    fs::write(&tx_path, tx.to_string()).unwrap();
+
+    let run = |extra: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_solana-tools-lite"))
+            .args(["analyze", "--input", tx_path.to_str().unwrap()])
+            .args(extra)
+            .output()
+            .expect("run analyze")
+    };
+
+    let strict = run(&["--strict-signatures"]);
+    assert!(!strict.status.success());
+    assert!(String::from_utf8_lossy(&strict.stderr).contains("signature"));
+
+    let lenient = run(&[]);
+    assert!(lenient.status.success(), "stderr: {}", String::from_utf8_lossy(&lenient.stderr));
+}
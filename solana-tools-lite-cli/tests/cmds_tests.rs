@@ -2,7 +2,7 @@
 mod tests {
     use clap::Parser;
     use solana_tools_lite_cli::shell::cli::Cli;
-    use solana_tools_lite_cli::models::cmds::OutFmt;
+    use solana_tools_lite_cli::models::cmds::{AnalysisFormat, ColorMode, CompletionShell, DataEncoding, HashAlgoArg, InFmt, NetworkArg, OutFmt, WarningOrder};
     use solana_tools_lite_cli::models::cmds::{Base58Action, Commands};
 
     /// Test that CLI arguments correctly parse into the `Gen` variant of `Commands`.
@@ -23,23 +23,74 @@ mod tests {
         match cli.command {
             Commands::Gen {
                 mnemonic,
+                seed_hex,
                 passphrase,
                 unsafe_show_secret,
                 output,
                 force,
+                derive_only,
+                count,
             } => {
                 assert_eq!(mnemonic.as_deref(), Some("./mnemonic.txt"));
+                assert!(seed_hex.is_none());
                 assert_eq!(passphrase.as_deref(), Some("pass"));
                 // False by default
                 assert_eq!(unsafe_show_secret, false);
                 assert_eq!(output.as_deref(), Some("./path"));
                 // False by default
                 assert_eq!(force, false);
+                assert!(!derive_only);
+                assert!(count.is_none());
             }
             _ => panic!("Parsed into wrong command variant"),
         }
     }
 
+    /// `gen --derive-only` needs a mnemonic and cannot be combined with saving or secrets.
+    #[test]
+    fn test_parse_gen_derive_only() {
+        let cli = Cli::parse_from(["solana-lite", "gen", "--derive-only", "--mnemonic", "-"]);
+        match cli.command {
+            Commands::Gen {
+                mnemonic,
+                derive_only,
+                ..
+            } => {
+                assert_eq!(mnemonic.as_deref(), Some("-"));
+                assert!(derive_only);
+            }
+            _ => panic!("Parsed into wrong command variant"),
+        }
+
+        assert!(Cli::try_parse_from(["solana-lite", "gen", "--derive-only"]).is_err());
+        assert!(
+            Cli::try_parse_from([
+                "solana-lite", "gen", "--derive-only", "--mnemonic", "-", "--output", "./w"
+            ])
+            .is_err()
+        );
+
+        assert!(
+            Cli::try_parse_from(["solana-lite", "gen", "--seed-hex", "seed.txt", "--mnemonic", "-"])
+                .is_err()
+        );
+    }
+
+    /// `gen --count` needs a mnemonic and at least one account.
+    #[test]
+    fn test_parse_gen_count() {
+        let cli = Cli::parse_from(["solana-lite", "gen", "--mnemonic", "-", "--count", "3"]);
+        match cli.command {
+            Commands::Gen { count, .. } => assert_eq!(count, Some(3)),
+            _ => panic!("Parsed into wrong command variant"),
+        }
+
+        assert!(Cli::try_parse_from(["solana-lite", "gen", "--count", "3"]).is_err());
+        assert!(
+            Cli::try_parse_from(["solana-lite", "gen", "--mnemonic", "-", "--count", "0"]).is_err()
+        );
+    }
+
     /// Test parsing the `sign` command with message and optional mnemonic.
     #[test]
     fn test_parse_sign_message_command() {
@@ -59,6 +110,7 @@ mod tests {
                 keypair,
                 output,
                 force,
+                raw,
             } => {
                 assert_eq!(message.as_deref(), Some("hello"));
                 assert_eq!(from_file, None);
@@ -69,6 +121,7 @@ mod tests {
                 // Defaults for optional flags
                 assert_eq!(output, None);
                 assert_eq!(force, false);
+                assert!(!raw);
             }
             _ => panic!("Parsed into wrong command variant"),
         }
@@ -93,6 +146,7 @@ mod tests {
                 keypair,
                 output,
                 force,
+                raw,
             } => {
                 assert_eq!(message, None);
                 assert_eq!(from_file.as_deref(), Some("./path/message.txt"));
@@ -102,6 +156,7 @@ mod tests {
                 );
                 assert_eq!(output, None);
                 assert_eq!(force, false);
+                assert!(!raw);
             }
             _ => panic!("Parsed into wrong command variant"),
         }
@@ -129,6 +184,7 @@ mod tests {
                 keypair,
                 output,
                 force,
+                raw,
             } => {
                 assert_eq!(message.as_deref(), Some("hello"));
                 assert!(from_file.is_none());
@@ -138,6 +194,7 @@ mod tests {
                 );
                 assert_eq!(output.as_deref(), Some("./out/result.json"));
                 assert!(force);
+                assert!(!raw);
             }
             _ => panic!("Parsed into wrong command variant"),
         }
@@ -163,6 +220,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_inspect_network_flag() {
+        let cli = Cli::parse_from(["solana-lite", "inspect", "-i", "tx.json"]);
+        match cli.command {
+            Commands::Inspect { network, .. } => assert_eq!(network, NetworkArg::Mainnet),
+            _ => panic!("Expected Inspect command"),
+        }
+
+        let cli = Cli::parse_from(["solana-lite", "inspect", "-i", "tx.json", "--network", "devnet"]);
+        match cli.command {
+            Commands::Inspect { network, .. } => assert_eq!(network, NetworkArg::Devnet),
+            _ => panic!("Expected Inspect command"),
+        }
+
+        assert!(Cli::try_parse_from(["solana-lite", "inspect", "-i", "x", "--network", "moonnet"]).is_err());
+        // Only inspect uses the cluster, so other commands do not accept the flag.
+        assert!(Cli::try_parse_from(["solana-lite", "analyze", "-i", "tx.json", "--network", "devnet"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_global_rent_config_flag() {
+        let cli = Cli::parse_from(["solana-lite", "analyze", "-i", "tx.json"]);
+        assert!(cli.rent_config.is_none());
+
+        let cli = Cli::parse_from(["solana-lite", "analyze", "-i", "tx.json", "--rent-config", "rent.json"]);
+        assert_eq!(cli.rent_config.as_deref(), Some("rent.json"));
+    }
+
+    #[test]
+    fn test_parse_global_short_keys_flag() {
+        let cli = Cli::parse_from(["solana-lite", "inspect", "-i", "tx.json"]);
+        assert!(!cli.short_keys);
+
+        let cli = Cli::parse_from(["solana-lite", "--short-keys", "analyze", "-i", "tx.json"]);
+        assert!(cli.short_keys);
+    }
+
+    #[test]
+    fn test_parse_verify_tx_command() {
+        let args = vec![
+            "solana-lite",
+            "verify-tx",
+            "--input",
+            "tx.b64",
+            "--input-format",
+            "base64",
+            "--pubkey",
+            "pub",
+        ];
+        let cli = Cli::parse_from(args);
+        match cli.command {
+            Commands::VerifyTx {
+                input,
+                input_format,
+                pubkey,
+            } => {
+                assert_eq!(input, "tx.b64");
+                assert_eq!(input_format, Some(InFmt::Base64));
+                assert_eq!(pubkey, "pub");
+            }
+            _ => panic!("Expected VerifyTx command"),
+        }
+
+        // --pubkey is required
+        assert!(Cli::try_parse_from(["solana-lite", "verify-tx", "--input", "tx.b64"]).is_err());
+    }
+
     /// Test parsing the `verify` command with inline message, signature, and pubkey.
     #[test]
     fn test_parse_verify_command_inline() {
@@ -194,7 +318,7 @@ mod tests {
                 assert_eq!(signature.as_deref(), Some("sig"));
                 assert!(signature_file.is_none());
 
-                assert_eq!(pubkey.as_deref(), Some("pub"));
+                assert_eq!(pubkey, vec!["pub"]);
                 assert!(pubkey_file.is_none());
 
                 assert!(output.is_none());
@@ -259,7 +383,7 @@ mod tests {
                 assert!(signature.is_none());
                 assert_eq!(signature_file.as_deref(), Some("./path/sig.bin"));
 
-                assert!(pubkey.is_none());
+                assert!(pubkey.is_empty());
                 assert_eq!(pubkey_file.as_deref(), Some("./path/pubkey.txt"));
 
                 assert!(output.is_none());
@@ -331,13 +455,27 @@ mod tests {
 
         match cli.command {
             Commands::Base58 { action } => match action {
-                Base58Action::Decode { input } => {
+                Base58Action::Decode {
+                    input,
+                    as_transaction,
+                } => {
                     assert_eq!(input, "cafebabe");
+                    assert!(!as_transaction);
                 }
                 _ => panic!("Expected Base58Action::Decode"),
             },
             _ => panic!("Parsed into wrong command variant"),
         }
+
+        let cli = Cli::parse_from([
+            "solana-lite", "base58", "decode", "--input", "cafebabe", "--as-transaction",
+        ]);
+        match cli.command {
+            Commands::Base58 {
+                action: Base58Action::Decode { as_transaction, .. },
+            } => assert!(as_transaction),
+            _ => panic!("Expected Base58Action::Decode"),
+        }
     }
     /// Test parsing the `sign-tx` command with all options provided.
     #[test]
@@ -361,15 +499,34 @@ mod tests {
         match cli.command {
             Commands::SignTx {
                 input,
+                input_format,
                 keypair,
                 output,
+                output_dir,
                 output_format,
                 force,
                 lookup_tables,
                 assume_yes,
                 max_fee,
+                require_fee_payer,
+                trust_program,
+                deny_unknown_programs,
+                show_message_bytes,
                 summary_json,
+                combined_json,
+                qr,
+                plan,
+                exit_code_by_severity,
             } => {
+                assert!(plan.is_none());
+                assert!(!qr);
+                assert!(!exit_code_by_severity);
+                assert!(trust_program.is_empty());
+                assert!(!deny_unknown_programs);
+                assert!(show_message_bytes.is_none());
+                assert_eq!(require_fee_payer, None);
+                assert_eq!(output_dir, None);
+                assert_eq!(input_format, None);
                 assert_eq!(input, "in.json");
                 assert_eq!(keypair.as_deref(), Some("wallet.json"));
                 assert_eq!(output.as_deref(), Some("out.json"));
@@ -380,11 +537,61 @@ mod tests {
                 assert!(!assume_yes);
                 assert!(max_fee.is_none());
                 assert!(!summary_json);
+                assert!(!combined_json);
             }
             _ => panic!("Parsed into wrong command variant"),
         }
     }
 
+    #[test]
+    fn test_parse_sign_tx_plan() {
+        let cli = Cli::parse_from(["solana-lite", "sign-tx", "-i", "tx.json", "--plan"]);
+        match cli.command {
+            Commands::SignTx { plan, keypair, .. } => {
+                assert_eq!(plan, Some(vec![]));
+                assert!(keypair.is_none());
+            }
+            _ => panic!("Parsed into wrong command variant"),
+        }
+
+        let cli = Cli::parse_from([
+            "solana-lite", "sign-tx", "-i", "tx.json", "-k", "a.json", "--plan", "b.json", "c.json",
+        ]);
+        match cli.command {
+            Commands::SignTx { plan, keypair, .. } => {
+                assert_eq!(plan, Some(vec!["b.json".to_string(), "c.json".to_string()]));
+                assert_eq!(keypair.as_deref(), Some("a.json"));
+            }
+            _ => panic!("Parsed into wrong command variant"),
+        }
+
+        let err = Cli::try_parse_from(["solana-lite", "sign-tx", "-i", "tx.json", "--plan", "-o", "out.json"]);
+        assert!(err.is_err(), "--plan never writes a signed transaction");
+    }
+
+    /// `--qr` replaces the stdout copy, so it cannot share stdout with the JSON summaries.
+    #[test]
+    fn test_parse_sign_tx_deny_unknown_programs() {
+        let cli = Cli::parse_from(["solana-lite", "sign-tx", "-i", "tx.json", "--deny-unknown-programs"]);
+        match cli.command {
+            Commands::SignTx { deny_unknown_programs, .. } => assert!(deny_unknown_programs),
+            _ => panic!("Expected SignTx command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_sign_tx_qr() {
+        let cli = Cli::parse_from(["solana-lite", "sign-tx", "-i", "tx.json", "--qr"]);
+        match cli.command {
+            Commands::SignTx { qr, .. } => assert!(qr),
+            _ => panic!("Parsed into wrong command variant"),
+        }
+        for flag in ["--summary-json", "--combined-json"] {
+            let err = Cli::try_parse_from(["solana-lite", "sign-tx", "-i", "tx.json", "-o", "o.b64", "--qr", flag]);
+            assert!(err.is_err(), "--qr conflicts with {flag}");
+        }
+    }
+
     /// Test parsing the `sign-tx` command with only required options.
     #[test]
     fn test_parse_sign_tx_minimal() {
@@ -400,15 +607,34 @@ mod tests {
         match cli.command {
             Commands::SignTx {
                 input,
+                input_format,
                 keypair,
                 output,
+                output_dir,
                 output_format,
                 force,
                 lookup_tables,
                 assume_yes,
                 max_fee,
+                require_fee_payer,
+                trust_program,
+                deny_unknown_programs,
+                show_message_bytes,
                 summary_json,
+                combined_json,
+                qr,
+                plan,
+                exit_code_by_severity,
             } => {
+                assert!(plan.is_none());
+                assert!(!qr);
+                assert!(!exit_code_by_severity);
+                assert!(trust_program.is_empty());
+                assert!(!deny_unknown_programs);
+                assert!(show_message_bytes.is_none());
+                assert_eq!(require_fee_payer, None);
+                assert_eq!(output_dir, None);
+                assert_eq!(input_format, None);
                 assert_eq!(input, "in.json");
                 assert_eq!(keypair.as_deref(), Some("wallet.json"));
                 assert_eq!(output, None);
@@ -417,6 +643,7 @@ mod tests {
                 assert_eq!(assume_yes, false);
                 assert!(max_fee.is_none());
                 assert!(!summary_json);
+                assert!(!combined_json);
 
                 assert!(matches!(output_format, None));
             }
@@ -424,6 +651,28 @@ mod tests {
         }
     }
 
+    /// `merge-sigs` takes two or more positional inputs.
+    #[test]
+    fn test_parse_merge_sigs() {
+        let cli = Cli::parse_from(["solana-lite", "merge-sigs", "a.json", "b.json", "-o", "out.json"]);
+        match cli.command {
+            Commands::MergeSigs {
+                inputs,
+                output,
+                output_format,
+                force,
+            } => {
+                assert_eq!(inputs, vec!["a.json", "b.json"]);
+                assert_eq!(output.as_deref(), Some("out.json"));
+                assert!(output_format.is_none());
+                assert!(!force);
+            }
+            _ => panic!("Expected Commands::MergeSigs"),
+        }
+
+        assert!(Cli::try_parse_from(["solana-lite", "merge-sigs", "a.json"]).is_err());
+    }
+
     /// Test parsing the `sign-tx` command with summary-json flag.
     #[test]
     fn test_parse_sign_tx_summary_json_flag() {
@@ -452,6 +701,60 @@ mod tests {
         }
     }
 
+    /// `--combined-json` excludes `--summary-json`.
+    #[test]
+    fn test_parse_sign_tx_combined_json_flag() {
+        let base = ["solana-lite", "sign-tx", "-i", "in.json", "-k", "w.json", "-o", "out.json"];
+        let cli = Cli::parse_from(base.iter().copied().chain(["--combined-json"]));
+        match cli.command {
+            Commands::SignTx {
+                combined_json,
+                summary_json,
+                ..
+            } => assert!(combined_json && !summary_json),
+            _ => panic!("Parsed into wrong command variant"),
+        }
+
+        let both = base.iter().copied().chain(["--combined-json", "--summary-json"]);
+        assert!(Cli::try_parse_from(both).is_err());
+    }
+
+    /// `--show-message-bytes` takes an encoding.
+    #[test]
+    fn test_parse_sign_tx_show_message_bytes() {
+        let cli = Cli::parse_from([
+            "solana-lite", "sign-tx", "-i", "in.json", "--show-message-bytes", "hex",
+        ]);
+        match cli.command {
+            Commands::SignTx { show_message_bytes, .. } => {
+                assert_eq!(show_message_bytes, Some(DataEncoding::Hex));
+            }
+            _ => panic!("Parsed into wrong command variant"),
+        }
+    }
+
+    /// `--output-dir` parses and cannot be combined with `--output`.
+    #[test]
+    fn test_parse_sign_tx_output_dir() {
+        let cli = Cli::parse_from([
+            "solana-lite", "sign-tx", "-i", "in.json", "--output-dir", "./signed",
+        ]);
+        match cli.command {
+            Commands::SignTx { output_dir, output, .. } => {
+                assert_eq!(output_dir.as_deref(), Some("./signed"));
+                assert_eq!(output, None);
+            }
+            _ => panic!("Parsed into wrong command variant"),
+        }
+
+        assert!(
+            Cli::try_parse_from([
+                "solana-lite", "sign-tx", "-i", "in.json", "--output-dir", "./signed", "-o", "x.json",
+            ])
+            .is_err()
+        );
+    }
+
     /// Test parsing the `analyze` command with all options.
     #[test]
     fn test_parse_analyze_command() {
@@ -464,26 +767,188 @@ mod tests {
             "Author1111111111111111111111111111111111111",
             "--tables",
             "luts.json",
+            "--message-only",
+            "--input-format",
+            "base58",
+            "--trust-program",
+            "11111111111111111111111111111111",
+            "--trust-program",
+            "Author1111111111111111111111111111111111111",
+            "--time-budget",
+            "250",
             "--summary-json",
         ];
         let cli = Cli::parse_from(args);
         match cli.command {
             Commands::Analyze {
                 input,
+                input_format,
                 pubkey,
                 lookup_tables,
+                message_only,
+                trust_program,
+                ignore_signer_check,
+                time_budget,
                 summary_json,
+                count_only,
+                format,
+                exit_code_by_severity,
             } => {
+                assert!(!exit_code_by_severity);
+                assert!(!ignore_signer_check);
+                assert_eq!(format, AnalysisFormat::Text);
                 assert_eq!(input, "tx.json");
+                assert_eq!(time_budget, Some(250));
+                assert_eq!(input_format, Some(InFmt::Base58));
                 assert_eq!(
-                    pubkey.as_deref(),
-                    Some("Author1111111111111111111111111111111111111")
+                    trust_program,
+                    vec![
+                        "11111111111111111111111111111111".to_string(),
+                        "Author1111111111111111111111111111111111111".to_string()
+                    ]
                 );
+                assert_eq!(pubkey, vec!["Author1111111111111111111111111111111111111".to_string()]);
                 assert_eq!(lookup_tables.as_deref(), Some("luts.json"));
+                assert!(message_only);
                 assert!(summary_json);
+                assert!(!count_only);
+            }
+            _ => panic!("Parsed into wrong command variant"),
+        }
+    }
+
+    #[test]
+    fn test_parse_completions_command() {
+        let cli = Cli::parse_from(["solana-lite", "completions", "powershell"]);
+        match cli.command {
+            Commands::Completions { shell } => assert_eq!(shell, CompletionShell::Powershell),
+            _ => panic!("Parsed into wrong command variant"),
+        }
+        assert!(Cli::try_parse_from(["solana-lite", "completions", "tcsh"]).is_err());
+    }
+
+    /// `--pubkey` is repeatable (one analysis per candidate signer).
+    #[test]
+    fn test_parse_analyze_repeated_pubkey() {
+        let cli = Cli::parse_from([
+            "solana-lite", "analyze", "-i", "tx.json", "-p", "A1", "--pubkey", "B2",
+        ]);
+        match cli.command {
+            Commands::Analyze { pubkey, .. } => assert_eq!(pubkey, vec!["A1", "B2"]),
+            _ => panic!("Parsed into wrong command variant"),
+        }
+    }
+
+    /// `--format markdown` parses and cannot be combined with JSON output modes.
+    #[test]
+    fn test_parse_analyze_format_markdown() {
+        let cli = Cli::parse_from(["solana-lite", "analyze", "-i", "tx.json", "--format", "markdown"]);
+        match cli.command {
+            Commands::Analyze { format, .. } => assert_eq!(format, AnalysisFormat::Markdown),
+            _ => panic!("Parsed into wrong command variant"),
+        }
+
+        for flag in ["--summary-json", "--count-only"] {
+            let err = Cli::try_parse_from(["solana-lite", "analyze", "-i", "tx.json", "--format", "markdown", flag]);
+            assert!(err.is_err(), "{flag} must conflict with --format markdown");
+        }
+    }
+
+    /// `--count-only` parses and cannot be combined with `--summary-json`.
+    #[test]
+    fn test_parse_analyze_count_only() {
+        let cli = Cli::parse_from(["solana-lite", "analyze", "--input", "tx.json", "--count-only"]);
+        match cli.command {
+            Commands::Analyze { count_only, .. } => assert!(count_only),
+            _ => panic!("Parsed into wrong command variant"),
+        }
+
+        let err = Cli::try_parse_from([
+            "solana-lite",
+            "analyze",
+            "--input",
+            "tx.json",
+            "--count-only",
+            "--summary-json",
+        ]);
+        assert!(err.is_err());
+    }
+
+    /// `message-hash` defaults to sha256 and accepts blake3 and keccak256.
+    #[test]
+    fn test_parse_message_hash_algo() {
+        let cli = Cli::parse_from(["solana-lite", "message-hash", "--input", "tx.json"]);
+        match cli.command {
+            Commands::MessageHash { input, hash_algo, .. } => {
+                assert_eq!(input, "tx.json");
+                assert_eq!(hash_algo, HashAlgoArg::Sha256);
+            }
+            _ => panic!("Parsed into wrong command variant"),
+        }
+
+        for (value, expected) in [("blake3", HashAlgoArg::Blake3), ("keccak256", HashAlgoArg::Keccak256)] {
+            let cli = Cli::parse_from(["solana-lite", "message-hash", "-i", "tx.json", "--hash-algo", value]);
+            match cli.command {
+                Commands::MessageHash { hash_algo, .. } => assert_eq!(hash_algo, expected),
+                _ => panic!("Parsed into wrong command variant"),
             }
+        }
+
+        let err = Cli::try_parse_from(["solana-lite", "message-hash", "-i", "tx.json", "--hash-algo", "md5"]);
+        assert!(err.is_err());
+    }
+
+    /// `--exit-code-by-severity` parses on analyze and sign-tx; it conflicts with `--count-only`.
+    #[test]
+    fn test_parse_exit_code_by_severity() {
+        let cli = Cli::parse_from(["solana-lite", "analyze", "--input", "tx.json", "--exit-code-by-severity"]);
+        match cli.command {
+            Commands::Analyze { exit_code_by_severity, .. } => assert!(exit_code_by_severity),
+            _ => panic!("Parsed into wrong command variant"),
+        }
+
+        let cli = Cli::parse_from([
+            "solana-lite",
+            "sign-tx",
+            "--input",
+            "tx.json",
+            "--keypair",
+            "wallet.json",
+            "--exit-code-by-severity",
+        ]);
+        match cli.command {
+            Commands::SignTx { exit_code_by_severity, .. } => assert!(exit_code_by_severity),
             _ => panic!("Parsed into wrong command variant"),
         }
+
+        let err = Cli::try_parse_from([
+            "solana-lite",
+            "analyze",
+            "--input",
+            "tx.json",
+            "--count-only",
+            "--exit-code-by-severity",
+        ]);
+        assert!(err.is_err());
+    }
+
+    /// Test that the global `--color` flag parses after the subcommand.
+    #[test]
+    fn test_parse_global_color_flag() {
+        let cli = Cli::parse_from(["solana-lite", "base58", "encode", "--input", "abc", "--color", "never"]);
+        assert_eq!(cli.color, Some(ColorMode::Never));
+
+        let cli = Cli::parse_from(["solana-lite", "base58", "encode", "--input", "abc"]);
+        assert_eq!(cli.color, None);
     }
 
+    /// `--sort-warnings` is global and defaults to detection order.
+    #[test]
+    fn test_parse_global_sort_warnings_flag() {
+        let cli = Cli::parse_from(["solana-lite", "analyze", "-i", "tx.json", "--sort-warnings", "severity"]);
+        assert_eq!(cli.sort_warnings, WarningOrder::Severity);
+
+        let cli = Cli::parse_from(["solana-lite", "analyze", "-i", "tx.json"]);
+        assert_eq!(cli.sort_warnings, WarningOrder::None);
+    }
 }
@@ -0,0 +1,27 @@
+//! Tests for presenter color handling (`--color auto|always|never`).
+
+use solana_tools_lite_cli::flows::presenter::color::{paint, render_line, strip_ansi, Style};
+use solana_tools_lite_cli::models::cmds::ColorMode;
+
+#[test]
+fn test_strip_ansi_removes_escape_codes() {
+    assert_eq!(strip_ansi("\x1b[1;31mdanger\x1b[0m text"), "danger text");
+    assert_eq!(strip_ansi("\x1b[33m- warning\x1b[0m"), "- warning");
+    assert_eq!(strip_ansi("plain line"), "plain line");
+}
+
+#[test]
+fn test_color_never_strips() {
+    let painted = paint("WARNINGS:", Style::Header, ColorMode::Never, true);
+    assert_eq!(painted, "WARNINGS:");
+    assert_eq!(render_line("\x1b[33m- warning\x1b[0m", ColorMode::Never, true), "- warning");
+    assert_eq!(render_line("\x1b[33m- warning\x1b[0m", ColorMode::Never, false), "- warning");
+}
+
+#[test]
+fn test_color_always_keeps() {
+    let painted = paint("WARNINGS:", Style::Header, ColorMode::Always, true);
+    assert!(painted.contains('\x1b'));
+    assert_eq!(strip_ansi(&painted), "WARNINGS:");
+    assert_eq!(render_line(&painted, ColorMode::Always, true), painted);
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "protocol-extensions")]
+
+use extensions::analysis::light_protocol::constants::LIGHT_SYSTEM_PROGRAM_ID;
+use solana_tools_lite::bs58;
+use solana_tools_lite::data_encoding::{BASE64, HEXLOWER};
+use solana_tools_lite_cli::flows::decode_light::decode;
+use solana_tools_lite_cli::models::cmds::DataEncoding;
+
+// Invoke instruction data from demo_compress_sol.b64: compress 0.1 SOL
+const DEMO_COMPRESS_SOL: &[u8] = &[
+    0x1a, 0x10, 0xa9, 0x07, 0x15, 0xca, 0xf2, 0x19, // discriminator
+    0x43, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // action + padding
+    0x01, 0x00, 0x00, 0x00, // proof length
+    0x36, 0x41, 0x33, 0xbf, 0x5f, 0xab, 0x28, 0x6d, 0x4a, 0x72, 0x93, 0xfc, 0x3c, 0x2b, 0x59, 0x5d,
+    0x9f, 0xa1, 0x16, 0x4d, 0x24, 0x8c, 0xf6, 0xe2, 0x88, 0xdd, 0x5a, 0x1f, 0x7b, 0xdb, 0x09, 0x41,
+    0x00, 0xe1, 0xf5, 0x05, 0x00, 0x00, 0x00, 0x00, // amount: 100_000_000 lamports
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, 0x00, 0xe1, 0xf5, 0x05, 0x00, 0x00, 0x00, 0x00, 0x01,
+];
+
+#[test]
+fn decode_light_demo_compress_sol() {
+    let data = bs58::encode(DEMO_COMPRESS_SOL).into_string();
+    let result = decode(LIGHT_SYSTEM_PROGRAM_ID, &data, None).expect("should decode");
+
+    assert_eq!(result.data_len, DEMO_COMPRESS_SOL.len());
+    assert!(result.action.starts_with("Invoke {"), "got: {}", result.action);
+    assert!(result.action.contains("lamports: Some(\n        100000000,\n    )"), "got: {}", result.action);
+    assert!(result.description.contains("100000000 lamports"));
+
+    let text = result.render_text();
+    assert!(text.contains(LIGHT_SYSTEM_PROGRAM_ID));
+    assert!(text.contains("Invoke {"));
+}
+
+#[test]
+fn decode_light_accepts_hex_and_base64() {
+    let from_b58 = decode(
+        LIGHT_SYSTEM_PROGRAM_ID,
+        &bs58::encode(DEMO_COMPRESS_SOL).into_string(),
+        None,
+    )
+    .unwrap();
+
+    let hex = format!("0x{}", HEXLOWER.encode(DEMO_COMPRESS_SOL));
+    let from_hex = decode(LIGHT_SYSTEM_PROGRAM_ID, &hex, None).unwrap();
+    assert_eq!(from_hex.action, from_b58.action);
+
+    let b64 = BASE64.encode(DEMO_COMPRESS_SOL);
+    let from_b64 = decode(LIGHT_SYSTEM_PROGRAM_ID, &b64, Some(DataEncoding::Base64)).unwrap();
+    assert_eq!(from_b64.action, from_b58.action);
+}
+
+#[test]
+fn decode_light_reports_unknown_discriminator() {
+    let result = decode(LIGHT_SYSTEM_PROGRAM_ID, "0xdeadbeef00112233", None).unwrap();
+    assert_eq!(result.action, "unknown discriminator: 0xdeadbeef00112233");
+}
+
+#[test]
+fn decode_light_rejects_non_light_program() {
+    let err = decode("11111111111111111111111111111111", "0x00", None).unwrap_err();
+    assert!(format!("{err}").contains("not a Light Protocol program"));
+}
@@ -0,0 +1,41 @@
+use solana_tools_lite::models::results::{DeriveManyResult, DerivedAccount};
+use solana_tools_lite_cli::flows::presenter::derive_many_presenter::render_table;
+
+fn result_with_secrets(count: u32) -> DeriveManyResult {
+    DeriveManyResult {
+        accounts: (0..count)
+            .map(|index| DerivedAccount {
+                index,
+                derivation_path: format!("m/44'/501'/{index}'/0'"),
+                public_key: format!("Pubkey{index}"),
+                secret_key: Some(format!("SECRET{index}")),
+                output_path: Some(format!("wallet-{index}.json")),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn derive_many_table_lists_one_row_per_account_without_secrets() {
+    let result = result_with_secrets(3);
+
+    let table = render_table(&result, false);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 1 + 3, "header plus one row per account:\n{table}");
+    assert!(lines[0].starts_with("Index | Derivation Path"));
+    assert!(lines[2].contains("m/44'/501'/1'/0'") && lines[2].contains("Pubkey1"));
+    assert!(lines[3].contains("wallet-2.json"));
+    assert!(!table.contains("SECRET"), "secrets must stay hidden:\n{table}");
+    assert!(!table.contains("Secret Key"));
+
+    // Columns are aligned: every row puts the pubkey at the same offset
+    let offset = lines[0].find("Public Key").unwrap();
+    assert!(lines[1..].iter().all(|line| line.find("Pubkey") == Some(offset)));
+}
+
+#[test]
+fn derive_many_table_shows_secrets_only_on_request() {
+    let table = render_table(&result_with_secrets(2), true);
+    assert!(table.lines().next().unwrap().contains("Secret Key"));
+    assert!(table.contains("SECRET0") && table.contains("SECRET1"));
+}
@@ -1,8 +1,12 @@
+use solana_tools_lite::adapters::io_adapter::ReadOptions;
+use solana_tools_lite::bs58;
+use solana_tools_lite::data_encoding::BASE64;
 use solana_tools_lite::handlers::sign_message;
 use solana_tools_lite::crypto::signing::SigningKey;
-use solana_tools_lite_cli::flows::{base58, generation, sign, verify};
-use solana_tools_lite_cli::models::cmds::Base58Action;
+use solana_tools_lite_cli::flows::{base58, completions, generation, message_hash, sign, verify};
+use solana_tools_lite_cli::models::cmds::{Base58Action, CompletionShell};
 use std::fs;
+use std::process::Command;
 use tempfile::TempDir;
 
 const TEST_MNEMONIC: &str =
@@ -48,6 +52,39 @@ fn generation_flow_from_mnemonic_and_passphrase_files() {
     );
 }
 
+#[test]
+fn gen_cli_count_saves_one_wallet_per_account_and_lists_them() {
+    let dir = TempDir::new().unwrap();
+    let mnemonic_path = write_text_file(&dir, "mnemonic.txt", TEST_MNEMONIC);
+    let out_dir = dir.path().join("accounts");
+    fs::create_dir(&out_dir).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_solana-tools-lite"))
+        .args(["gen", "--mnemonic", &mnemonic_path, "--count", "3", "--output"])
+        .arg(&out_dir)
+        .output()
+        .expect("run gen");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    // Wallets are written, so the table goes to stderr like the single `gen` output
+    let table = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(table.lines().count(), 1 + 3, "header plus one row per account:\n{table}");
+    assert!(table.contains("m/44'/501'/2'/0'"));
+    assert!(!table.contains("Secret Key"));
+
+    for index in 0..3 {
+        let saved = fs::read_to_string(out_dir.join(format!("wallet-{index}.json")))
+            .expect("wallet file per account");
+        assert!(saved.contains("secretKey"));
+        assert!(!table.contains(saved_secret(&saved).as_str()));
+    }
+}
+
+fn saved_secret(wallet_json: &str) -> String {
+    let value: serde_json::Value = serde_json::from_str(wallet_json).unwrap();
+    value["secretKey"].as_str().unwrap().to_string()
+}
+
 #[test]
 fn sign_flow_reads_message_file_and_saves_json() {
     let dir = TempDir::new().unwrap();
@@ -61,6 +98,7 @@ fn sign_flow_reads_message_file_and_saves_json() {
         &key_path,
         Some(output_path.to_str().unwrap()),
         false,
+        false,
         true,
     )
     .expect("sign flow should succeed");
@@ -72,6 +110,64 @@ fn sign_flow_reads_message_file_and_saves_json() {
     );
 }
 
+#[test]
+fn sign_flow_raw_refuses_serialized_transaction() {
+    use solana_tools_lite::codec::serialize_transaction;
+    use solana_tools_lite::crypto::ed25519::signature_from_bytes;
+    use solana_tools_lite::models::instruction::Instruction;
+    use solana_tools_lite::models::message::{MessageHeader, MessageLegacy};
+    use solana_tools_lite::models::{HashBase58, Message, PubkeyBase58, Transaction};
+
+    let dir = TempDir::new().unwrap();
+    let (key, key_path) = write_secret_key_file(&dir, 7);
+    let signer = PubkeyBase58::from(key.verifying_key().to_bytes());
+    let tx = Transaction {
+        signatures: vec![signature_from_bytes(&[0u8; 64])],
+        message: Message::Legacy(MessageLegacy {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![signer, PubkeyBase58::from([0u8; 32])],
+            recent_blockhash: HashBase58([1u8; 32]),
+            instructions: vec![Instruction { program_id_index: 1, accounts: vec![0], data: vec![2] }],
+        }),
+    };
+    let tx_path = dir.path().join("tx.bin");
+    fs::write(&tx_path, serialize_transaction(&tx)).unwrap();
+    let tx_path = tx_path.to_string_lossy().to_string();
+
+    let err = sign::execute(None, Some(&tx_path), &key_path, None, false, true, false)
+        .expect_err("--raw must refuse transaction bytes");
+    assert!(format!("{err}").contains("decode as a Solana transaction"));
+
+    // Arbitrary bytes are signed
+    let blob_path = dir.path().join("blob.bin");
+    fs::write(&blob_path, [0xffu8, 0x00, 0x13, 0x37]).unwrap();
+    sign::execute(None, Some(blob_path.to_str().unwrap()), &key_path, None, false, true, true)
+        .expect("--raw signs opaque bytes");
+}
+
+/// `--keypair env:VAR` signs with the key from that variable. It is set on the spawned
+/// CLI only, so the test process environment is never modified.
+#[test]
+fn sign_cli_reads_keypair_from_env_var() {
+    let key = SigningKey::from_bytes(&[14u8; 32]);
+    let var = "SOLANA_TOOLS_LITE_TEST_KEYPAIR_ENV";
+
+    let output = Command::new(env!("CARGO_BIN_EXE_solana-tools-lite"))
+        .args(["sign", "--message", "ci", "--keypair", &format!("env:{var}")])
+        .env(var, bs58::encode(key.to_keypair_bytes()).into_string())
+        .output()
+        .expect("run sign");
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    // Ed25519 signatures are deterministic: the CLI must have used this exact key
+    let expected = sign_message::handle("ci", &key).unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), expected.signature_base58);
+}
+
 #[test]
 fn verify_flow_valid_signature_creates_report() {
     let dir = TempDir::new().unwrap();
@@ -87,7 +183,7 @@ fn verify_flow_valid_signature_creates_report() {
         None,
         Some(&sig_b58),
         None,
-        Some(&pubkey_b58),
+        std::slice::from_ref(&pubkey_b58),
         None,
         Some(output_path.to_str().unwrap()),
         false,
@@ -102,6 +198,63 @@ fn verify_flow_valid_signature_creates_report() {
     );
 }
 
+#[test]
+fn verify_flow_accepts_base64_signature_and_pubkey() {
+    let dir = TempDir::new().unwrap();
+    let (key, _) = write_secret_key_file(&dir, 12);
+    let signed = sign_message::handle("msg", &key).unwrap();
+    let to_base64 = |b58: &str| BASE64.encode(&bs58::decode(b58).into_vec().unwrap());
+
+    verify::execute(
+        Some("msg"),
+        None,
+        Some(&to_base64(&signed.signature_base58)),
+        None,
+        &[to_base64(&signed.public_key)],
+        None,
+        None,
+        false,
+        true,
+    )
+    .expect("base64 signature and pubkey should verify");
+
+    let err = verify::execute(
+        Some("msg"),
+        None,
+        Some(&BASE64.encode(&[1u8; 62])),
+        None,
+        std::slice::from_ref(&signed.public_key),
+        None,
+        None,
+        false,
+        true,
+    )
+    .expect_err("62-byte signature must be rejected");
+    assert!(format!("{err}").contains("InvalidSigLen(62)"));
+}
+
+#[test]
+fn verify_flow_reports_empty_pubkey_file() {
+    let dir = TempDir::new().unwrap();
+    let (key, _) = write_secret_key_file(&dir, 13);
+    let signed = sign_message::handle("msg", &key).unwrap();
+    let pubkey_path = write_text_file(&dir, "pubkeys.txt", "\n  \n");
+
+    let err = verify::execute(
+        Some("msg"),
+        None,
+        Some(&signed.signature_base58),
+        None,
+        &[],
+        Some(&pubkey_path),
+        None,
+        false,
+        false,
+    )
+    .expect_err("empty public key file must be rejected");
+    assert!(format!("{err}").contains("is empty"), "got: {err}");
+}
+
 #[test]
 fn verify_flow_invalid_signature_errors() {
     let dir = TempDir::new().unwrap();
@@ -112,7 +265,7 @@ fn verify_flow_invalid_signature_errors() {
         None,
         Some(&valid.signature_base58),
         None,
-        Some(&valid.public_key),
+        std::slice::from_ref(&valid.public_key),
         None,
         None,
         false,
@@ -138,6 +291,7 @@ fn base58_flow_encode_and_invalid_decode() {
     let err = base58::execute(
         &Base58Action::Decode {
             input: "0OIl+/=".into(), // invalid alphabet
+            as_transaction: false,
         },
         false,
     )
@@ -149,3 +303,75 @@ fn base58_flow_encode_and_invalid_decode() {
         "unexpected error text: {err_text}"
     );
 }
+
+#[test]
+fn completions_flow_renders_every_shell() {
+    for shell in [
+        CompletionShell::Bash,
+        CompletionShell::Zsh,
+        CompletionShell::Fish,
+        CompletionShell::Powershell,
+    ] {
+        assert!(!completions::render(shell).is_empty(), "{shell:?} script is empty");
+    }
+
+    let bash = completions::render(CompletionShell::Bash);
+    assert!(bash.contains("sign-tx"));
+    assert!(bash.contains("solana-tools-lite"));
+}
+
+#[test]
+fn message_hash_flow_digests_message_bytes() {
+    use solana_tools_lite::codec::serialize_transaction;
+    use solana_tools_lite::crypto::ed25519::signature_from_bytes;
+    use solana_tools_lite::crypto::hash::{HashAlgo, digest};
+    use solana_tools_lite::models::hash_base58::HashBase58;
+    use solana_tools_lite::models::instruction::Instruction;
+    use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+    use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+    use solana_tools_lite::models::Transaction;
+    use solana_tools_lite::utils::hex_encode;
+
+    let dir = TempDir::new().unwrap();
+    let tx = Transaction {
+        signatures: vec![signature_from_bytes(&[0u8; 64])],
+        message: Message::Legacy(MessageLegacy {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![PubkeyBase58::from([1u8; 32]), PubkeyBase58::from([0u8; 32])],
+            recent_blockhash: HashBase58([7u8; 32]),
+            instructions: vec![Instruction { program_id_index: 1, accounts: vec![0], data: vec![] }],
+        }),
+    };
+    let path = write_text_file(&dir, "tx.b64", &BASE64.encode(&serialize_transaction(&tx)));
+
+    for algo in [HashAlgo::Sha256, HashAlgo::Blake3, HashAlgo::Keccak256] {
+        let hex = message_hash::message_hash(Some(&path), None, ReadOptions::default(), algo).expect("message hash");
+        assert_eq!(hex.len(), 64, "{}", algo.name());
+        assert_eq!(hex, hex_encode(&digest(algo, &tx.message_signing_bytes())));
+        assert_eq!(hex, message_hash::message_hash(Some(&path), None, ReadOptions::default(), algo).unwrap());
+    }
+}
+
+/// `--rent-config` is read only by commands that analyze a transaction.
+#[test]
+fn rent_config_is_only_loaded_by_analyzing_commands() {
+    let dir = TempDir::new().unwrap();
+    let missing = dir.path().join("missing-rent.json");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_solana-tools-lite"))
+        .args(["--rent-config", missing.to_str().unwrap(), "base58", "encode", "--input", "ci"])
+        .output()
+        .expect("run base58");
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_solana-tools-lite"))
+        .args(["--rent-config", missing.to_str().unwrap(), "analyze", "--input", "tx.json"])
+        .output()
+        .expect("run analyze");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("rent-config"));
+}
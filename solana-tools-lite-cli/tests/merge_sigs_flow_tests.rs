@@ -0,0 +1,90 @@
+use solana_tools_lite::adapters::io_adapter::ReadOptions;
+use solana_tools_lite::handlers::sign_tx::sign_transaction_by_key;
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::input_transaction::{InputTransaction, UiTransaction};
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+use solana_tools_lite::models::transaction::{SignatureStatus, Transaction};
+use solana_tools_lite::crypto::signing::{Signature, SigningKey};
+use solana_tools_lite_cli::flows::merge_sigs;
+use std::fs;
+use tempfile::TempDir;
+
+fn two_signer_tx(a: &SigningKey, b: &SigningKey) -> Transaction {
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 2,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![
+            PubkeyBase58::from(a.verifying_key().to_bytes()),
+            PubkeyBase58::from(b.verifying_key().to_bytes()),
+            PubkeyBase58::from([7u8; 32]),
+        ],
+        recent_blockhash: HashBase58([5u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 2,
+            accounts: vec![0, 1],
+            data: vec![1, 2, 3],
+        }],
+    });
+
+    Transaction {
+        signatures: vec![Signature::from_bytes(&[0u8; 64]); 2],
+        message,
+    }
+}
+
+fn write_tx(dir: &TempDir, name: &str, tx: &Transaction) -> String {
+    let json = serde_json::to_string(&InputTransaction::Json(UiTransaction::from(tx))).unwrap();
+    let path = dir.path().join(name);
+    fs::write(&path, json).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+#[test]
+fn merge_sigs_writes_fully_signed_transaction() {
+    let dir = TempDir::new().unwrap();
+    let a = SigningKey::from_bytes(&[1u8; 32]);
+    let b = SigningKey::from_bytes(&[2u8; 32]);
+
+    let mut signed_by_a = two_signer_tx(&a, &b);
+    sign_transaction_by_key(&mut signed_by_a, &a).unwrap();
+    let mut signed_by_b = two_signer_tx(&a, &b);
+    sign_transaction_by_key(&mut signed_by_b, &b).unwrap();
+
+    let inputs = vec![
+        write_tx(&dir, "a.json", &signed_by_a),
+        write_tx(&dir, "b.json", &signed_by_b),
+    ];
+    let out_path = dir.path().join("merged.json");
+    let out = Some(out_path.to_str().unwrap());
+    merge_sigs::execute(&inputs, ReadOptions::default(), out, false, None, false).expect("merge-sigs");
+
+    let ui: UiTransaction = serde_json::from_str(&fs::read_to_string(&out_path).unwrap()).unwrap();
+    let merged = Transaction::try_from(ui).unwrap();
+    assert_eq!(
+        merged.verify_all(),
+        vec![SignatureStatus::Valid, SignatureStatus::Valid]
+    );
+}
+
+#[test]
+fn merge_sigs_rejects_a_single_input() {
+    let dir = TempDir::new().unwrap();
+    let a = SigningKey::from_bytes(&[1u8; 32]);
+    let b = SigningKey::from_bytes(&[2u8; 32]);
+    let mut signed_by_a = two_signer_tx(&a, &b);
+    sign_transaction_by_key(&mut signed_by_a, &a).unwrap();
+
+    let inputs = vec![write_tx(&dir, "a.json", &signed_by_a)];
+    let out_path = dir.path().join("merged.json");
+    let out = Some(out_path.to_str().unwrap());
+    let err = merge_sigs::execute(&inputs, ReadOptions::default(), out, false, None, false)
+        .expect_err("one input is not a merge");
+
+    assert!(err.to_string().contains("at least two inputs"), "got: {err}");
+    assert!(!out_path.exists());
+}
@@ -11,6 +11,8 @@ use solana_tools_lite::models::hash_base58::HashBase58;
 use extensions::analysis::light_protocol::constants::{DISCRIMINATOR_INVOKE, DISCRIMINATOR_INVOKE_CPI, LIGHT_SYSTEM_PROGRAM_ID, COMPRESSED_TOKEN_PROGRAM_ID};
 use solana_tools_lite_cli::flows::presenter::Presentable;
 use solana_tools_lite_cli::flows::presenter::analysis_presenter::AnalysisPresenter;
+use solana_tools_lite_cli::flows::presenter::keys::KeyFormat;
+use solana_tools_lite_cli::models::cmds::{ColorMode, WarningOrder};
 
 fn build_light_compress_message(signer: &PubkeyBase58, amount_lamports: u64) -> Message {
     let light_system_program = PubkeyBase58::try_from(LIGHT_SYSTEM_PROGRAM_ID).unwrap();
@@ -35,8 +37,8 @@ fn build_light_compress_message(signer: &PubkeyBase58, amount_lamports: u64) ->
         account_keys: vec![
             signer.clone(),
             light_system_program,
-            PubkeyBase58([2u8; 32]),
-            PubkeyBase58([3u8; 32]),
+            PubkeyBase58::from_bytes(&[2u8; 32]),
+            PubkeyBase58::from_bytes(&[3u8; 32]),
         ],
         recent_blockhash: HashBase58([4u8; 32]),
         instructions: vec![instr],
@@ -52,12 +54,15 @@ fn test_light_protocol_single_instruction_presenter_output() {
     let message = build_light_compress_message(&signer, 5_000_000_000); // 5 SOL
     
     // Analyze transaction
-    let analysis = analyze_transaction(&message, &signer, None);
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
     
     // Use real presenter
     let presentation = AnalysisPresenter {
         analysis: Some(&analysis),
         summary_payload: None,
+        key_format: KeyFormat::Full,
+        warning_order: WarningOrder::None,
+        color: ColorMode::Never,
     };
     
     eprintln!("\n=== SINGLE LIGHT PROTOCOL INSTRUCTION ===");
@@ -118,20 +123,23 @@ fn test_light_protocol_multiple_instructions_presenter_output() {
         account_keys: vec![
             signer.clone(),
             light_system_program,
-            PubkeyBase58([2u8; 32]),
-            PubkeyBase58([3u8; 32]),
+            PubkeyBase58::from_bytes(&[2u8; 32]),
+            PubkeyBase58::from_bytes(&[3u8; 32]),
         ],
         recent_blockhash: HashBase58([4u8; 32]),
         instructions: vec![instr1, instr2],
     });
     
     // Analyze transaction
-    let analysis = analyze_transaction(&message, &signer, None);
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
     
     // Use real presenter
     let presentation = AnalysisPresenter {
         analysis: Some(&analysis),
         summary_payload: None,
+        key_format: KeyFormat::Full,
+        warning_order: WarningOrder::None,
+        color: ColorMode::Never,
     };
     
     eprintln!("\n=== MULTIPLE LIGHT PROTOCOL INSTRUCTIONS ===");
@@ -167,12 +175,15 @@ fn test_unknown_program_presenter_output() {
     });
     
     // Analyze transaction
-    let analysis = analyze_transaction(&message, &signer, None);
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
     
     // Use real presenter
     let presentation = AnalysisPresenter {
         analysis: Some(&analysis),
         summary_payload: None,
+        key_format: KeyFormat::Full,
+        warning_order: WarningOrder::None,
+        color: ColorMode::Never,
     };
     
     eprintln!("\n=== UNKNOWN PROGRAM TRANSACTION ===");
@@ -225,8 +236,8 @@ fn test_light_protocol_and_unknown_program_presenter_output() {
         account_keys: vec![
             signer.clone(),
             light_system_program,
-            PubkeyBase58([2u8; 32]),
-            PubkeyBase58([3u8; 32]),
+            PubkeyBase58::from_bytes(&[2u8; 32]),
+            PubkeyBase58::from_bytes(&[3u8; 32]),
             unknown_program,
         ],
         recent_blockhash: HashBase58([4u8; 32]),
@@ -234,12 +245,15 @@ fn test_light_protocol_and_unknown_program_presenter_output() {
     });
     
     // Analyze transaction
-    let analysis = analyze_transaction(&message, &signer, None);
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
     
     // Use real presenter
     let presentation = AnalysisPresenter {
         analysis: Some(&analysis),
         summary_payload: None,
+        key_format: KeyFormat::Full,
+        warning_order: WarningOrder::None,
+        color: ColorMode::Never,
     };
     
     eprintln!("\n=== LIGHT PROTOCOL + UNKNOWN PROGRAM ===");
@@ -298,20 +312,23 @@ fn test_light_protocol_mixed_operations_presenter_output() {
             signer.clone(),
             light_system_program,
             compressed_token_program,
-            PubkeyBase58([2u8; 32]),
-            PubkeyBase58([3u8; 32]),
+            PubkeyBase58::from_bytes(&[2u8; 32]),
+            PubkeyBase58::from_bytes(&[3u8; 32]),
         ],
         recent_blockhash: HashBase58([4u8; 32]),
         instructions: vec![compress_instr, mint_instr],
     });
     
     // Analyze transaction
-    let analysis = analyze_transaction(&message, &signer, None);
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
     
     // Use real presenter
     let presentation = AnalysisPresenter {
         analysis: Some(&analysis),
         summary_payload: None,
+        key_format: KeyFormat::Full,
+        warning_order: WarningOrder::None,
+        color: ColorMode::Never,
     };
     
     eprintln!("\n=== LIGHT PROTOCOL MIXED OPERATIONS (Compress + MintTo) ===");
@@ -380,20 +397,23 @@ fn test_light_protocol_mixed_and_unknown_program_presenter_output() {
             light_system_program,
             compressed_token_program,
             unknown_program,
-            PubkeyBase58([2u8; 32]),
-            PubkeyBase58([3u8; 32]),
+            PubkeyBase58::from_bytes(&[2u8; 32]),
+            PubkeyBase58::from_bytes(&[3u8; 32]),
         ],
         recent_blockhash: HashBase58([4u8; 32]),
         instructions: vec![compress_instr, mint_instr, unknown_instr],
     });
     
     // Analyze transaction
-    let analysis = analyze_transaction(&message, &signer, None);
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
     
     // Use real presenter
     let presentation = AnalysisPresenter {
         analysis: Some(&analysis),
         summary_payload: None,
+        key_format: KeyFormat::Full,
+        warning_order: WarningOrder::None,
+        color: ColorMode::Never,
     };
     
     eprintln!("\n=== LIGHT PROTOCOL MIXED + UNKNOWN PROGRAM ===");
@@ -418,4 +438,218 @@ fn test_light_protocol_mixed_and_unknown_program_presenter_output() {
         )),
         "Should have unknown program warning"
     );
-}
\ No newline at end of file
+}
+#[test]
+fn test_markdown_presenter_renders_tables_and_warnings() {
+    use solana_tools_lite_cli::flows::presenter::markdown_presenter::render_markdown;
+
+    extensions::init();
+
+    let signer = PubkeyBase58::try_from("7ZD7xmv1Ccvoqj28aPKwpJmzSBafkwXNAV3aGhBo5nSi").unwrap();
+    let unknown_program = PubkeyBase58::from_bytes(&[5u8; 32]);
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), unknown_program.clone()],
+        recent_blockhash: HashBase58([4u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: vec![1, 2, 3],
+        }],
+    });
+
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
+    let markdown = render_markdown(&analysis, KeyFormat::Full, WarningOrder::None);
+
+    assert!(markdown.contains("## Summary"), "got:\n{markdown}");
+    assert!(markdown.contains("| Field | Value |\n| --- | --- |"), "got:\n{markdown}");
+    assert!(markdown.contains("## Warnings"), "got:\n{markdown}");
+    let warning_line = markdown
+        .lines()
+        .skip_while(|line| *line != "## Warnings")
+        .find(|line| line.contains(&unknown_program.to_string()))
+        .expect("unknown program listed under warnings");
+    assert!(warning_line.starts_with("- 🟡 **Warn**"), "got: {warning_line}");
+}
+
+#[test]
+fn test_markdown_presenter_short_keys_abbreviate_warning_keys() {
+    use solana_tools_lite_cli::flows::presenter::markdown_presenter::render_markdown;
+
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let unknown_program = PubkeyBase58::from_bytes(&[5u8; 32]);
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), unknown_program.clone()],
+        recent_blockhash: HashBase58([4u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: vec![1, 2, 3],
+        }],
+    });
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
+
+    // The key format is per presenter: two renders of one analysis can differ
+    let short = render_markdown(&analysis, KeyFormat::Short, WarningOrder::None);
+    let full = render_markdown(&analysis, KeyFormat::Full, WarningOrder::None);
+
+    assert!(short.contains(&unknown_program.short()), "got:\n{short}");
+    assert!(!short.contains(&unknown_program.to_string()), "got:\n{short}");
+    assert!(full.contains(&unknown_program.to_string()), "got:\n{full}");
+}
+
+#[test]
+fn test_markdown_presenter_warning_order_is_per_presenter() {
+    use solana_tools_lite::models::analysis::{AnalysisWarning, TxAnalysis};
+    use solana_tools_lite_cli::flows::presenter::markdown_presenter::render_markdown;
+
+    let analysis = TxAnalysis {
+        warnings: vec![AnalysisWarning::LookupTableNotProvided, AnalysisWarning::SignerNotRequired],
+        ..Default::default()
+    };
+    let first_warning = |markdown: &str| {
+        markdown
+            .lines()
+            .skip_while(|line| *line != "## Warnings")
+            .find(|line| line.starts_with("- "))
+            .map(str::to_string)
+            .expect("warnings listed")
+    };
+
+    // Detection order by default; severity order only on request
+    let detected = render_markdown(&analysis, KeyFormat::Full, WarningOrder::None);
+    assert!(!first_warning(&detected).contains("**Critical**"), "got:\n{detected}");
+    let sorted = render_markdown(&analysis, KeyFormat::Full, WarningOrder::Severity);
+    assert!(first_warning(&sorted).contains("**Critical**"), "got:\n{sorted}");
+    assert_eq!(analysis.warnings.len(), 2);
+}
+
+#[test]
+fn test_markdown_presenter_shows_compute_budget_units() {
+    use solana_tools_lite::constants::programs;
+    use solana_tools_lite_cli::flows::presenter::markdown_presenter::render_markdown;
+
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let compute_budget = PubkeyBase58::try_from(programs::COMPUTE_BUDGET_ID).unwrap();
+    let mut set_limit = vec![2u8];
+    set_limit.extend_from_slice(&300_000u32.to_le_bytes());
+    let mut set_price = vec![3u8];
+    set_price.extend_from_slice(&25_000u64.to_le_bytes());
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), compute_budget],
+        recent_blockhash: HashBase58([4u8; 32]),
+        instructions: vec![
+            Instruction { program_id_index: 1, accounts: vec![], data: set_limit },
+            Instruction { program_id_index: 1, accounts: vec![], data: set_price },
+        ],
+    });
+
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
+    let markdown = render_markdown(&analysis, KeyFormat::Full, WarningOrder::None);
+
+    // priority fee = price_micro * limit / 1_000_000 = 25_000 * 300_000 / 1_000_000
+    let expected_fee = 25_000u128 * 300_000 / 1_000_000;
+    assert!(
+        markdown.contains(&format!("| Priority fee | 0.0000075 SOL ({expected_fee} lamports) |")),
+        "got:\n{markdown}"
+    );
+    assert!(
+        markdown.contains(&format!(
+            "| Compute budget | price=25000 micro-lamports/CU (0.025 lamports/CU), limit=300000 CU, priority fee={expected_fee} lamports |"
+        )),
+        "got:\n{markdown}"
+    );
+}
+
+#[test]
+fn test_markdown_presenter_lists_action_names() {
+    use solana_tools_lite_cli::flows::presenter::markdown_presenter::render_markdown;
+
+    extensions::init();
+
+    let signer = PubkeyBase58::try_from("7ZD7xmv1Ccvoqj28aPKwpJmzSBafkwXNAV3aGhBo5nSi").unwrap();
+    let analysis = analyze_transaction(&build_light_compress_message(&signer, 1_000), &signer, None, None, None);
+    let action = &analysis.extension_actions[0];
+    let markdown = render_markdown(&analysis, KeyFormat::Full, WarningOrder::None);
+
+    assert!(
+        markdown.contains("| Instruction | Protocol | Action | Description | Privacy |"),
+        "got:\n{markdown}"
+    );
+    let row = markdown
+        .lines()
+        .find(|line| line.starts_with("| #1 |"))
+        .expect("extension action row");
+    assert!(
+        row.starts_with(&format!("| #1 | Light Protocol | {} | ", action.action_name())),
+        "got: {row}"
+    );
+}
+
+#[test]
+fn test_markdown_presenter_short_keys_abbreviate_token_setup_keys() {
+    use solana_tools_lite::models::analysis::{TokenSetupAction, TxAnalysis};
+    use solana_tools_lite_cli::flows::presenter::markdown_presenter::render_markdown;
+
+    let mint = PubkeyBase58::from([21u8; 32]);
+    let authority = PubkeyBase58::from([22u8; 32]);
+    let analysis = TxAnalysis {
+        token_setup: vec![TokenSetupAction::InitializeMint {
+            mint: mint.clone(),
+            decimals: 6,
+            mint_authority: authority.clone(),
+            freeze_authority: None,
+        }],
+        ..Default::default()
+    };
+
+    let short = render_markdown(&analysis, KeyFormat::Short, WarningOrder::None);
+    let full = render_markdown(&analysis, KeyFormat::Full, WarningOrder::None);
+
+    for key in [&mint, &authority] {
+        assert!(short.contains(&key.short()), "got:\n{short}");
+        assert!(!short.contains(&key.to_string()), "got:\n{short}");
+        assert!(full.contains(&key.to_string()), "got:\n{full}");
+    }
+}
+
+#[test]
+fn test_analysis_presenter_numbers_extension_actions_from_one() {
+    use solana_tools_lite::crypto::signing::Signature;
+    use solana_tools_lite::models::input_transaction::UiTransaction;
+    use solana_tools_lite::models::transaction::Transaction;
+    use std::process::Command;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let signer = PubkeyBase58::try_from("7ZD7xmv1Ccvoqj28aPKwpJmzSBafkwXNAV3aGhBo5nSi").unwrap();
+    let tx = Transaction {
+        signatures: vec![Signature::from_bytes(&[0u8; 64])],
+        message: build_light_compress_message(&signer, 1_000),
+    };
+    let tx_path = dir.path().join("light.json");
+    std::fs::write(&tx_path, serde_json::to_string(&UiTransaction::from(&tx)).unwrap()).unwrap();
+
+    // The text summary goes to stderr; instructions are numbered from 1 like everywhere else
+    let output = Command::new(env!("CARGO_BIN_EXE_solana-tools-lite"))
+        .args(["analyze", "--input", tx_path.to_str().unwrap(), "--color", "never"])
+        .output()
+        .expect("run analyze");
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("  - instruction #1: "), "got:\n{stderr}");
+    assert!(!stderr.contains("instruction #0"), "got:\n{stderr}");
+}
@@ -2,10 +2,14 @@ use solana_tools_lite::models::input_transaction::{
     InputTransaction, UiAddressTableLookup, UiCompiledInstruction, UiRawMessage, UiRawMessageV0,
     UiTransaction,
 };
-use solana_tools_lite::models::message::MessageHeader;
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::transaction::Transaction;
+use solana_tools_lite::serde::fmt::OutputFormat;
 use solana_tools_lite::bs58;
-use solana_tools_lite::crypto::signing::SigningKey;
-use solana_tools_lite_cli::flows::sign_tx;
+use solana_tools_lite::crypto::signing::{Signature, SigningKey};
+use solana_tools_lite_cli::flows::sign_tx::{self, SignTxOptions};
+use solana_tools_lite_cli::models::cmds::OutFmt;
 use std::fs;
 use tempfile::TempDir;
 
@@ -85,18 +89,15 @@ fn sign_tx_with_tables_and_assume_yes() {
     let keypair_path = write_keypair_file(&dir);
     let output_path = dir.path().join("signed.b64");
 
-    sign_tx::execute(
-        Some(tx_path.to_str().unwrap()),
-        &keypair_path,
-        Some(output_path.to_str().unwrap()),
-        false, // json pretty
-        None,  // output format mirror
-        false, // force
-        Some(tables_path.as_str()),
-        true,         // assume_yes
-        Some(10_000), // max_fee above base fee
-        false,        // summary_json
-    )
+    let opts = SignTxOptions {
+        input: Some(tx_path.to_str().unwrap()),
+        output: Some(output_path.to_str().unwrap()),
+        lookup_tables_path: Some(tables_path.as_str()),
+        assume_yes: true,
+        max_fee: Some(10_000), // above base fee
+        ..Default::default()
+    };
+    sign_tx::execute(&keypair_path, &opts)
     .expect("signing should succeed");
 
     let signed = fs::read_to_string(&output_path).unwrap();
@@ -116,40 +117,383 @@ fn sign_tx_max_fee_exceeded_errors() {
     let tx_path = dir.path().join("tx.json");
     fs::write(&tx_path, tx_json).unwrap();
 
-    let err = sign_tx::execute(
-        Some(tx_path.to_str().unwrap()),
-        &keypair_path,
-        None,
-        false,
-        None,
-        false,
-        None,
-        true,
-        Some(1), // too low for base fee
-        false,
-    )
+    let opts = SignTxOptions {
+        input: Some(tx_path.to_str().unwrap()),
+        assume_yes: true,
+        max_fee: Some(1), // too low for base fee
+        ..Default::default()
+    };
+    let err = sign_tx::execute(&keypair_path, &opts)
     .err()
     .expect("should error on fee limit");
 
     assert!(format!("{err}").contains("exceeds max-fee"));
 }
 
+#[test]
+fn sign_tx_plan_applies_signing_gates() {
+    use solana_tools_lite_cli::shell::error::CliError;
+
+    let dir = TempDir::new().unwrap();
+    let keypair_path = write_keypair_file(&dir);
+    let signer = SigningKey::from_bytes(&[1u8; 32]);
+    let signer_pk = bs58::encode(signer.verifying_key().to_bytes()).into_string();
+
+    let tx_json = build_v0_tx_json(&signer_pk, &bs58::encode([7u8; 32]).into_string());
+    let tx_path = dir.path().join("tx.json");
+    fs::write(&tx_path, tx_json).unwrap();
+    let keypairs = [keypair_path];
+
+    let too_low = SignTxOptions {
+        input: Some(tx_path.to_str().unwrap()),
+        max_fee: Some(1),
+        ..Default::default()
+    };
+    assert!(matches!(
+        sign_tx::execute_plan(&keypairs, &too_low),
+        Err(CliError::FeeLimitExceeded { max_lamports: 1, .. })
+    ));
+
+    let not_payer = SignTxOptions {
+        input: Some(tx_path.to_str().unwrap()),
+        require_fee_payer: Some(false),
+        ..Default::default()
+    };
+    assert!(matches!(
+        sign_tx::execute_plan(&keypairs, &not_payer),
+        Err(CliError::FeePayerMismatch { required: false })
+    ));
+
+    let passing = SignTxOptions {
+        input: Some(tx_path.to_str().unwrap()),
+        max_fee: Some(10_000),
+        require_fee_payer: Some(true),
+        ..Default::default()
+    };
+    sign_tx::execute_plan(&keypairs, &passing).expect("plan within the gates");
+}
+
+#[test]
+fn sign_tx_deny_unknown_programs_blocks_an_unknown_program_id() {
+    use solana_tools_lite_cli::shell::error::CliError;
+
+    let dir = TempDir::new().unwrap();
+    let keypair_path = write_keypair_file(&dir);
+    let signer_pk = bs58::encode(SigningKey::from_bytes(&[1u8; 32]).verifying_key().to_bytes())
+        .into_string();
+    let unknown_program = bs58::encode([42u8; 32]).into_string();
+
+    // Same shape as the transfer fixture, but the instruction calls an unknown program
+    let mut tx: serde_json::Value = serde_json::from_str(&build_v0_tx_json(
+        &signer_pk,
+        &bs58::encode([7u8; 32]).into_string(),
+    ))
+    .unwrap();
+    tx["message"]["account_keys"][1] = serde_json::Value::String(unknown_program.clone());
+    let tx_path = dir.path().join("tx.json");
+    fs::write(&tx_path, tx.to_string()).unwrap();
+    let keypairs = [keypair_path.clone()];
+
+    let deny = SignTxOptions {
+        input: Some(tx_path.to_str().unwrap()),
+        assume_yes: true,
+        deny_unknown_programs: true,
+        ..Default::default()
+    };
+    let err = sign_tx::execute(&keypair_path, &deny).expect_err("unknown program must block signing");
+    assert!(format!("{err}").contains(&unknown_program));
+    assert!(matches!(sign_tx::execute_plan(&keypairs, &deny), Err(CliError::Core(_))));
+
+    // Trusting the program lifts the gate
+    let trusted = [unknown_program];
+    let plan = SignTxOptions {
+        input: Some(tx_path.to_str().unwrap()),
+        deny_unknown_programs: true,
+        trusted_programs: &trusted,
+        ..Default::default()
+    };
+    sign_tx::execute_plan(&keypairs, &plan).expect("trusted program passes the gate");
+}
+
 #[test]
 fn summary_json_requires_output_path() {
-    let err = sign_tx::execute(
-        Some("tx.json"), // won't be read because validation happens first
-        "wallet.json",
-        None,
-        false,
-        None,
-        false,
-        None,
-        true,
-        None,
-        true, // summary_json
-    )
+    let opts = SignTxOptions {
+        input: Some("tx.json"), // won't be read because validation happens first
+        assume_yes: true,
+        summary_json: true,
+        ..Default::default()
+    };
+    let err = sign_tx::execute("wallet.json", &opts)
     .err()
     .expect("must reject summary-json without output");
 
     assert!(format!("{err}").contains("--summary-json requires --output"));
 }
+
+#[test]
+fn signed_tx_filename_uses_first_signature() {
+    let sig_bytes = [5u8; 64];
+    let tx = Transaction {
+        signatures: vec![Signature::from_bytes(&[0u8; 64]), Signature::from_bytes(&sig_bytes)],
+        message: Message::Legacy(MessageLegacy {
+            header: MessageHeader {
+                num_required_signatures: 2,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 0,
+            },
+            account_keys: vec![],
+            recent_blockhash: HashBase58([0u8; 32]),
+            instructions: vec![],
+        }),
+    };
+
+    let expected_stem = bs58::encode(sig_bytes).into_string();
+    assert_eq!(
+        sign_tx::signed_tx_filename(&tx, OutputFormat::Base64),
+        format!("{expected_stem}.b64")
+    );
+    assert_eq!(
+        sign_tx::signed_tx_filename(&tx, OutputFormat::Json { pretty: true }),
+        format!("{expected_stem}.json")
+    );
+}
+
+#[test]
+fn combined_json_has_analysis_summary_and_signed_sections() {
+    use solana_tools_lite::codec::serialize_transaction;
+    use solana_tools_lite::data_encoding::BASE64;
+    use solana_tools_lite::handlers::analysis::analyze_transaction;
+    use solana_tools_lite::models::instruction::Instruction;
+    use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let mut transfer = 2u32.to_le_bytes().to_vec();
+    transfer.extend_from_slice(&1_000u64.to_le_bytes());
+    let tx = Transaction {
+        signatures: vec![Signature::from_bytes(&[5u8; 64])],
+        message: Message::Legacy(MessageLegacy {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![
+                signer.clone(),
+                PubkeyBase58::from([2u8; 32]),
+                PubkeyBase58::from([1u8; 32]),
+                PubkeyBase58::try_from("11111111111111111111111111111111").unwrap(),
+            ],
+            recent_blockhash: HashBase58([0u8; 32]),
+            instructions: vec![
+                Instruction { program_id_index: 3, accounts: vec![0, 1], data: transfer.clone() },
+                Instruction { program_id_index: 3, accounts: vec![0, 2], data: transfer },
+            ],
+        }),
+    };
+    let analysis = analyze_transaction(&tx.message, &signer, None, None, None);
+
+    let payload = sign_tx::combined_json_payload(&tx, &analysis, Some("out.b64")).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&payload).unwrap();
+
+    assert_eq!(json["analysis"]["transfers"][0]["lamports"], 1_000);
+    assert_eq!(json["analysis"]["message_version"], "legacy");
+    assert!(json["analysis"]["extension_actions"].is_array());
+    assert_eq!(json["summary"]["total_sol_send_by_signer"], 2_000);
+    // Per-recipient outflows are written in recipient order, not hash order
+    let mut recipients = [PubkeyBase58::from([2u8; 32]).to_string(), PubkeyBase58::from([1u8; 32]).to_string()];
+    recipients.sort();
+    let outflows_at = payload.find("signer_outflows_by_recipient").unwrap();
+    let first = payload[outflows_at..].find(&recipients[0]).unwrap();
+    let second = payload[outflows_at..].find(&recipients[1]).unwrap();
+    assert!(first < second);
+    assert_eq!(json["summary"]["output_path"], "out.b64");
+    let raw = serialize_transaction(&tx);
+    assert_eq!(json["signed"]["base64"], BASE64.encode(&raw));
+    assert_eq!(json["signed"]["base58"], bs58::encode(&raw).into_string());
+}
+
+#[test]
+fn rendered_message_bytes_match_serialized_transaction() {
+    use solana_tools_lite::codec::serialize_transaction;
+    use solana_tools_lite::data_encoding::{BASE64, HEXLOWER};
+    use solana_tools_lite::models::instruction::Instruction;
+    use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+    use solana_tools_lite_cli::models::cmds::DataEncoding;
+
+    let tx = Transaction {
+        signatures: vec![Signature::from_bytes(&[7u8; 64])],
+        message: Message::Legacy(MessageLegacy {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![PubkeyBase58::from([3u8; 32]), PubkeyBase58::from([0u8; 32])],
+            recent_blockhash: HashBase58([9u8; 32]),
+            instructions: vec![Instruction { program_id_index: 1, accounts: vec![0], data: vec![2, 0, 0, 0] }],
+        }),
+    };
+
+    // Wire layout: shortvec(1) + one 64-byte signature + message
+    let wire = serialize_transaction(&tx);
+    let message_portion = &wire[1 + 64..];
+
+    assert_eq!(
+        sign_tx::render_message_bytes(&tx.message, DataEncoding::Hex),
+        HEXLOWER.encode(message_portion)
+    );
+    assert_eq!(
+        sign_tx::render_message_bytes(&tx.message, DataEncoding::Base64),
+        BASE64.encode(message_portion)
+    );
+    assert_eq!(
+        sign_tx::render_message_bytes(&tx.message, DataEncoding::Base58),
+        bs58::encode(message_portion).into_string()
+    );
+}
+
+#[test]
+fn sign_tx_output_dir_names_file_after_signature() {
+    let dir = TempDir::new().unwrap();
+    let key = SigningKey::from_bytes(&[1u8; 32]);
+    let signer_pk = bs58::encode(key.verifying_key().to_bytes()).into_string();
+    let table_key = bs58::encode([7u8; 32]).into_string();
+
+    let tx_path = dir.path().join("tx.json");
+    fs::write(&tx_path, build_v0_tx_json(&signer_pk, &table_key)).unwrap();
+    let tables_path = write_tables_file(&dir, &table_key, &[bs58::encode([8u8; 32]).into_string()]);
+    let keypair_path = write_keypair_file(&dir);
+    let out_dir = dir.path().join("signed");
+
+    let opts = SignTxOptions {
+        input: Some(tx_path.to_str().unwrap()),
+        output_dir: Some(out_dir.to_str().unwrap()),
+        out_override: Some(OutFmt::Base64),
+        lookup_tables_path: Some(tables_path.as_str()),
+        assume_yes: true,
+        ..Default::default()
+    };
+    sign_tx::execute(&keypair_path, &opts)
+    .expect("signing should succeed");
+
+    let entries: Vec<_> = fs::read_dir(&out_dir).unwrap().map(|e| e.unwrap().path()).collect();
+    assert_eq!(entries.len(), 1);
+    let name = entries[0].file_name().unwrap().to_string_lossy().to_string();
+    let stem = name.strip_suffix(".b64").expect("base64 extension");
+    assert_eq!(bs58::decode(stem).into_vec().unwrap().len(), 64);
+}
+
+#[test]
+fn sign_tx_qr_encodes_the_signed_base64() {
+    use solana_tools_lite_cli::flows::presenter::qr::{qr_chunks, render_qr, QR_CHUNK_CHARS};
+
+    let dir = TempDir::new().unwrap();
+    let key = SigningKey::from_bytes(&[1u8; 32]);
+    let signer_pk = bs58::encode(key.verifying_key().to_bytes()).into_string();
+    let table_key = bs58::encode([7u8; 32]).into_string();
+
+    let tx_path = dir.path().join("tx.json");
+    fs::write(&tx_path, build_v0_tx_json(&signer_pk, &table_key)).unwrap();
+    let tables_path = write_tables_file(&dir, &table_key, &[bs58::encode([8u8; 32]).into_string()]);
+    let keypair_path = write_keypair_file(&dir);
+    let output_path = dir.path().join("signed.b64");
+
+    let opts = SignTxOptions {
+        input: Some(tx_path.to_str().unwrap()),
+        output: Some(output_path.to_str().unwrap()),
+        out_override: Some(OutFmt::Base64),
+        lookup_tables_path: Some(tables_path.as_str()),
+        assume_yes: true,
+        qr: true,
+        ..Default::default()
+    };
+    sign_tx::execute(&keypair_path, &opts)
+    .expect("signing with --qr should succeed");
+
+    // A small transaction fits one code whose payload is exactly the signed Base64
+    let signed_b64 = fs::read_to_string(&output_path).unwrap().trim().to_string();
+    let chunks = qr_chunks(&signed_b64);
+    assert_eq!(chunks, vec![signed_b64.clone()]);
+
+    // Decoded back to modules, the rendered code is the code of the signed Base64
+    let expected = qrcode::QrCode::new(signed_b64.as_bytes()).unwrap();
+    let rendered = render_qr(&chunks[0]).unwrap();
+    assert_eq!(dense1x2_modules(&rendered, expected.width()), expected.to_colors());
+
+    // Oversized payloads are split with index headers and reassemble in order
+    let long = "A".repeat(QR_CHUNK_CHARS * 2 + 1);
+    let parts = qr_chunks(&long);
+    assert_eq!(parts.len(), 3);
+    assert!(parts[0].starts_with("1/3:") && parts[2].starts_with("3/3:"));
+    let joined: String = parts.iter().map(|p| p.split_once(':').unwrap().1).collect();
+    assert_eq!(joined, long);
+}
+
+/// Modules of a `Dense1x2` rendering (two module rows per line, 4-module quiet zone),
+/// without the quiet zone.
+fn dense1x2_modules(rendered: &str, width: usize) -> Vec<qrcode::Color> {
+    const QUIET: usize = 4;
+    let rows: Vec<Vec<bool>> = rendered
+        .lines()
+        .flat_map(|line| {
+            let pairs: Vec<(bool, bool)> = line
+                .chars()
+                .map(|c| match c {
+                    '█' => (true, true),
+                    '▀' => (true, false),
+                    '▄' => (false, true),
+                    _ => (false, false),
+                })
+                .collect();
+            [
+                pairs.iter().map(|p| p.0).collect::<Vec<_>>(),
+                pairs.iter().map(|p| p.1).collect::<Vec<_>>(),
+            ]
+        })
+        .collect();
+    rows[QUIET..QUIET + width]
+        .iter()
+        .flat_map(|row| row[QUIET..QUIET + width].iter())
+        .map(|&dark| if dark { qrcode::Color::Dark } else { qrcode::Color::Light })
+        .collect()
+}
+
+fn sign_with_fee_payer_requirement(dir: &TempDir, require_fee_payer: bool) -> Result<(), String> {
+    let key = SigningKey::from_bytes(&[1u8; 32]);
+    let signer_pk = bs58::encode(key.verifying_key().to_bytes()).into_string();
+    let table_key = bs58::encode([7u8; 32]).into_string();
+
+    // The signer is account #0, so it pays the fee
+    let tx_path = dir.path().join("tx.json");
+    fs::write(&tx_path, build_v0_tx_json(&signer_pk, &table_key)).unwrap();
+    let tables_path = write_tables_file(dir, &table_key, &[bs58::encode([8u8; 32]).into_string()]);
+    let keypair_path = write_keypair_file(dir);
+    let output_path = dir.path().join(format!("signed_{require_fee_payer}.b64"));
+
+    let opts = SignTxOptions {
+        input: Some(tx_path.to_str().unwrap()),
+        output: Some(output_path.to_str().unwrap()),
+        lookup_tables_path: Some(tables_path.as_str()),
+        assume_yes: true,
+        require_fee_payer: Some(require_fee_payer),
+        ..Default::default()
+    };
+    sign_tx::execute(&keypair_path, &opts)
+    .map(drop)
+    .map_err(|e| e.to_string())
+}
+
+#[test]
+fn require_fee_payer_match_signs() {
+    let dir = TempDir::new().unwrap();
+    sign_with_fee_payer_requirement(&dir, true).expect("signer is the fee payer");
+    assert!(dir.path().join("signed_true.b64").exists());
+}
+
+#[test]
+fn require_fee_payer_mismatch_refuses() {
+    let dir = TempDir::new().unwrap();
+    let err = sign_with_fee_payer_requirement(&dir, false).expect_err("signer pays the fee");
+    assert!(err.contains("--require-fee-payer"));
+    assert!(!dir.path().join("signed_false.b64").exists());
+}
@@ -1,18 +1,21 @@
-use crate::models::Transaction;
+use crate::models::{Message, Transaction};
 use crate::codec::serialize_transaction;
 use crate::constants::permission::{FILE_PERMS_PUBLIC, FILE_PERMS_SECRET};
 use crate::crypto::helpers::parse_signing_key_content;
-use crate::errors::IoError;
+use crate::errors::{IoError, TransactionParseError};
 use crate::serde::{parse_lookup_tables, LookupTableEntry};
 use crate::layers::io as io_layer;
-use crate::models::input_transaction::{InputTransaction, UiTransaction};
+use crate::models::input_transaction::{InputFormat, InputTransaction, UiTransaction};
+use crate::models::rent::RentConfig;
 use crate::serde::fmt::OutputFormat;
+use crate::serde::input_tx::detect_text_encoding;
 use crate::{Result, ToolError};
 use bs58;
 use data_encoding::BASE64 as B64;
 use ed25519_dalek::SigningKey;
 use std::io as std_io;
 use std::path::{Path, PathBuf};
+use crate::crypto::secret::Zeroizing;
 
 // Private source enum: used internally to model a single text input source
 enum TextSource<'a> {
@@ -21,9 +24,9 @@ enum TextSource<'a> {
     Stdin,
 }
 
-/// Read from a file or stdin ("-") based on `path`.
+/// Read from a file or stdin ("-") based on `path`; stdin is capped at `max_stdin_size` bytes.
 /// Returns adapter-level IoError with optional path context.
-fn read_input(path: Option<&str>) -> std::result::Result<String, IoError> {
+fn read_input(path: Option<&str>, max_stdin_size: u64) -> std::result::Result<String, IoError> {
     match path {
         Some(p) if p != "-" => {
             io_layer::read_from_file(Path::new(p)).map_err(|e| IoError::IoWithPath {
@@ -31,7 +34,7 @@ fn read_input(path: Option<&str>) -> std::result::Result<String, IoError> {
                 path: Some(p.to_string()),
             })
         }
-        _ => io_layer::read_from_stdin().map_err(|e| IoError::IoWithPath {
+        _ => io_layer::read_from_stdin(max_stdin_size).map_err(|e| IoError::IoWithPath {
             source: e,
             path: None,
         }),
@@ -50,7 +53,7 @@ fn read_input(path: Option<&str>) -> std::result::Result<String, IoError> {
 /// Contract:
 /// - Exactly one of `inline` or `file` must be `Some`.
 /// - If `file == Some("-")`: reads from stdin when `allow_stdin == true`, otherwise returns an error.
-/// - If `file == Some(path)`: reads the whole file as UTF-8 text via `read_input(Some(path))`.
+/// - If `file == Some(path)`: reads the whole file as UTF-8 text via `read_input`.
 /// - If `inline == Some(s)`: returns `s` as-owned `String`.
 pub fn read_text_source(
     inline: Option<&str>,
@@ -87,31 +90,148 @@ fn write_output(path: Option<&str>, data: &str, force: bool) -> std::result::Res
     })
 }
 
-/// Read and parse an input transaction from file/stdin, auto-detecting its format.
+/// How transaction and message readers treat what they read; `Default` is lenient with
+/// the default stdin cap.
+#[derive(Clone, Copy, Debug)]
+pub struct ReadOptions {
+    /// Parse strictly (`serde::input_tx::parse_input_transaction_strict` when the format
+    /// is detected) and validate signatures (`serde::input_tx::validate_signatures`).
+    pub strict: bool,
+    /// Stdin size cap in bytes (files have their own fixed cap).
+    pub max_stdin_size: u64,
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self {
+            strict: false,
+            max_stdin_size: io_layer::DEFAULT_MAX_STDIN_SIZE,
+        }
+    }
+}
+
+fn check_strict_signatures(input: InputTransaction, read: ReadOptions) -> Result<InputTransaction> {
+    if read.strict {
+        crate::serde::input_tx::validate_signatures(&input)?;
+    }
+    Ok(input)
+}
+
+/// Read and parse an input transaction from file/stdin, auto-detecting its format
+/// (`ReadOptions::default()`).
 pub fn read_input_transaction(input: Option<&str>) -> Result<InputTransaction> {
-    // Read raw text via IO layer first (file or stdin), then detect format
-    let raw = match input {
-        Some(p) => read_input(Some(p))?,
-        None => read_input(None)?,
+    read_input_transaction_as(input, None, ReadOptions::default())
+}
+
+/// Read and parse an input transaction from file/stdin in a forced format.
+///
+/// With `format = None` the format is auto-detected as in `read_input_transaction`;
+/// `read.strict` detects it with the strict parser, which rejects trailing data, and
+/// additionally validates the signatures.
+pub fn read_input_transaction_as(
+    input: Option<&str>,
+    format: Option<InputFormat>,
+    read: ReadOptions,
+) -> Result<InputTransaction> {
+    // Read raw text via IO layer first (file or stdin), then decode
+    let raw = read_input(input, read.max_stdin_size)?;
+    let parsed = match (format, read.strict) {
+        (Some(format), _) => crate::serde::input_tx::parse_input_transaction_as(Some(&raw), format),
+        (None, true) => crate::serde::input_tx::parse_input_transaction_strict(Some(&raw)),
+        (None, false) => crate::serde::input_tx::parse_input_transaction(Some(&raw)),
+    }
+    .map_err(ToolError::from)?;
+    check_strict_signatures(parsed, read)
+}
+
+/// Read a message-only input (wire bytes without signatures) from file/stdin.
+///
+/// Accepts Base64 or Base58 text, tried in that order, e.g. web3.js `message.serialize()`.
+/// A forced `format` skips detection; JSON is not a message encoding and is rejected.
+/// Only `read.max_stdin_size` applies (a message carries no signatures).
+pub fn read_input_message(
+    input: Option<&str>,
+    format: Option<InputFormat>,
+    read: ReadOptions,
+) -> Result<Message> {
+    let raw = read_input(input, read.max_stdin_size)?;
+    let trimmed = raw.trim();
+
+    let decode_base64 = || {
+        B64.decode(trimmed.as_bytes())
+            .map_err(|e| TransactionParseError::InvalidBase64(e.to_string()))
+    };
+    let decode_base58 = || {
+        bs58::decode(trimmed)
+            .into_vec()
+            .map_err(|e| TransactionParseError::InvalidBase58(e.to_string()))
     };
-    crate::serde::input_tx::parse_input_transaction(Some(&raw)).map_err(ToolError::from)
+
+    // Same detection order as transaction input
+    let bytes = match format.or_else(|| detect_text_encoding(trimmed)) {
+        Some(InputFormat::Base64) => decode_base64()?,
+        Some(InputFormat::Base58) => decode_base58()?,
+        Some(InputFormat::Json) => {
+            return Err(TransactionParseError::InvalidFormat(
+                "message input cannot be JSON; use Base64 or Base58".into(),
+            )
+            .into());
+        }
+        None => {
+            return Err(TransactionParseError::InvalidFormat(
+                "message input must be Base64 or Base58".into(),
+            )
+            .into());
+        }
+    };
+
+    Ok(Message::from_wire_bytes(&bytes)?)
 }
 
 /// Read lookup tables JSON from file or stdin ("-") and parse into lookup table entry.
 pub fn read_lookup_tables(
     path: &str,
 ) -> Result<LookupTableEntry> {
-    let raw = read_input(Some(path)).map_err(ToolError::Io)?;
+    let raw = read_input(Some(path), io_layer::DEFAULT_MAX_STDIN_SIZE).map_err(ToolError::Io)?;
     parse_lookup_tables(&raw).map_err(ToolError::from)
 }
 
+/// Read rent parameters from a JSON file (or stdin with `-`).
+///
+/// Format: `{"lamports_per_byte_year": 3480, "exemption_threshold": 2.0}`; omitted fields
+/// keep the Solana defaults, unknown fields are rejected.
+pub fn read_rent_config(path: &str) -> Result<RentConfig> {
+    let raw = read_input(Some(path), io_layer::DEFAULT_MAX_STDIN_SIZE).map_err(ToolError::Io)?;
+    serde_json::from_str(&raw)
+        .map_err(|e| ToolError::InvalidInput(format!("invalid rent config {path}: {e}")))
+}
+
+/// Prefix selecting an environment variable instead of a keypair file (`env:VAR_NAME`).
+pub const KEYPAIR_ENV_PREFIX: &str = "env:";
+
 /// Read a secret key file and parse it into a SigningKey.
+///
+/// `env:VAR_NAME` reads the key content from that environment variable instead,
+/// so it never has to touch the disk or the command line.
 pub fn read_and_parse_secret_key(path: &str) -> Result<SigningKey> {
-    let text = read_secret_key_file(path)?;
+    let text = match path.strip_prefix(KEYPAIR_ENV_PREFIX) {
+        Some(var) => read_secret_key_env(var)?,
+        None => Zeroizing::new(read_secret_key_file(path)?),
+    };
     let key = parse_signing_key_content(&text)?;
     Ok(key)
 }
 
+/// Read secret key content from an environment variable; the value is never echoed.
+fn read_secret_key_env(var: &str) -> Result<Zeroizing<String>> {
+    match std::env::var(var) {
+        Ok(value) if !value.trim().is_empty() => Ok(Zeroizing::new(value)),
+        _ => Err(ToolError::InvalidInput(format!(
+            "keypair environment variable {var} is not set or empty"
+        ))),
+    }
+}
+
 /// Read a secret key file as text (stdin is rejected for safety).
 pub fn read_secret_key_file(path: &str) -> std::result::Result<String, ToolError> {
     // For security reasons, reading secret keys from stdin is disabled.
@@ -144,7 +264,7 @@ pub fn read_secret_key_file(path: &str) -> std::result::Result<String, ToolError
         }));
     }
 
-    let s = read_input(Some(path)).map_err(ToolError::Io)?;
+    let s = read_input(Some(path), io_layer::DEFAULT_MAX_STDIN_SIZE).map_err(ToolError::Io)?;
 
     Ok(s.trim().to_string())
 }
@@ -242,10 +362,23 @@ pub fn read_mnemonic(input: &str) -> Result<String> {
         "-" => None,
         _ => Some(input),
     };
-    let raw = read_input(path).map_err(ToolError::Io)?;
+    let raw = read_input(path, io_layer::DEFAULT_MAX_STDIN_SIZE).map_err(ToolError::Io)?;
     Ok(raw.split_whitespace().collect::<Vec<_>>().join(" "))
 }
 
+/// Read a hex-encoded seed from file or stdin (`-`).
+///
+/// Only surrounding whitespace is trimmed; unlike `read_mnemonic`, the content is not
+/// normalized, so a malformed seed is reported by the hex decoder as written.
+pub fn read_seed_hex(input: &str) -> Result<String> {
+    let path = match input {
+        "-" => None,
+        _ => Some(input),
+    };
+    let raw = read_input(path, io_layer::DEFAULT_MAX_STDIN_SIZE).map_err(ToolError::Io)?;
+    Ok(raw.trim().to_string())
+}
+
 /// Read passphrase from file or stdin ("-") without altering internal whitespace.
 /// Trims only trailing newlines ("\n"/"\r\n").
 pub fn read_passphrase(input: &str) -> Result<String> {
@@ -253,7 +386,7 @@ pub fn read_passphrase(input: &str) -> Result<String> {
         "-" => None,
         _ => Some(input),
     };
-    let raw = read_input(path).map_err(ToolError::Io)?;
+    let raw = read_input(path, io_layer::DEFAULT_MAX_STDIN_SIZE).map_err(ToolError::Io)?;
     Ok(raw.trim_end_matches(['\r', '\n']).to_string())
 }
 
@@ -292,12 +425,13 @@ pub fn write_secret_file(
 
 /// Write non-secret public artifact to a file path, respecting `force` and using 0o644 perms.
 /// - Stdout is NOT allowed here (use `write_output` for stdout writes).
+/// - Written via temp file + rename, so an interrupted write never truncates an existing file.
 pub fn write_public_file(
     path: &Path,
     data: &str,
     force: bool,
 ) -> std::result::Result<(), ToolError> {
-    write_bytes_file_with_opts(path, data.as_bytes(), FILE_PERMS_PUBLIC, force).map_err(|e| {
+    write_bytes_file_atomic(path, data.as_bytes(), FILE_PERMS_PUBLIC, force).map_err(|e| {
         ToolError::Io(IoError::IoWithPath {
             source: e,
             path: Some(path.display().to_string()),
@@ -329,14 +463,16 @@ fn to_text_source<'a>(inline: Option<&'a str>, file: Option<&'a str>) -> Result<
 fn resolve_text_source(src: TextSource<'_>, allow_stdin: bool) -> Result<String> {
     match src {
         TextSource::Inline(s) => Ok(s.to_owned()),
-        TextSource::File(p) => read_input(Some(p)).map_err(ToolError::Io),
+        TextSource::File(p) => {
+            read_input(Some(p), io_layer::DEFAULT_MAX_STDIN_SIZE).map_err(ToolError::Io)
+        }
         TextSource::Stdin => {
             if !allow_stdin {
                 return Err(ToolError::InvalidInput(
                     "reading from stdin is disabled".to_string(),
                 ));
             }
-            read_input(None).map_err(ToolError::Io)
+            read_input(None, io_layer::DEFAULT_MAX_STDIN_SIZE).map_err(ToolError::Io)
         }
     }
 }
@@ -347,6 +483,28 @@ pub fn read_message(inline: Option<&str>, file: Option<&str>) -> Result<String>
     resolve_text_source(src, true)
 }
 
+/// Read message bytes from inline text or a file/stdin ("-") without any UTF-8 requirement.
+/// Used by raw signing, where the exact bytes matter.
+pub fn read_message_bytes(inline: Option<&str>, file: Option<&str>) -> Result<Vec<u8>> {
+    let read = |path: Option<&str>| {
+        match path {
+            Some(p) => io_layer::read_bytes_from_file(Path::new(p)),
+            None => io_layer::read_bytes_from_stdin(io_layer::DEFAULT_MAX_STDIN_SIZE),
+        }
+        .map_err(|e| {
+            ToolError::Io(IoError::IoWithPath {
+                source: e,
+                path: path.map(str::to_string),
+            })
+        })
+    };
+    match to_text_source(inline, file)? {
+        TextSource::Inline(s) => Ok(s.as_bytes().to_vec()),
+        TextSource::File(p) => read(Some(p)),
+        TextSource::Stdin => read(None),
+    }
+}
+
 /// Read signature from inline/file/stdin and trim trailing whitespace/newlines
 pub fn read_signature(inline: Option<&str>, file: Option<&str>) -> Result<String> {
     let src = to_text_source(inline, file)?;
@@ -371,7 +529,7 @@ enum OutputTarget<'a> {
 /// Low-level writer: writes to either stdout or a file depending on `target`.
 ///
 /// - For `OutputTarget::Stdout`, writes bytes as-is to stdout and flushes.
-/// - For `OutputTarget::File`, delegates to `write_bytes_file_with_opts` with the provided
+/// - For `OutputTarget::File`, delegates to `write_bytes_file_atomic` with the provided
 ///   permissions (`perms`) and overwrite policy (`force`).
 ///
 /// This helper centralizes the “stdout vs file” branching so upper layers can express intent
@@ -389,8 +547,25 @@ fn write_bytes_with_opts(
             stdout.flush()?;
             Ok(())
         }
-        OutputTarget::File(p) => write_bytes_file_with_opts(p, bytes, perms, force),
+        OutputTarget::File(p) => write_bytes_file_atomic(p, bytes, perms, force),
+    }
+}
+
+/// File-only writer for public artifacts: writes to a temp file and renames it into place.
+///
+/// - Honors `force` the same way as `write_bytes_file_with_opts`.
+/// - A failure before the rename leaves any existing file at `path` untouched.
+/// - An existing FIFO or device is written in place instead (it cannot be replaced by a rename).
+fn write_bytes_file_atomic(
+    path: &Path,
+    bytes: &[u8],
+    perms: u32,
+    force: bool,
+) -> std::result::Result<(), std::io::Error> {
+    if io_layer::is_special_file(path) {
+        return io_layer::write_to_special_file(path, bytes);
     }
+    io_layer::write_atomic(path, perms, force, |file| file.write_all(bytes))
 }
 
 /// File-only writer: safely writes bytes to a filesystem path.
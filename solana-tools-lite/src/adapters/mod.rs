@@ -1,2 +1,3 @@
+#[cfg(feature = "io")]
 pub mod io_adapter;
 pub mod transaction_adapter;
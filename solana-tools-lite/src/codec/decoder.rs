@@ -20,7 +20,18 @@ const VERSION_0: u8 = 0;
 /// 1. Signatures count (short_vec)
 /// 2. Signatures (64 bytes each)
 /// 3. Message (Legacy or V0)
+///
+/// Trailing bytes after the message are ignored; use `deserialize_transaction_with_len`
+/// to detect them.
 pub fn deserialize_transaction(data: &[u8]) -> Result<Transaction, DeserializeError> {
+    deserialize_transaction_with_len(data).map(|(tx, _)| tx)
+}
+
+/// Deserialize a full Solana transaction from raw bytes.
+/// Returns parsed transaction and bytes consumed.
+pub fn deserialize_transaction_with_len(
+    data: &[u8],
+) -> Result<(Transaction, usize), DeserializeError> {
     let mut cursor = 0;
 
     // 1. Read signature count (compact-u16).
@@ -47,29 +58,50 @@ pub fn deserialize_transaction(data: &[u8]) -> Result<Transaction, DeserializeEr
     }
 
     // 3. Parse Message: Legacy or V0 by prefix
-    let message = if data
-        .get(cursor)
+    let (message, consumed) = deserialize_message(&data[cursor..])?;
+    cursor += consumed;
+
+    Ok((
+        Transaction {
+            signatures,
+            message,
+        },
+        cursor,
+    ))
+}
+
+/// Deserialize a message (Legacy or V0, detected by the version prefix).
+/// Returns parsed message and bytes consumed.
+pub fn deserialize_message(data: &[u8]) -> Result<(Message, usize), DeserializeError> {
+    let is_versioned = data
+        .first()
         .map(|b| b & VERSION_PREFIX != 0)
-        .unwrap_or(false)
-    {
-        let (msg_v0, _) = deserialize_message_v0(&data[cursor..])?;
-        Message::V0(msg_v0)
-    } else {
-        let (msg_legacy, _) = deserialize_message_legacy(&data[cursor..])?;
-        Message::Legacy(msg_legacy)
-    };
+        .unwrap_or(false);
 
-    Ok(Transaction {
-        signatures,
-        message,
-    })
+    if is_versioned {
+        let (msg_v0, consumed) = deserialize_message_v0(data)?;
+        Ok((Message::V0(msg_v0), consumed))
+    } else {
+        let (msg_legacy, consumed) = deserialize_message_legacy(data)?;
+        Ok((Message::Legacy(msg_legacy), consumed))
+    }
 }
 
 /// Deserialize a legacy message (non-versioned).
 /// Returns parsed message and bytes consumed.
+///
+/// A leading byte with the version prefix bit set is a versioned message, never a legacy
+/// header, and is rejected with `DeserializeError::VersionMismatch`.
 pub fn deserialize_message_legacy(data: &[u8]) -> Result<(MessageLegacy, usize), DeserializeError> {
     let mut cursor = 0;
 
+    if data.first().is_some_and(|b| b & VERSION_PREFIX != 0) {
+        return Err(DeserializeError::VersionMismatch {
+            expected: "legacy",
+            found: "versioned",
+        });
+    }
+
     // Header (3 bytes)
     if data.len() < 3 {
         return Err(DeserializeError::Deserialization(
@@ -98,7 +130,7 @@ pub fn deserialize_message_legacy(data: &[u8]) -> Result<(MessageLegacy, usize),
         let pubkey_bytes: [u8; 32] = data[cursor..cursor + 32]
             .try_into()
             .map_err(|_| DeserializeError::Deserialization("Invalid pubkey length".to_string()))?;
-        account_keys.push(PubkeyBase58(pubkey_bytes));
+        account_keys.push(PubkeyBase58::from_bytes(&pubkey_bytes));
         cursor += 32;
     }
 
@@ -167,9 +199,10 @@ pub fn deserialize_message_v0(data: &[u8]) -> Result<(MessageV0, usize), Deseria
 
     // Extract version (lower 7 bits)
     if version & VERSION_PREFIX == 0 {
-        return Err(DeserializeError::Deserialization(
-            "Expected versioned message prefix".to_string(),
-        ));
+        return Err(DeserializeError::VersionMismatch {
+            expected: "v0",
+            found: "legacy",
+        });
     }
     let ver = version & !VERSION_PREFIX;
     if ver != VERSION_0 {
@@ -208,7 +241,7 @@ pub fn deserialize_message_v0(data: &[u8]) -> Result<(MessageV0, usize), Deseria
         let pubkey_bytes: [u8; PUBKEY_LEN] = data[cursor..cursor + PUBKEY_LEN]
             .try_into()
             .map_err(|_| DeserializeError::Deserialization("Invalid pubkey length".to_string()))?;
-        account_keys.push(PubkeyBase58(pubkey_bytes));
+        account_keys.push(PubkeyBase58::from_bytes(&pubkey_bytes));
         cursor += PUBKEY_LEN;
     }
 
@@ -273,7 +306,7 @@ pub fn deserialize_message_v0(data: &[u8]) -> Result<(MessageV0, usize), Deseria
         cursor += readonly_len;
 
         address_table_lookups.push(MessageAddressTableLookup {
-            account_key: PubkeyBase58(account_key_bytes),
+            account_key: PubkeyBase58::from_bytes(&account_key_bytes),
             writable_indexes,
             readonly_indexes,
         });
@@ -1,7 +1,7 @@
 use crate::codec::short_vec::write_shortvec_len;
 use crate::models::instruction::Instruction;
 use crate::models::message::{Message, MessageLegacy, MessageV0};
-use crate::models::{HashBase58, PubkeyBase58, Transaction};
+use crate::models::{HashBase58, Transaction};
 
 const VERSION_PREFIX: u8 = 0x80;
 const VERSION_0: u8 = 0;
@@ -58,8 +58,8 @@ pub fn serialize_message_legacy(msg: &MessageLegacy) -> Vec<u8> {
     buf.push(msg.header.num_readonly_unsigned_accounts);
 
     write_shortvec_len(msg.account_keys.len(), &mut buf);
-    for PubkeyBase58(pk) in &msg.account_keys {
-        buf.extend_from_slice(pk);
+    for pk in &msg.account_keys {
+        buf.extend_from_slice(pk.as_bytes());
     }
 
     let HashBase58(bh) = &msg.recent_blockhash;
@@ -83,8 +83,8 @@ pub fn serialize_message_v0(msg: &MessageV0) -> Vec<u8> {
     buf.push(msg.header.num_readonly_unsigned_accounts);
 
     write_shortvec_len(msg.account_keys.len(), &mut buf);
-    for PubkeyBase58(pk) in &msg.account_keys {
-        buf.extend_from_slice(pk);
+    for pk in &msg.account_keys {
+        buf.extend_from_slice(pk.as_bytes());
     }
 
     let HashBase58(bh) = &msg.recent_blockhash;
@@ -97,7 +97,7 @@ pub fn serialize_message_v0(msg: &MessageV0) -> Vec<u8> {
 
     write_shortvec_len(msg.address_table_lookups.len(), &mut buf);
     for lut in &msg.address_table_lookups {
-        buf.extend_from_slice(&lut.account_key.0);
+        buf.extend_from_slice(lut.account_key.as_bytes());
         write_shortvec_len(lut.writable_indexes.len(), &mut buf);
         buf.extend_from_slice(&lut.writable_indexes);
         write_shortvec_len(lut.readonly_indexes.len(), &mut buf);
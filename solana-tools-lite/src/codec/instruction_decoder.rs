@@ -1,6 +1,45 @@
+use crate::constants::crypto::PUBKEY_LEN;
+use crate::models::pubkey_base58::PubkeyBase58;
+
 // System Program
+const SYSTEM_CREATE_ACCOUNT_TAG: u32 = 0; // accounts: [funding, new account]
+const SYSTEM_CREATE_ACCOUNT_DATA_LEN: usize = 52; // tag (4) + lamports (8) + space (8) + owner (32)
 const SYSTEM_TRANSFER_TAG: u32 = 2;
-const SYSTEM_TRANSFER_DATA_LEN: usize = 12; // tag (4) + lamports (8)
+pub(crate) const SYSTEM_TRANSFER_DATA_LEN: usize = 12; // tag (4) + lamports (8)
+const SYSTEM_WITHDRAW_NONCE_TAG: u32 = 5;
+const SYSTEM_WITHDRAW_NONCE_DATA_LEN: usize = 12; // tag (4) + lamports (8)
+
+// SPL Token / Token-2022 (same instruction layout)
+const TOKEN_CLOSE_ACCOUNT_TAG: u8 = 9;
+const TOKEN_CLOSE_ACCOUNT_DESTINATION_INDEX: usize = 1; // accounts: [account, destination, owner]
+const TOKEN_APPROVE_TAG: u8 = 4;
+const TOKEN_APPROVE_DELEGATE_INDEX: usize = 1; // accounts: [source, delegate, owner]
+const TOKEN_REVOKE_TAG: u8 = 5;
+const TOKEN_APPROVE_CHECKED_TAG: u8 = 13;
+const TOKEN_APPROVE_CHECKED_DELEGATE_INDEX: usize = 2; // accounts: [source, mint, delegate, owner]
+const TOKEN_AMOUNT_DATA_LEN: usize = 9; // tag (1) + amount (8)
+const TOKEN_SET_AUTHORITY_TAG: u8 = 6;
+const TOKEN_SET_AUTHORITY_MIN_LEN: usize = 3; // tag (1) + authority type (1) + COption tag (1)
+const TOKEN_INITIALIZE_MINT_TAG: u8 = 0; // accounts: [mint, rent]
+const TOKEN_INITIALIZE_MINT2_TAG: u8 = 20; // accounts: [mint]
+const TOKEN_INITIALIZE_MINT_MIN_LEN: usize = 35; // tag (1) + decimals (1) + authority (32) + COption tag (1)
+const TOKEN_INITIALIZE_ACCOUNT_TAG: u8 = 1; // accounts: [account, mint, owner, rent]
+const TOKEN_INITIALIZE_ACCOUNT3_TAG: u8 = 18; // accounts: [account, mint]; owner in data
+const TOKEN_SYNC_NATIVE_TAG: u8 = 17; // accounts: [native token account]
+
+// Associated Token Account program
+const ATA_CREATE_TAG: u8 = 0; // empty data is also `Create`
+const ATA_CREATE_IDEMPOTENT_TAG: u8 = 1;
+// accounts: [payer, associated account, wallet, mint, system program, token program]
+const ATA_CREATE_ACCOUNT_INDEX: usize = 1;
+const ATA_CREATE_MINT_INDEX: usize = 3;
+
+// Stake Program
+const STAKE_WITHDRAW_TAG: u32 = 4;
+const STAKE_WITHDRAW_DATA_LEN: usize = 12; // tag (4) + lamports (8)
+// accounts: [stake, recipient, clock, stake_history, withdraw_authority, (custodian)]
+const STAKE_WITHDRAW_RECIPIENT_INDEX: usize = 1;
+const STAKE_WITHDRAW_AUTHORITY_INDEX: usize = 4;
 
 // Compute Budget
 const COMPUTE_BUDGET_SET_UNIT_LIMIT: u8 = 2;
@@ -9,6 +48,43 @@ const COMPUTE_BUDGET_TAG_LEN: usize = 1;
 const COMPUTE_UNIT_LIMIT_LEN: usize = 4;
 const COMPUTE_UNIT_PRICE_LEN: usize = 8;
 
+pub enum TokenDelegateAction {
+    /// `Approve` / `ApproveChecked`: `delegate_index` is the delegate's position
+    /// within the instruction's account list.
+    Approve { delegate_index: usize, amount: u64 },
+    Revoke,
+    None,
+}
+
+/// Stake `Withdraw`: account positions are within the instruction's account list.
+pub struct StakeWithdraw {
+    pub recipient_index: usize,
+    pub authority_index: usize,
+    pub lamports: u64,
+}
+
+/// SPL Token mint/account initialization. Account positions: mint #0 for mints;
+/// account #0, mint #1 (and owner #2 for `InitializeAccount`) for token accounts.
+pub enum TokenSetupInstruction {
+    /// `InitializeMint` / `InitializeMint2`.
+    InitializeMint {
+        decimals: u8,
+        mint_authority: PubkeyBase58,
+        freeze_authority: Option<PubkeyBase58>,
+    },
+    /// `InitializeAccount`: the owner is account #2.
+    InitializeAccount,
+    /// `InitializeAccount3`: the owner is carried in the data.
+    InitializeAccount3 { owner: PubkeyBase58 },
+}
+
+/// System `CreateAccount`: the funding account is #0, the new account #1.
+pub struct SystemCreateAccount {
+    pub lamports: u64,
+    pub space: u64,
+    pub owner: PubkeyBase58,
+}
+
 pub enum ComputeBudgetAction {
     SetLimit(u32),
     SetPrice(u64),
@@ -27,6 +103,152 @@ pub fn decode_system_transfer_amount(data: &[u8]) -> Option<u64> {
     None
 }
 
+/// Decode System `CreateAccount` (lamports funded, data space, owner program).
+pub fn decode_system_create_account(data: &[u8]) -> Option<SystemCreateAccount> {
+    if data.len() < SYSTEM_CREATE_ACCOUNT_DATA_LEN {
+        return None;
+    }
+    let kind = u32::from_le_bytes(data[0..4].try_into().ok()?);
+    if kind != SYSTEM_CREATE_ACCOUNT_TAG {
+        return None;
+    }
+    let owner: [u8; PUBKEY_LEN] = data[20..52].try_into().ok()?;
+    Some(SystemCreateAccount {
+        lamports: u64::from_le_bytes(data[4..12].try_into().ok()?),
+        space: u64::from_le_bytes(data[12..20].try_into().ok()?),
+        owner: PubkeyBase58::from(owner),
+    })
+}
+
+/// Decode lamports of System `WithdrawNonceAccount` (nonce account -> recipient).
+pub fn decode_system_withdraw_nonce_amount(data: &[u8]) -> Option<u64> {
+    if data.len() < SYSTEM_WITHDRAW_NONCE_DATA_LEN {
+        return None;
+    }
+    let kind = u32::from_le_bytes(data[0..4].try_into().ok()?);
+    if kind == SYSTEM_WITHDRAW_NONCE_TAG {
+        return Some(u64::from_le_bytes(data[4..12].try_into().ok()?));
+    }
+    None
+}
+
+/// Decode Stake `Withdraw` (stake account -> recipient, signed by the withdraw authority).
+pub fn decode_stake_withdraw(data: &[u8]) -> Option<StakeWithdraw> {
+    if data.len() < STAKE_WITHDRAW_DATA_LEN {
+        return None;
+    }
+    let kind = u32::from_le_bytes(data[0..4].try_into().ok()?);
+    if kind != STAKE_WITHDRAW_TAG {
+        return None;
+    }
+    Some(StakeWithdraw {
+        recipient_index: STAKE_WITHDRAW_RECIPIENT_INDEX,
+        authority_index: STAKE_WITHDRAW_AUTHORITY_INDEX,
+        lamports: u64::from_le_bytes(data[4..12].try_into().ok()?),
+    })
+}
+
+/// Decode SPL Token `CloseAccount`: returns the position of the destination (rent recipient)
+/// within the instruction's account list.
+pub fn decode_token_close_account_destination(data: &[u8]) -> Option<usize> {
+    match data.first() {
+        Some(&TOKEN_CLOSE_ACCOUNT_TAG) => Some(TOKEN_CLOSE_ACCOUNT_DESTINATION_INDEX),
+        _ => None,
+    }
+}
+
+/// Decode SPL Token `SyncNative`: returns the position of the native (wSOL) token account
+/// within the instruction's account list.
+pub fn decode_token_sync_native_account(data: &[u8]) -> Option<usize> {
+    match data.first() {
+        Some(&TOKEN_SYNC_NATIVE_TAG) => Some(0),
+        _ => None,
+    }
+}
+
+/// Decode Associated Token Account `Create` / `CreateIdempotent`: returns the positions of
+/// the associated account and its mint within the instruction's account list.
+pub fn decode_associated_token_create(data: &[u8]) -> Option<(usize, usize)> {
+    match data.first() {
+        None | Some(&ATA_CREATE_TAG) | Some(&ATA_CREATE_IDEMPOTENT_TAG) => {
+            Some((ATA_CREATE_ACCOUNT_INDEX, ATA_CREATE_MINT_INDEX))
+        }
+        _ => None,
+    }
+}
+
+/// Decode SPL Token `Approve`, `ApproveChecked` and `Revoke`.
+pub fn decode_token_delegate(data: &[u8]) -> TokenDelegateAction {
+    let delegate_index = match data.first() {
+        Some(&TOKEN_APPROVE_TAG) => TOKEN_APPROVE_DELEGATE_INDEX,
+        Some(&TOKEN_APPROVE_CHECKED_TAG) => TOKEN_APPROVE_CHECKED_DELEGATE_INDEX,
+        Some(&TOKEN_REVOKE_TAG) => return TokenDelegateAction::Revoke,
+        _ => return TokenDelegateAction::None,
+    };
+    if data.len() < TOKEN_AMOUNT_DATA_LEN {
+        return TokenDelegateAction::None;
+    }
+    match data[1..TOKEN_AMOUNT_DATA_LEN].try_into() {
+        Ok(bytes) => TokenDelegateAction::Approve {
+            delegate_index,
+            amount: u64::from_le_bytes(bytes),
+        },
+        Err(_) => TokenDelegateAction::None,
+    }
+}
+
+/// Decode SPL Token `SetAuthority`: returns the raw authority type and the new authority
+/// (`None` when the authority is being removed).
+pub fn decode_token_set_authority(data: &[u8]) -> Option<(u8, Option<PubkeyBase58>)> {
+    if data.first() != Some(&TOKEN_SET_AUTHORITY_TAG) || data.len() < TOKEN_SET_AUTHORITY_MIN_LEN {
+        return None;
+    }
+    let authority_type = data[1];
+    match data[2] {
+        0 => Some((authority_type, None)),
+        1 => {
+            let key = data.get(TOKEN_SET_AUTHORITY_MIN_LEN..TOKEN_SET_AUTHORITY_MIN_LEN + PUBKEY_LEN)?;
+            Some((authority_type, Some(PubkeyBase58::try_from(key).ok()?)))
+        }
+        _ => None,
+    }
+}
+
+/// Decode SPL Token `InitializeMint(2)` and `InitializeAccount(3)`.
+pub fn decode_token_setup(data: &[u8]) -> Option<TokenSetupInstruction> {
+    match *data.first()? {
+        TOKEN_INITIALIZE_MINT_TAG | TOKEN_INITIALIZE_MINT2_TAG => {
+            if data.len() < TOKEN_INITIALIZE_MINT_MIN_LEN {
+                return None;
+            }
+            let mint_authority = PubkeyBase58::try_from(&data[2..2 + PUBKEY_LEN]).ok()?;
+            let freeze_authority = match data[TOKEN_INITIALIZE_MINT_MIN_LEN - 1] {
+                0 => None,
+                1 => {
+                    let key = data.get(
+                        TOKEN_INITIALIZE_MINT_MIN_LEN..TOKEN_INITIALIZE_MINT_MIN_LEN + PUBKEY_LEN,
+                    )?;
+                    Some(PubkeyBase58::try_from(key).ok()?)
+                }
+                _ => return None,
+            };
+            Some(TokenSetupInstruction::InitializeMint {
+                decimals: data[1],
+                mint_authority,
+                freeze_authority,
+            })
+        }
+        TOKEN_INITIALIZE_ACCOUNT_TAG => Some(TokenSetupInstruction::InitializeAccount),
+        TOKEN_INITIALIZE_ACCOUNT3_TAG => {
+            let key = data.get(1..1 + PUBKEY_LEN)?;
+            Some(TokenSetupInstruction::InitializeAccount3 {
+                owner: PubkeyBase58::try_from(key).ok()?,
+            })
+        }
+        _ => None,
+    }
+}
+
 pub fn decode_compute_budget(data: &[u8]) -> ComputeBudgetAction {
     if data.is_empty() {
         return ComputeBudgetAction::None;
@@ -16,6 +16,11 @@ pub mod crypto {
     pub const SEED_LEN: usize = 32;
 }
 
+pub mod packet {
+    /// Largest serialized transaction (signatures + message) the network accepts.
+    pub const PACKET_DATA_SIZE: usize = 1232;
+}
+
 pub mod compute_budget {
     pub const DEFAULT_COMPUTE_UNIT_LIMIT: u32 = 200_000;
 }
@@ -26,4 +31,25 @@ pub mod programs {
     pub const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
     pub const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
     pub const ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+    /// Native Ed25519 signature verification precompile.
+    pub const ED25519_PROGRAM_ID: &str = "Ed25519SigVerify111111111111111111111111111";
+    /// Native Secp256k1 (Ethereum-style) signature recovery precompile.
+    pub const SECP256K1_PROGRAM_ID: &str = "KeccakSecp256k11111111111111111111111111111";
+    /// SPL Memo v1 (legacy).
+    pub const MEMO_V1_PROGRAM_ID: &str = "Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo";
+    /// SPL Memo v2.
+    pub const MEMO_PROGRAM_ID: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+    /// Native Stake program.
+    pub const STAKE_PROGRAM_ID: &str = "Stake11111111111111111111111111111111111111";
+    /// Squads multisig v3.
+    pub const SQUADS_V3_PROGRAM_ID: &str = "SMPLecH534NA9acpos4G6x7uf3LWbCAwZQE9e8ZekMu";
+    /// Squads multisig v4.
+    pub const SQUADS_V4_PROGRAM_ID: &str = "SQDS4ep65T869zMMBKyuUq6aD6EgTMKzo8q5eXsH4Tj";
+    /// Multisig programs: signing for them is usually an approval of a vault transaction.
+    pub const MULTISIG_PROGRAM_IDS: &[&str] = &[SQUADS_V3_PROGRAM_ID, SQUADS_V4_PROGRAM_ID];
+}
+
+pub mod mints {
+    /// Wrapped SOL: Token accounts of this mint hold native lamports (`SyncNative`).
+    pub const NATIVE_MINT_ID: &str = "So11111111111111111111111111111111111111112";
 }
@@ -3,6 +3,8 @@ use bip39::{Language, Mnemonic, MnemonicType};
 #[cfg(feature = "zeroize")]
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+use crate::crypto::secret::Zeroizing;
+
 /// Convenience alias for BIP-39–scoped results.
 pub type Bip39Result<T> = std::result::Result<T, Bip39Error>;
 
@@ -93,9 +95,9 @@ pub fn derive_seed(phrase: &str, passphrase: &str) -> Bip39Result<Seed> {
 /// Derive a 64-byte seed from a validated mnemonic and passphrase.
 pub fn derive_seed_from_mnemonic(mnemonic: &NormalizedMnemonic, passphrase: &str) -> Seed {
     let b_seed = bip39::Seed::new(&mnemonic.inner, passphrase);
-    let mut bytes = [0u8; 64];
+    let mut bytes = Zeroizing::new([0u8; 64]);
     bytes.copy_from_slice(b_seed.as_bytes());
-    Seed::new(bytes)
+    Seed::new(*bytes)
 }
 
 /// Validate a BIP-39 mnemonic phrase.
@@ -104,6 +106,8 @@ pub fn validate_mnemonic(phrase: &str) -> Bip39Result<()> {
 }
 
 /// 64-byte seed wrapper to make zeroing explicit and avoid leaking in Debug.
+///
+/// With the `zeroize` feature the bytes are wiped on drop; call `zeroize()` to wipe them earlier.
 #[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
 pub struct Seed([u8; 64]);
 
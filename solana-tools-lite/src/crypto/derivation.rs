@@ -1,7 +1,9 @@
 use crate::crypto::bip39::Seed;
 use crate::errors::GenError;
+use ed25519_dalek::SigningKey;
 use hmac::{Hmac, Mac};
-use sha2::Sha512;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256, Sha512};
 use std::fmt;
 use std::str::FromStr;
 
@@ -135,3 +137,25 @@ pub fn derive_key_from_seed(
 
     Ok((key, chain_code))
 }
+
+/// BIP32 fingerprint of the SLIP-0010 Ed25519 master key derived from `seed`:
+/// the first 4 bytes of HASH160 (RIPEMD160 of SHA256) of `0x00 || master public key`.
+pub fn master_fingerprint(seed: &Seed) -> [u8; 4] {
+    // HMAC accepts keys of any length, so this cannot fail
+    let mut mac = HmacSha512::new_from_slice(ED25519_SEED).expect("HMAC accepts any key length");
+    mac.update(seed.as_bytes());
+    let result = mac.finalize().into_bytes();
+
+    let mut master_key = [0u8; 32];
+    master_key.copy_from_slice(&result[0..32]);
+    let public_key = SigningKey::from_bytes(&master_key).verifying_key().to_bytes();
+
+    // SLIP-0010 serializes Ed25519 public keys with a 0x00 prefix (33 bytes)
+    let mut prefixed = [0u8; 33];
+    prefixed[1..].copy_from_slice(&public_key);
+
+    let hash160 = Ripemd160::digest(Sha256::digest(prefixed));
+    let mut fingerprint = [0u8; 4];
+    fingerprint.copy_from_slice(&hash160[0..4]);
+    fingerprint
+}
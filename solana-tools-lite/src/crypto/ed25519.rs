@@ -19,6 +19,15 @@ pub fn keypair_from_seed(seed: &[u8]) -> Result<SigningKey, KeypairError> {
 pub fn sign_message(key: &SigningKey, message: &[u8]) -> Signature {
     key.sign(message)
 }
+
+/// Sign arbitrary bytes with no checks on what they contain.
+///
+/// This is the blind-signing escape hatch: callers must make sure `bytes` are not a
+/// serialized transaction or message (see `handlers::sign_message::handle_raw`).
+pub fn sign_raw_bytes(key: &SigningKey, bytes: &[u8]) -> Signature {
+    key.sign(bytes)
+}
+
 /// Verify a signature against raw message bytes using a verifying key.
 pub fn verify_signature(pubkey: &VerifyingKey, message: &[u8], signature: &Signature) -> bool {
     pubkey.verify(message, signature).is_ok()
@@ -51,9 +60,9 @@ pub fn verify_signature_raw(
     let pubkey = ed25519_dalek::VerifyingKey::from_bytes(&pubkey_array)
         .map_err(|_| VerifyError::InvalidPubkeyFormat)?;
 
-    pubkey
-        .verify(message.as_bytes(), &signature)
-        .map_err(|_| VerifyError::VerificationFailed)?;
+    if !verify_signature(&pubkey, message.as_bytes(), &signature) {
+        return Err(VerifyError::VerificationFailed);
+    }
 
     Ok(())
 }
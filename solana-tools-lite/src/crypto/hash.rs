@@ -0,0 +1,52 @@
+//! Message digests for off-chain coordination (e.g. the `message-hash` command).
+
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+/// Length in bytes of every supported digest.
+pub const DIGEST_LEN: usize = 32;
+
+/// Supported digest algorithms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HashAlgo {
+    #[default]
+    Sha256,
+    Blake3,
+    /// Keccak-256 as used by Ethereum (not the padded NIST SHA3-256).
+    Keccak256,
+}
+
+impl HashAlgo {
+    /// Lowercase algorithm name, as accepted on the command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Blake3 => "blake3",
+            Self::Keccak256 => "keccak256",
+        }
+    }
+}
+
+/// SHA-256 of `data`.
+pub fn sha256(data: &[u8]) -> [u8; DIGEST_LEN] {
+    Sha256::digest(data).into()
+}
+
+/// BLAKE3 (default 32-byte output) of `data`.
+pub fn blake3(data: &[u8]) -> [u8; DIGEST_LEN] {
+    *::blake3::hash(data).as_bytes()
+}
+
+/// Keccak-256 of `data`.
+pub fn keccak256(data: &[u8]) -> [u8; DIGEST_LEN] {
+    Keccak256::digest(data).into()
+}
+
+/// Digest of `data` with `algo`.
+pub fn digest(algo: HashAlgo, data: &[u8]) -> [u8; DIGEST_LEN] {
+    match algo {
+        HashAlgo::Sha256 => sha256(data),
+        HashAlgo::Blake3 => blake3(data),
+        HashAlgo::Keccak256 => keccak256(data),
+    }
+}
@@ -2,22 +2,29 @@ use ed25519_dalek::SigningKey;
 use std::convert::TryInto;
 
 use crate::constants::crypto::SEED_LEN;
+use crate::crypto::secret::Zeroizing;
 use crate::errors::SignError;
 use crate::models::keypair_json::KeypairJson;
 
 /// Build SigningKey from decoded bytes: accept 32-byte seed or 64-byte keypair bytes.
+///
+/// With the `zeroize` feature the input buffer and intermediate seed copies are wiped
+/// before returning.
 pub fn signing_key_from_decoded(bytes: Vec<u8>) -> Result<SigningKey, SignError> {
+    let bytes = Zeroizing::new(bytes);
     match bytes.len() {
         64 => {
-            let mut seed = [0u8; SEED_LEN];
+            let mut seed = Zeroizing::new([0u8; SEED_LEN]);
             seed.copy_from_slice(&bytes[..32]);
             Ok(SigningKey::from_bytes(&seed))
         }
         32 => {
-            let arr: [u8; SEED_LEN] = bytes
-                .as_slice()
-                .try_into()
-                .map_err(|_| SignError::InvalidKeyLength)?;
+            let arr: Zeroizing<[u8; SEED_LEN]> = Zeroizing::new(
+                bytes
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| SignError::InvalidKeyLength)?,
+            );
             Ok(SigningKey::from_bytes(&arr))
         }
         _ => Err(SignError::InvalidKeyLength),
@@ -27,35 +34,20 @@ pub fn signing_key_from_decoded(bytes: Vec<u8>) -> Result<SigningKey, SignError>
 /// Parse signing key from content string (no I/O).
 /// Supported formats:
 /// 1) JSON array of bytes (32 or 64)
-/// 2) Keypair JSON with Base58 secretKey
+/// 2) Keypair JSON object: Base58 `secretKey` or a `keypair` byte array; a `publicKey`,
+///    when present, must match the key, and other fields are ignored
 /// 3) Raw Base58 string
 pub fn parse_signing_key_content(content: &str) -> Result<SigningKey, SignError> {
     let text = content.trim();
 
-    // 1) JSON array of bytes
+    // 1) JSON array of bytes (wiped once the key is built)
     if let Ok(arr) = serde_json::from_str::<Vec<u8>>(text) {
-        return match arr.len() {
-            64 => {
-                let mut seed = [0u8; SEED_LEN];
-                seed.copy_from_slice(&arr[..SEED_LEN]);
-                Ok(SigningKey::from_bytes(&seed))
-            }
-            32 => {
-                let mut seed = [0u8; SEED_LEN];
-                seed.copy_from_slice(&arr[..SEED_LEN]);
-                Ok(SigningKey::from_bytes(&seed))
-            }
-            _ => Err(SignError::InvalidKeyLength),
-        };
+        return signing_key_from_decoded(arr);
     }
 
     // 2) Keypair JSON
     if let Ok(kp_json) = serde_json::from_str::<KeypairJson>(text) {
-        let sec = kp_json.secret_key.trim();
-        let bytes = bs58::decode(sec)
-            .into_vec()
-            .map_err(|_| SignError::InvalidBase58)?;
-        return signing_key_from_decoded(bytes);
+        return signing_key_from_keypair_json(kp_json);
     }
 
     // 3) Raw Base58
@@ -64,3 +56,27 @@ pub fn parse_signing_key_content(content: &str) -> Result<SigningKey, SignError>
         .map_err(|_| SignError::InvalidBase58)?;
     signing_key_from_decoded(decoded)
 }
+
+fn signing_key_from_keypair_json(kp_json: KeypairJson) -> Result<SigningKey, SignError> {
+    let key = match (kp_json.secret_key, kp_json.keypair) {
+        (Some(_), Some(_)) => {
+            return Err(SignError::InvalidKeypairJson("both secretKey and keypair are set"));
+        }
+        (None, None) => return Err(SignError::InvalidKeypairJson("missing secretKey or keypair")),
+        (Some(sec), None) => {
+            let bytes = bs58::decode(sec.trim())
+                .into_vec()
+                .map_err(|_| SignError::InvalidBase58)?;
+            signing_key_from_decoded(bytes)?
+        }
+        (None, Some(bytes)) => signing_key_from_decoded(bytes)?,
+    };
+
+    if let Some(public_key) = kp_json.public_key {
+        let derived = bs58::encode(key.verifying_key().as_bytes()).into_string();
+        if public_key.trim() != derived {
+            return Err(SignError::InvalidKeypairJson("publicKey does not match the secret key"));
+        }
+    }
+    Ok(key)
+}
@@ -1,7 +1,9 @@
 pub mod bip39;
 pub mod derivation;
 pub mod ed25519;
+pub mod hash;
 pub mod helpers;
+pub(crate) mod secret;
 
 /// Mnemonic types and operations (BIP-39).
 pub mod mnemonic {
@@ -15,7 +17,7 @@ pub mod mnemonic {
 /// Signing types and operations (Ed25519).
 pub mod signing {
     pub use crate::crypto::ed25519::{
-        keypair_from_seed, sign_message, verify_signature, verify_signature_raw,
+        keypair_from_seed, sign_message, sign_raw_bytes, verify_signature, verify_signature_raw,
     };
     pub use crate::constants::crypto::{PUBKEY_LEN, SIG_LEN};
     pub use ed25519_dalek::{Signature, SigningKey, VerifyingKey};
@@ -23,5 +25,7 @@ pub mod signing {
 
 /// Derivation types and operations for Solana-compatible key material.
 pub mod derive {
-    pub use crate::crypto::derivation::{derive_key_from_seed, DerivationPath, SOLANA_DERIVATION_PATH};
+    pub use crate::crypto::derivation::{
+        derive_key_from_seed, master_fingerprint, DerivationPath, SOLANA_DERIVATION_PATH,
+    };
 }
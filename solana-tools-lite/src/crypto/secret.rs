@@ -0,0 +1,33 @@
+//! Wrapper for secret buffers that are wiped on drop when the `zeroize` feature is enabled.
+//!
+//! Without the feature `Zeroizing` is a plain holder with the same API, so call sites
+//! do not need their own `cfg`.
+
+#[cfg(feature = "zeroize")]
+pub(crate) use zeroize::Zeroizing;
+
+#[cfg(not(feature = "zeroize"))]
+pub(crate) struct Zeroizing<T>(T);
+
+#[cfg(not(feature = "zeroize"))]
+impl<T> Zeroizing<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(not(feature = "zeroize"))]
+impl<T> std::ops::Deref for Zeroizing<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+#[cfg(not(feature = "zeroize"))]
+impl<T> std::ops::DerefMut for Zeroizing<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
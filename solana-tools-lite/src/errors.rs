@@ -34,6 +34,7 @@ impl ExitCode {
 /// - `Deserialize` – internal raw transaction deserialization (binary → domain)
 /// - `Bip39`, `Bincode`, `Base58` – wrapped library errors
 /// - `InvalidInput` – CLI-level validation (mutually exclusive args, stdin forbidden, etc.)
+/// - `AmountOverflow` – a lamport total does not fit in `u64` (names the summary field)
 #[derive(Debug, Error)]
 pub enum ToolError {
     #[error("bip39: {0}")]
@@ -69,10 +70,43 @@ pub enum ToolError {
     #[error("{0}")]
     InvalidInput(String),
 
+    #[error("amount_overflow: {field} exceeds u64 lamports")]
+    AmountOverflow { field: &'static str },
+
     #[error("configuration: {0}")]
     ConfigurationError(String),
 }
 
+impl ToolError {
+    /// One-line diagnostic for users: the error, any underlying causes not already part of
+    /// its message (joined by `: `), and the file path of I/O errors as a trailing
+    /// `(path: ...)`; `stdio` stands for stdin/stdout.
+    pub fn display_chain(&self) -> String {
+        let (mut message, mut cause, path) = match self {
+            ToolError::Io(IoError::IoWithPath { source, path }) => (
+                format!("io: {}", source),
+                std::error::Error::source(source),
+                Some(path.as_deref().unwrap_or("stdio")),
+            ),
+            _ => (self.to_string(), std::error::Error::source(self), None),
+        };
+
+        while let Some(err) = cause {
+            let text = err.to_string();
+            if !message.contains(&text) {
+                message.push_str(": ");
+                message.push_str(&text);
+            }
+            cause = err.source();
+        }
+
+        if let Some(path) = path {
+            message.push_str(&format!(" (path: {})", path));
+        }
+        message
+    }
+}
+
 /// Errors that can arise when working with BIP‑39 helpers.
 #[derive(Error, Debug)]
 pub enum Bip39Error {
@@ -91,6 +125,8 @@ pub enum SignError {
     InvalidPubkeyFormat,
     #[error("InvalidKeyLength")]
     InvalidKeyLength,
+    #[error("InvalidKeypairJson({0})")]
+    InvalidKeypairJson(&'static str),
     #[error("SigningFailed({0})")]
     SigningFailed(String),
 
@@ -100,6 +136,18 @@ pub enum SignError {
     #[error("SigningNotRequired")]
     SigningNotRequiredForKey,
 
+    #[error("FeeLimitExceeded({fee_lamports} > {max_lamports})")]
+    FeeLimitExceeded { fee_lamports: u128, max_lamports: u64 },
+
+    #[error("FeePayerMismatch(required={required})")]
+    FeePayerMismatch { required: bool },
+
+    #[error("UnknownProgramDenied({program_id})")]
+    UnknownProgramDenied { program_id: String },
+
+    #[error("Rejected")]
+    Rejected,
+
     #[error("JsonParse({0})")]
     JsonParse(#[source] serde_json::Error),
 }
@@ -158,6 +206,8 @@ pub enum TransactionParseError {
     InvalidPubkeyLength(usize),
     #[error("SigFormat({0})")]
     InvalidSignatureFormat(String),
+    #[error("SigCount({found} > {required})")]
+    TooManySignatures { found: usize, required: usize },
 
     #[error("BlockhashLen({0})")]
     InvalidBlockhashLength(usize),
@@ -174,6 +224,13 @@ pub enum TransactionParseError {
 pub enum DeserializeError {
     #[error("Deserialize({0})")]
     Deserialization(String),
+
+    /// The version prefix byte contradicts the message kind the caller decodes as.
+    #[error("version_mismatch: expected {expected} message, found {found}")]
+    VersionMismatch {
+        expected: &'static str,
+        found: &'static str,
+    },
 }
 
 /// Adapter-level I/O errors (files, stdin/stdout) with optional path context.
@@ -246,6 +303,7 @@ impl AsExitCode for ToolError {
             | ToolError::Keypair(_)
             | ToolError::Gen(_)
             | ToolError::Verify(_)
+            | ToolError::AmountOverflow { .. }
             | ToolError::Deserialize(_)
             | ToolError::TransactionParse(_) => ExitCode::DataErr.as_i32(),
         }
@@ -1,4 +1,5 @@
 use serde::Serialize;
+use std::collections::HashMap;
 use std::sync::Arc;
 use super::super::traits::ExtensionAction;
 
@@ -15,9 +16,20 @@ pub enum PrivacyImpact {
     Confidential,
 }
 
+/// Privacy impact overrides keyed by action name (see `AnalysisAction::action_name`).
+pub type PrivacyPolicy = HashMap<String, PrivacyImpact>;
+
 /// Analysis-specific action trait that extends `ExtensionAction` with privacy classification.
 pub trait AnalysisAction: ExtensionAction {
-    /// Get the privacy impact classification of this action.
+    /// Stable, protocol-scoped action name (e.g. "BatchCompress"); the privacy policy key.
+    ///
+    /// Defaults to `protocol_name`, so one policy entry covers every action of a protocol
+    /// that does not name its actions.
+    fn action_name(&self) -> &'static str {
+        self.protocol_name()
+    }
+
+    /// Get the default privacy impact classification of this action.
     fn privacy_impact(&self) -> PrivacyImpact;
 } 
 
@@ -26,11 +38,21 @@ pub trait AnalysisAction: ExtensionAction {
 /// Wraps any action implementing `AnalysisAction` (which includes privacy impact classification).
 /// Used in `TxAnalysis` to store heterogeneous protocol-specific actions.
 #[derive(Clone)]
-pub struct AnalysisExtensionAction(Arc<dyn AnalysisAction>);
+pub struct AnalysisExtensionAction(Arc<dyn AnalysisAction>, Option<usize>);
 
 impl AnalysisExtensionAction {
     pub fn new(action: Arc<dyn AnalysisAction>) -> Self {
-        Self(action)
+        Self(action, None)
+    }
+
+    /// Action decoded from the top-level instruction at `instruction_index`.
+    pub fn at_instruction(instruction_index: usize, action: Arc<dyn AnalysisAction>) -> Self {
+        Self(action, Some(instruction_index))
+    }
+
+    /// Index of the instruction this action was decoded from, when the analyzer recorded it.
+    pub fn instruction_index(&self) -> Option<usize> {
+        self.1
     }
     
     pub fn protocol_name(&self) -> &'static str {
@@ -41,8 +63,13 @@ impl AnalysisExtensionAction {
         self.0.description()
     }
     
+    pub fn action_name(&self) -> &'static str {
+        self.0.action_name()
+    }
+
+    /// Privacy impact after applying the registered policy (see `registry::set_privacy_policy`).
     pub fn privacy_impact(&self) -> PrivacyImpact {
-        self.0.privacy_impact()
+        crate::extensions::registry::effective_privacy_impact(self.0.as_ref())
     }
 }
 
@@ -1,3 +1,3 @@
 pub mod actions;
 
-pub use actions::{PrivacyImpact, AnalysisAction, AnalysisExtensionAction};
+pub use actions::{PrivacyImpact, PrivacyPolicy, AnalysisAction, AnalysisExtensionAction};
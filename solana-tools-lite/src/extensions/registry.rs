@@ -1,7 +1,57 @@
+use crate::extensions::analysis::{AnalysisAction, PrivacyImpact, PrivacyPolicy};
 use crate::extensions::traits::ProtocolAnalyzer;
-use std::sync::{Arc, OnceLock};
+use crate::models::analysis::TransferView;
+use crate::models::pubkey_base58::PubkeyBase58;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
 
-static ANALYZERS: OnceLock<Vec<Arc<dyn ProtocolAnalyzer>>> = OnceLock::new();
+/// Decodes SOL transfers out of a custom program's instruction data.
+///
+/// Receives the program id and the raw instruction data; `from_is_signer` is recomputed
+/// by the analysis, so decoders may leave it `false`.
+pub type TransferDecoder = fn(&PubkeyBase58, &[u8]) -> Vec<TransferView>;
+
+/// An explicit set of protocol analyzers, used instead of the process-wide registry.
+///
+/// Pass it to `analysis::analyze_transaction_with` (`AnalysisConfig::analyzers`) to run independent configurations side
+/// by side (tests, services hosting several policies). The global registry is itself an
+/// `AnalyzerSet` (see `global_analyzers`).
+#[derive(Clone, Default)]
+pub struct AnalyzerSet {
+    analyzers: Vec<Arc<dyn ProtocolAnalyzer>>,
+}
+
+impl AnalyzerSet {
+    pub fn new(analyzers: Vec<Arc<dyn ProtocolAnalyzer>>) -> Self {
+        Self { analyzers }
+    }
+
+    /// Append an analyzer; analyzers run in insertion order.
+    pub fn push(&mut self, analyzer: Arc<dyn ProtocolAnalyzer>) {
+        self.analyzers.push(analyzer);
+    }
+
+    pub fn analyzers(&self) -> &[Arc<dyn ProtocolAnalyzer>] {
+        &self.analyzers
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.analyzers.is_empty()
+    }
+}
+
+static ANALYZERS: OnceLock<AnalyzerSet> = OnceLock::new();
+
+static NO_ANALYZERS: AnalyzerSet = AnalyzerSet { analyzers: Vec::new() };
+
+static PRIVACY_POLICY: RwLock<Option<PrivacyPolicy>> = RwLock::new(None);
+
+static TRANSFER_DECODERS: RwLock<Option<HashMap<PubkeyBase58, TransferDecoder>>> = RwLock::new(None);
+static ANCHOR_PROGRAMS: RwLock<Option<HashMap<PubkeyBase58, Vec<AnchorInstruction>>>> = RwLock::new(None);
+
+/// Discriminator and IDL name of one registered Anchor instruction.
+type AnchorInstruction = ([u8; 8], String);
 
 /// Register protocol analyzers (call once at startup).
 /// 
@@ -17,7 +67,19 @@ static ANALYZERS: OnceLock<Vec<Arc<dyn ProtocolAnalyzer>>> = OnceLock::new();
 /// solana_tools_lite::extensions::registry::register(analyzers);
 /// ```
 pub fn register(analyzers: Vec<Arc<dyn ProtocolAnalyzer>>) {
-    let _ = ANALYZERS.set(analyzers); // Ignore error if already set
+    try_register(analyzers);
+}
+
+/// Register protocol analyzers, reporting whether this call installed them.
+///
+/// Returns `false` (and drops `analyzers`) when an earlier call already registered a set.
+pub fn try_register(analyzers: Vec<Arc<dyn ProtocolAnalyzer>>) -> bool {
+    ANALYZERS.set(AnalyzerSet::new(analyzers)).is_ok()
+}
+
+/// The registered analyzers as a set (empty until `register` is called).
+pub fn global_analyzers() -> &'static AnalyzerSet {
+    ANALYZERS.get().unwrap_or(&NO_ANALYZERS)
 }
 
 /// Returns a list of all registered protocol analyzers.
@@ -25,7 +87,103 @@ pub fn register(analyzers: Vec<Arc<dyn ProtocolAnalyzer>>) {
 /// Returns an empty slice if no analyzers have been registered yet.
 /// The returned reference is to a static slice, avoiding allocations.
 pub fn get_all_analyzers() -> &'static [Arc<dyn ProtocolAnalyzer>] {
-    ANALYZERS.get()
-        .map(|v| v.as_slice())
-        .unwrap_or(&[])
+    global_analyzers().analyzers()
+}
+
+/// Install (or clear with `None`) privacy impact overrides for extension actions.
+///
+/// Keys are action names as returned by `AnalysisAction::action_name`; actions not in
+/// the map keep their default classification. Applies to every analysis run after the
+/// call, process-wide.
+///
+/// # Example
+/// ```ignore
+/// use solana_tools_lite::extensions::analysis::{PrivacyImpact, PrivacyPolicy};
+///
+/// let mut policy = PrivacyPolicy::new();
+/// policy.insert("BatchCompress".to_string(), PrivacyImpact::Hybrid);
+/// solana_tools_lite::extensions::registry::set_privacy_policy(Some(policy));
+/// ```
+pub fn set_privacy_policy(policy: Option<PrivacyPolicy>) {
+    let mut guard = PRIVACY_POLICY.write().unwrap_or_else(|e| e.into_inner());
+    *guard = policy;
+}
+
+/// Privacy impact of `action`: the policy override if one is registered, else its default.
+pub fn effective_privacy_impact(action: &dyn AnalysisAction) -> PrivacyImpact {
+    let guard = PRIVACY_POLICY.read().unwrap_or_else(|e| e.into_inner());
+    guard
+        .as_ref()
+        .and_then(|policy| policy.get(action.action_name()).copied())
+        .unwrap_or_else(|| action.privacy_impact())
+}
+
+/// Register a transfer decoder for `program_id` (e.g. a router batching System transfers
+/// in one instruction). Replaces any decoder previously registered for the same program.
+///
+/// During analysis, instructions of `program_id` are passed to the decoder and the returned
+/// transfers are counted like System transfers; the program is no longer reported as unknown.
+///
+/// # Example
+/// ```ignore
+/// use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+///
+/// fn decode_router(_program_id: &PubkeyBase58, data: &[u8]) -> Vec<TransferView> {
+///     /* parse `data` into transfers */
+///     Vec::new()
+/// }
+///
+/// solana_tools_lite::extensions::registry::register_transfer_decoder(router_id, decode_router);
+/// ```
+pub fn register_transfer_decoder(program_id: PubkeyBase58, decoder: TransferDecoder) {
+    let mut guard = TRANSFER_DECODERS.write().unwrap_or_else(|e| e.into_inner());
+    guard.get_or_insert_with(HashMap::new).insert(program_id, decoder);
+}
+
+/// Transfer decoder registered for `program_id`, if any.
+pub fn transfer_decoder(program_id: &PubkeyBase58) -> Option<TransferDecoder> {
+    let guard = TRANSFER_DECODERS.read().unwrap_or_else(|e| e.into_inner());
+    guard.as_ref().and_then(|decoders| decoders.get(program_id).copied())
+}
+
+/// Anchor instruction discriminator: the first 8 bytes of `sha256("global:<ix_name>")`.
+pub fn anchor_discriminator(ix_name: &str) -> [u8; 8] {
+    let digest = Sha256::digest(format!("global:{ix_name}").as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&digest[..8]);
+    discriminator
+}
+
+/// Register an Anchor program by the instruction names from its IDL.
+///
+/// Discriminators are precomputed here; during analysis, instructions of `program_id` whose
+/// data starts with one of them are reported as an `AnchorAction` instead of an unknown
+/// program. Registering the same program again replaces its instruction names.
+///
+/// # Example
+/// ```ignore
+/// solana_tools_lite::extensions::registry::register_anchor_program(
+///     program_id,
+///     &["initialize", "deposit"],
+/// );
+/// ```
+pub fn register_anchor_program(program_id: PubkeyBase58, ix_names: &[&str]) {
+    let entries = ix_names
+        .iter()
+        .map(|name| (anchor_discriminator(name), name.to_string()))
+        .collect();
+    let mut guard = ANCHOR_PROGRAMS.write().unwrap_or_else(|e| e.into_inner());
+    guard.get_or_insert_with(HashMap::new).insert(program_id, entries);
+}
+
+/// Instruction name for `data` of a registered Anchor program, matched by discriminator.
+pub fn anchor_instruction_name(program_id: &PubkeyBase58, data: &[u8]) -> Option<String> {
+    let discriminator = data.get(..8)?;
+    let guard = ANCHOR_PROGRAMS.read().unwrap_or_else(|e| e.into_inner());
+    guard
+        .as_ref()?
+        .get(program_id)?
+        .iter()
+        .find(|(known, _)| known.as_slice() == discriminator)
+        .map(|(_, name)| name.clone())
 }
@@ -52,11 +52,9 @@ pub trait ProtocolAnalyzer: Send + Sync {
         };
         
         message.instructions().iter().any(|instr| {
-            if let Some(pk) = message.account_keys().get(instr.program_id_index as usize) {
-                supported.contains(pk)
-            } else {
-                false
-            }
+            instr
+                .program_id(message.account_keys())
+                .is_some_and(|pk| supported.contains(pk))
         })
     }
 
@@ -1,23 +1,36 @@
 use crate::Result;
 use crate::ToolError;
 use crate::codec::{
-    ComputeBudgetAction, decode_compute_budget, decode_system_transfer_amount,
-    serialize_transaction,
+    ComputeBudgetAction, TokenDelegateAction, TokenSetupInstruction, decode_compute_budget, decode_system_create_account, decode_system_transfer_amount,
+    decode_associated_token_create, decode_stake_withdraw, decode_system_withdraw_nonce_amount,
+    decode_token_close_account_destination, decode_token_sync_native_account,
+    decode_token_delegate, decode_token_set_authority, decode_token_setup, serialize_message, serialize_transaction, write_shortvec_len,
 };
-use crate::constants::{compute_budget, programs};
+use crate::codec::instruction_decoder::SYSTEM_TRANSFER_DATA_LEN;
+use crate::constants::crypto::SIG_LEN;
+use crate::constants::{compute_budget, mints, packet, programs};
 use crate::models::analysis::{
-    AnalysisWarning, PrivacyLevel, SigningSummary, TokenProgramKind, TransferView, TxAnalysis,
+    AnalysisWarning, AnchorAction, CreatedAccount, PrecompileKind, PrivacyLevel, SigningSummary, TokenAuthorityType,
+    TokenProgramKind, TokenSetupAction, TransactionShape, TransferView, TxAnalysis,
+    UnknownInstruction, WsolUnwrap,
 };
 use crate::extensions::analysis::{AnalysisExtensionAction, PrivacyImpact};
 use crate::models::input_transaction::InputTransaction;
 use crate::models::instruction::Instruction;
+use crate::models::lamports::Lamports;
 use crate::models::message::{Message, MessageAddressTableLookup};
+use crate::models::rent::RentConfig;
 use crate::models::pubkey_base58::PubkeyBase58;
 use crate::models::transaction::Transaction;
+use crate::utils::format_micro_lamports;
 use crate::serde::LookupTableEntry;
+use ed25519_dalek::VerifyingKey;
 use std::borrow::Cow;
-use std::collections::HashSet;
-use crate::extensions::registry;
+use std::collections::{BTreeMap, HashMap, HashSet};
+#[cfg(feature = "timing")]
+use std::time::Duration;
+use std::time::Instant;
+use crate::extensions::registry::{self, AnalyzerSet};
 
 // --- Constants ---
 const ESTIMATED_BASE_FEE_PER_SIGNATURE: u64 = 5000;
@@ -26,14 +39,96 @@ const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
 // Limits for Anti-DoS
 const MAX_TRANSFERS_TO_DISPLAY: usize = 50;
 
+// Single signer transfers up to this amount take the fast path (0.1 SOL)
+const FAST_PATH_MAX_LAMPORTS: u64 = 100_000_000;
+
+// Identical instructions repeated more than this many times are flagged (possible drainer loop)
+const REPEATED_INSTRUCTION_THRESHOLD: usize = 3;
+
+// Raw data of at most this many unknown instructions is kept in the analysis; trusted
+// (allowlisted) programs keep none, so they never use up one of these slots
+const MAX_UNKNOWN_INSTRUCTIONS: usize = 16;
+
+/// Settings of an analysis run that do not come from the transaction.
+#[derive(Clone, Copy)]
+pub struct AnalysisConfig<'a> {
+    /// Protocol analyzers to run.
+    pub analyzers: &'a AnalyzerSet,
+    /// Rent parameters for the rent-exempt minimum of created accounts.
+    pub rent: RentConfig,
+    /// How many bytes of one instruction's data decoders (core and analyzers) scan.
+    pub max_scanned_data_len: usize,
+}
+
+impl Default for AnalysisConfig<'_> {
+    /// The process-wide analyzers (`registry::global_analyzers`), Solana's rent defaults
+    /// and `DEFAULT_MAX_SCANNED_DATA_LEN`.
+    fn default() -> Self {
+        Self {
+            analyzers: registry::global_analyzers(),
+            rent: RentConfig::DEFAULT,
+            max_scanned_data_len: DEFAULT_MAX_SCANNED_DATA_LEN,
+        }
+    }
+}
+
+/// Default number of instruction data bytes decoders scan (16 KB, well above the packet size).
+pub const DEFAULT_MAX_SCANNED_DATA_LEN: usize = 16 * 1024;
+
+/// The part of `data` a decoder may scan: at most `max_len` leading bytes.
+fn scan_window(data: &[u8], max_len: usize) -> &[u8] {
+    &data[..data.len().min(max_len)]
+}
+
+/// `message` with every instruction's data cut to the scan window, for protocol analyzers;
+/// borrowed unchanged when no instruction exceeds it.
+fn windowed_message(message: &Message, max_len: usize) -> Cow<'_, Message> {
+    if message.instructions().iter().all(|instr| instr.data.len() <= max_len) {
+        return Cow::Borrowed(message);
+    }
+    let mut windowed = message.clone();
+    let instructions = match &mut windowed {
+        Message::Legacy(m) => &mut m.instructions,
+        Message::V0(m) => &mut m.instructions,
+    };
+    for instr in instructions {
+        instr.data.truncate(max_len);
+    }
+    Cow::Owned(windowed)
+}
+
+// Programs whose instructions normally take no accounts (no `InstructionNoAccounts` warning);
+// a memo lists accounts only when it wants their signatures
+const ACCOUNTLESS_PROGRAMS: &[&str] = &[
+    programs::COMPUTE_BUDGET_ID,
+    programs::ED25519_PROGRAM_ID,
+    programs::SECP256K1_PROGRAM_ID,
+    programs::MEMO_V1_PROGRAM_ID,
+    programs::MEMO_PROGRAM_ID,
+];
+
 /// Internal state used to collect metrics and flags during transaction analysis.
 #[derive(Default)]
 struct AnalysisState {
     transfers: Vec<TransferView>,
     total_sol_send_by_signer: u128,
+    signer_outflows_by_recipient: BTreeMap<String, u64>,
     detected_programs: HashSet<TokenProgramKind>,
+    precompiles: HashSet<PrecompileKind>,
+    multisig_programs: Vec<PubkeyBase58>,
     saw_system_transfer: bool,
     unknown_programs: HashSet<PubkeyBase58>,
+    unknown_instructions: Vec<UnknownInstruction>,
+    token_setup: Vec<TokenSetupAction>,
+    anchor_actions: Vec<AnchorAction>,
+    created_accounts: Vec<CreatedAccount>,
+    /// Token accounts known to hold wrapped SOL (native mint) from earlier instructions.
+    wsol_accounts: HashSet<PubkeyBase58>,
+    wsol_unwraps: Vec<WsolUnwrap>,
+    /// Owner given to the signer's own account by a System `CreateAccount`.
+    signer_created_with_owner: Option<PubkeyBase58>,
+    /// A transfer out of the signer followed that `CreateAccount`.
+    signer_debited_after_create: bool,
     cu_price_micro: Option<u64>,
     cu_limit: Option<u32>,
     extension_actions: Vec<AnalysisExtensionAction>,
@@ -43,33 +138,152 @@ struct AnalysisState {
     is_fee_payer: bool,
 }
 
+/// Whether the account at `account_index` of the full (lookup-resolved) account list is writable.
+///
+/// Static keys follow the header regions (see `Message::is_static_writable`). In a v0
+/// message the lookup-resolved accounts come next: the writable indexes of every lookup,
+/// then the readonly ones, in lookup order. Analysis resolves its account list in the same
+/// layout (one address per index), so positions in that list line up with this split.
+/// Legacy messages have no lookup accounts, and out-of-range indexes are never writable.
+pub fn is_account_writable(message: &Message, account_index: usize) -> bool {
+    let static_len = message.account_keys().len();
+    if account_index < static_len {
+        return message.is_static_writable(account_index);
+    }
+    match message {
+        Message::Legacy(_) => false,
+        Message::V0(v0) => {
+            let lookup_writable: usize = v0
+                .address_table_lookups
+                .iter()
+                .map(|lookup| lookup.writable_indexes.len())
+                .sum();
+            account_index - static_len < lookup_writable
+        }
+    }
+}
+
 /// Analyze an input transaction (raw, unsigned) to produce fee estimates, transfers, and warnings.
 /// This function handles the conversion from InputTransaction to Message internally.
 pub fn analyze_input_transaction(
     input_tx: &InputTransaction,
     signer: &PubkeyBase58,
     tables: Option<&LookupTableEntry>,
+    allowlist: Option<&HashSet<PubkeyBase58>>,
 ) -> Result<TxAnalysis> {
     let tx: Transaction = Transaction::try_from(input_tx)?;
     tx.message.sanitize()?;
 
-    Ok(analyze_transaction(&tx.message, signer, tables))
+    Ok(analyze_transaction(&tx.message, signer, tables, allowlist, None))
 }
 
 /// Analyze a message to produce fee estimates, transfers, and warnings.
+///
+/// A single small System transfer from the signer is recognized up front and analyzed
+/// without running protocol extensions (see `analyze_fast_path`).
+///
+/// Programs in `allowlist` are trusted by the caller: they never produce an
+/// `UnknownProgram` warning, although they are still tracked as undecoded.
+///
+/// `deadline` bounds the work spent on untrusted input. It is checked between
+/// instructions and before each protocol extension (cooperative, not preemptive);
+/// once passed, the partial result carries `AnalysisTruncatedByDeadline`.
 pub fn analyze_transaction(
     message: &Message,
     signer: &PubkeyBase58,
     tables: Option<&LookupTableEntry>,
+    allowlist: Option<&HashSet<PubkeyBase58>>,
+    deadline: Option<Instant>,
+) -> TxAnalysis {
+    analyze_transaction_with(message, signer, tables, allowlist, deadline, &AnalysisConfig::default())
+}
+
+/// Same as `analyze_transaction`, with explicit settings: e.g. analyzers other than the
+/// ones in the process-wide registry, or other rent parameters.
+pub fn analyze_transaction_with(
+    message: &Message,
+    signer: &PubkeyBase58,
+    tables: Option<&LookupTableEntry>,
+    allowlist: Option<&HashSet<PubkeyBase58>>,
+    deadline: Option<Instant>,
+    config: &AnalysisConfig,
+) -> TxAnalysis {
+    // Declared tables must still be checked against the lookups (`UnusedLookupTable`),
+    // and a passed deadline must still be reported (`AnalysisTruncatedByDeadline`)
+    let declares_tables = tables.is_some_and(|t| !t.tables.is_empty());
+    if !declares_tables
+        && !deadline_passed(deadline)
+        && let Some(analysis) = analyze_fast_path(message, signer)
+    {
+        return analysis;
+    }
+    let mut timings = Timings::default();
+    analyze_full_with_timings(message, signer, tables, allowlist, deadline, config, &mut timings)
+}
+
+/// Analyze a message through the full pipeline (all checks and protocol extensions).
+pub fn analyze_transaction_full(
+    message: &Message,
+    signer: &PubkeyBase58,
+    tables: Option<&LookupTableEntry>,
+    allowlist: Option<&HashSet<PubkeyBase58>>,
+    deadline: Option<Instant>,
+) -> TxAnalysis {
+    analyze_full_with_timings(
+        message,
+        signer,
+        tables,
+        allowlist,
+        deadline,
+        &AnalysisConfig::default(),
+        &mut Timings::default(),
+    )
+}
+
+/// Run the full pipeline and return how long the core instruction loop and each
+/// analyzer hook (`<name>::analyze`, `<name>::enrich_notice`) took.
+#[cfg(feature = "timing")]
+pub fn analyze_transaction_timed(
+    message: &Message,
+    signer: &PubkeyBase58,
+    tables: Option<&LookupTableEntry>,
+    allowlist: Option<&HashSet<PubkeyBase58>>,
+) -> (TxAnalysis, Vec<(String, Duration)>) {
+    let mut timings = Timings::default();
+    let analysis = analyze_full_with_timings(
+        message,
+        signer,
+        tables,
+        allowlist,
+        None,
+        &AnalysisConfig::default(),
+        &mut timings,
+    );
+    (analysis, timings.entries)
+}
+
+fn analyze_full_with_timings(
+    message: &Message,
+    signer: &PubkeyBase58,
+    tables: Option<&LookupTableEntry>,
+    allowlist: Option<&HashSet<PubkeyBase58>>,
+    deadline: Option<Instant>,
+    config: &AnalysisConfig,
+    timings: &mut Timings,
 ) -> TxAnalysis {
     let mut warnings = Vec::new();
+    let mut truncated = false;
 
     // 1. Resolve message components
-    let (account_list, instructions, message_version, _) =
+    let (account_list, static_len, instructions, message_version, _) =
         resolve_message_components(message, tables, &mut warnings);
 
+    check_duplicate_accounts(&account_list, &mut warnings);
+
     // [Security Check] Verify if the provided signer is actually required to sign
-    verify_signer_requirement(message, &account_list, signer, &mut warnings);
+    verify_signer_requirement(message, &account_list, static_len, signer, &mut warnings);
+    check_signer_keys_on_curve(message, &mut warnings);
+    check_account_ordering(message, &mut warnings);
 
     // [Fee Payer Check] The first account in any Solana message is the fee payer.
     let is_fee_payer = account_list.first().map(|pk| pk == signer).unwrap_or(false);
@@ -80,27 +294,49 @@ pub fn analyze_transaction(
         ..Default::default()
     };
 
-    for instr in instructions {
-        let program_id = match account_list.get(instr.program_id_index as usize) {
+    let core = timings.start();
+    for (instruction_index, instr) in instructions.iter().enumerate() {
+        if deadline_passed(deadline) {
+            truncated = true;
+            break;
+        }
+        let program_id = match instr.program_id(&account_list) {
             Some(pk) => pk,
             None => continue,
         };
 
         let program_id_str = program_id.to_string();
 
+        let data = scan_window(&instr.data, config.max_scanned_data_len);
+        if data.len() < instr.data.len() {
+            warnings.push(AnalysisWarning::InstructionDataTruncatedForAnalysis {
+                instruction_index,
+                data_len: instr.data.len(),
+                scanned_len: data.len(),
+            });
+        }
+
+        if instr.accounts.is_empty() && !ACCOUNTLESS_PROGRAMS.contains(&program_id_str.as_str()) {
+            warnings.push(AnalysisWarning::InstructionNoAccounts { instruction_index });
+        }
+
         let handled = match program_id_str.as_str() {
             programs::SYSTEM_PROGRAM_ID => {
-                if let Some(lamports) = decode_system_transfer_amount(&instr.data) {
+                // Transfer and WithdrawNonceAccount both move lamports from account #0 to #1
+                let lamports = decode_system_transfer_amount(data)
+                    .or_else(|| decode_system_withdraw_nonce_amount(data));
+                if let Some(lamports) = lamports {
                     // Ensure we have at least 2 accounts (from, to)
                     if instr.accounts.len() >= 2 {
                         state.saw_system_transfer = true;
-                        process_transfer(&mut state, &account_list, instr, lamports, signer);
+                        process_transfer(&mut state, message, &account_list, instr, lamports, signer);
                     }
                 }
+                record_created_account(&mut state, &account_list, instr, signer, &config.rent);
                 true
             }
             programs::COMPUTE_BUDGET_ID => {
-                match decode_compute_budget(&instr.data) {
+                match decode_compute_budget(data) {
                     ComputeBudgetAction::SetLimit(l) => state.cu_limit = Some(l),
                     ComputeBudgetAction::SetPrice(p) => state.cu_price_micro = Some(p),
                     ComputeBudgetAction::None => {}
@@ -109,31 +345,105 @@ pub fn analyze_transaction(
             }
             programs::TOKEN_PROGRAM_ID => {
                 state.detected_programs.insert(TokenProgramKind::SplToken);
+                record_token_setup(&mut state, &account_list, instr);
+                track_wrapped_sol(&mut state, &account_list, instr, signer);
+                check_token_close_account(&account_list, instr, signer, &mut warnings);
+                check_token_approve(&account_list, instr, &mut warnings);
+                check_token_set_authority(instr, signer, &mut warnings);
                 true
             }
             programs::TOKEN_2022_PROGRAM_ID => {
                 state.detected_programs.insert(TokenProgramKind::Token2022);
+                record_token_setup(&mut state, &account_list, instr);
+                track_wrapped_sol(&mut state, &account_list, instr, signer);
+                check_token_close_account(&account_list, instr, signer, &mut warnings);
+                check_token_approve(&account_list, instr, &mut warnings);
+                check_token_set_authority(instr, signer, &mut warnings);
                 true
             }
             programs::ASSOCIATED_TOKEN_PROGRAM_ID => {
                 state
                     .detected_programs
                     .insert(TokenProgramKind::AssociatedToken);
+                record_wsol_associated_account(&mut state, &account_list, instr);
                 true
             }
-            _ => false,
+            programs::STAKE_PROGRAM_ID => {
+                check_stake_withdraw(&account_list, instr, signer, &mut warnings);
+                true
+            }
+            programs::ED25519_PROGRAM_ID => {
+                state.precompiles.insert(PrecompileKind::Ed25519);
+                true
+            }
+            programs::SECP256K1_PROGRAM_ID => {
+                state.precompiles.insert(PrecompileKind::Secp256k1);
+                true
+            }
+            id if programs::MULTISIG_PROGRAM_IDS.contains(&id) => {
+                if !state.multisig_programs.contains(program_id) {
+                    state.multisig_programs.push(program_id.clone());
+                }
+                true
+            }
+            _ => match registry::transfer_decoder(program_id) {
+                Some(decode) => {
+                    for transfer in decode(program_id, data) {
+                        let from_is_signer = transfer.from == signer.to_string();
+                        record_transfer(&mut state, TransferView { from_is_signer, ..transfer });
+                    }
+                    true
+                }
+                None => match registry::anchor_instruction_name(program_id, data) {
+                    Some(ix_name) => {
+                        state.anchor_actions.push(AnchorAction {
+                            program: program_id.clone(),
+                            ix_name,
+                        });
+                        true
+                    }
+                    None => false,
+                },
+            },
         };
 
         if !handled {
+            let trusted = allowlist.is_some_and(|trusted| trusted.contains(program_id));
+            if !trusted && state.unknown_instructions.len() < MAX_UNKNOWN_INSTRUCTIONS {
+                state.unknown_instructions.push(UnknownInstruction {
+                    program_id: program_id_str,
+                    data_base64: data_encoding::BASE64.encode(data),
+                });
+            }
             state.unknown_programs.insert(program_id.clone());
         }
     }
+    timings.finish(core, || "core".to_string());
+
+    if truncated {
+        warnings.push(AnalysisWarning::AnalysisTruncatedByDeadline);
+    }
+
+    // [Drainer Check] Flag identical instructions repeated many times
+    detect_repeated_instructions(instructions, &mut warnings);
 
     // 3. Finalize results
-    let mut analysis = finalize_analysis(message, state, warnings, message_version);
+    let mut analysis = finalize_analysis(message, state, warnings, message_version, allowlist);
 
-    // 4. Run protocol extensions (Plugins)
-    process_analysis_extensions(message, &account_list, signer, &mut analysis);
+    // 4. Run protocol extensions (Plugins) on data cut to the scan window, so oversized,
+    // crafted data cannot slow them down either (the core loop reported the truncation)
+    if !process_analysis_extensions(
+        &windowed_message(message, config.max_scanned_data_len),
+        &account_list,
+        signer,
+        &mut analysis,
+        config.analyzers,
+        deadline,
+        timings,
+    ) && !truncated
+    {
+        analysis.warnings.push(AnalysisWarning::AnalysisTruncatedByDeadline);
+    }
 
     // Refresh privacy level after plugins
     analysis.recalculate_privacy_level();
@@ -141,10 +451,125 @@ pub fn analyze_transaction(
     analysis
 }
 
+/// Fast path for the most common shape: exactly one System transfer of at most
+/// `FAST_PATH_MAX_LAMPORTS`, sent by a required signer, with no address table lookups
+/// and no accounts besides sender, recipient and the System program. Messages that would
+/// raise a warning (duplicate keys, data beyond the scan window, ordering, size, off-curve
+/// signers) are left to the full pipeline.
+///
+/// Returns `None` for any other shape. The result is identical to `analyze_transaction_full`
+/// for the accepted shape; protocol extensions are skipped since none apply to it.
+pub fn analyze_fast_path(message: &Message, signer: &PubkeyBase58) -> Option<TxAnalysis> {
+    let message_version = match message {
+        Message::Legacy(_) => "legacy",
+        Message::V0(v0) if v0.address_table_lookups.is_empty() => "v0",
+        Message::V0(_) => return None,
+    };
+
+    let [instr] = message.instructions().as_slice() else {
+        return None;
+    };
+    // Only sender, recipient and the System program; extra accounts may interest extensions
+    let accounts = message.account_keys();
+    if accounts.len() > 3 || instr.accounts.iter().any(|&i| i as usize >= accounts.len()) {
+        return None;
+    }
+    // Duplicate keys need a warning and longer data may need a truncation warning; leave
+    // them to the full pipeline
+    let has_duplicates = accounts.iter().enumerate().any(|(i, key)| accounts[..i].contains(key));
+    if has_duplicates || instr.data.len() != SYSTEM_TRANSFER_DATA_LEN {
+        return None;
+    }
+
+    let program_id = instr.program_id(accounts)?;
+    if program_id.to_string() != programs::SYSTEM_PROGRAM_ID {
+        return None;
+    }
+
+    let lamports = decode_system_transfer_amount(&instr.data)?;
+    if lamports > FAST_PATH_MAX_LAMPORTS || instr.accounts.len() < 2 {
+        return None;
+    }
+
+    // The sender must be the analyzed signer and a required signer of the message
+    let from_idx = instr.accounts[0] as usize;
+    let num_required_signatures = message.header().num_required_signatures as usize;
+    if from_idx >= num_required_signatures || accounts.get(from_idx) != Some(signer) {
+        return None;
+    }
+    // Oversized or mis-ordered transactions need a warning; leave them to the full pipeline
+    if account_ordering_issue(message).is_some() {
+        return None;
+    }
+    if serialized_transaction_size(message) > packet::PACKET_DATA_SIZE {
+        return None;
+    }
+    // Off-curve signer keys (fee payer included) need a warning; leave them to the full pipeline
+    if !accounts.first().is_some_and(is_on_curve)
+        || !accounts.iter().take(num_required_signatures).all(is_on_curve)
+    {
+        return None;
+    }
+
+    let mut state = AnalysisState {
+        is_fee_payer: accounts.first() == Some(signer),
+        saw_system_transfer: true,
+        ..Default::default()
+    };
+    process_transfer(&mut state, message, accounts, instr, lamports, signer);
+
+    Some(finalize_analysis(message, state, Vec::new(), message_version, None))
+}
+
+/// Per-stage durations, collected only with the `timing` feature; otherwise an
+/// empty type whose `measure` just runs the closure.
+#[derive(Default)]
+struct Timings {
+    #[cfg(feature = "timing")]
+    entries: Vec<(String, Duration)>,
+}
+
+/// Start of a measured stage (`Timings::start`); empty without the `timing` feature.
+struct Stage {
+    #[cfg(feature = "timing")]
+    started: Instant,
+}
+
+impl Timings {
+    #[inline(always)]
+    fn start(&self) -> Stage {
+        Stage {
+            #[cfg(feature = "timing")]
+            started: Instant::now(),
+        }
+    }
+
+    /// Record the time since `stage` started under `label`.
+    #[inline(always)]
+    fn finish(&mut self, stage: Stage, label: impl FnOnce() -> String) {
+        #[cfg(feature = "timing")]
+        self.entries.push((label(), stage.started.elapsed()));
+        #[cfg(not(feature = "timing"))]
+        let _ = (stage, label);
+    }
+
+    #[inline(always)]
+    fn measure<T>(&mut self, label: impl FnOnce() -> String, run: impl FnOnce() -> T) -> T {
+        let stage = self.start();
+        let out = run();
+        self.finish(stage, label);
+        out
+    }
+}
+
 /// Verify that the current user (signer) is actually listed as a required signer in the message header.
+///
+/// Accounts from `lookup_start` onward were resolved through address lookup tables; a signer
+/// found only there can never produce a valid signature.
 fn verify_signer_requirement(
     message: &Message,
     accounts: &[PubkeyBase58],
+    lookup_start: usize,
     signer: &PubkeyBase58,
     warnings: &mut Vec<AnalysisWarning>,
 ) {
@@ -158,58 +583,486 @@ fn verify_signer_requirement(
 
     if !is_required {
         warnings.push(AnalysisWarning::SignerNotRequired);
+
+        let in_static_keys = accounts.iter().take(lookup_start).any(|pk| pk == signer);
+        let in_lookups = accounts.iter().skip(lookup_start).any(|pk| pk == signer);
+        if in_lookups && !in_static_keys {
+            warnings.push(AnalysisWarning::SignerFromLookupTable);
+        }
+    } else if !signer_touches_any_instruction(message.instructions(), accounts, signer) {
+        warnings.push(AnalysisWarning::SignerNotReferencedByInstructions);
+    }
+}
+
+/// Whether any instruction lists `signer` among its accounts (resolved through `accounts`).
+fn signer_touches_any_instruction(
+    instructions: &[Instruction],
+    accounts: &[PubkeyBase58],
+    signer: &PubkeyBase58,
+) -> bool {
+    instructions.iter().any(|instr| {
+        instr
+            .accounts
+            .iter()
+            .any(|&idx| accounts.get(idx as usize) == Some(signer))
+    })
+}
+
+/// Warn about required signer keys that are not valid Ed25519 points (e.g. a PDA placed
+/// in the signer region); no private key exists for them, so they can never sign.
+///
+/// An off-curve fee payer is reported as `FeePayerNotSignable` (whether or not the header
+/// requires it to sign): the fee cannot be paid at all.
+fn check_signer_keys_on_curve(message: &Message, warnings: &mut Vec<AnalysisWarning>) {
+    let account_keys = message.account_keys();
+    if let Some(fee_payer) = account_keys.first()
+        && !is_on_curve(fee_payer)
+    {
+        warnings.push(AnalysisWarning::FeePayerNotSignable {
+            fee_payer: fee_payer.clone(),
+        });
+    }
+
+    let num_required_signatures = message.header().num_required_signatures as usize;
+    for (index, key) in account_keys
+        .iter()
+        .take(num_required_signatures)
+        .enumerate()
+        .skip(1)
+    {
+        if !is_on_curve(key) {
+            warnings.push(AnalysisWarning::SignerKeyOffCurve { index });
+        }
+    }
+}
+
+/// First static account whose position contradicts the header regions (writable signers,
+/// readonly signers, writable non-signers, readonly non-signers), if any.
+///
+/// Suspect when the fee payer is not a writable signer, the signed and readonly unsigned
+/// regions overlap, or an account invoked as a program sits in a signer or writable slot.
+fn account_ordering_issue(message: &Message) -> Option<usize> {
+    let header = message.header();
+    let keys = message.account_keys();
+    let num_signed = header.num_required_signatures as usize;
+
+    if num_signed == 0 || header.num_readonly_signed_accounts as usize >= num_signed {
+        return Some(0);
+    }
+    if num_signed + header.num_readonly_unsigned_accounts as usize > keys.len() {
+        return Some(num_signed);
+    }
+
+    message
+        .instructions()
+        .iter()
+        .map(|instr| instr.program_id_index as usize)
+        .filter(|&idx| idx < keys.len() && (idx < num_signed || is_account_writable(message, idx)))
+        .min()
+}
+
+/// Warn once per key listed more than once in the resolved account list.
+fn check_duplicate_accounts(account_list: &[PubkeyBase58], warnings: &mut Vec<AnalysisWarning>) {
+    let mut seen = HashSet::with_capacity(account_list.len());
+    let mut reported = HashSet::new();
+    for key in account_list {
+        if !seen.insert(key) && reported.insert(key) {
+            warnings.push(AnalysisWarning::DuplicateAccountKey { key: key.clone() });
+        }
+    }
+}
+
+fn check_account_ordering(message: &Message, warnings: &mut Vec<AnalysisWarning>) {
+    if let Some(index) = account_ordering_issue(message) {
+        warnings.push(AnalysisWarning::AccountOrderingSuspect { index });
+    }
+}
+
+/// Size of the wire transaction once every required signer has signed.
+fn serialized_transaction_size(message: &Message) -> usize {
+    let required = message.header().num_required_signatures as usize;
+    let mut prefix = Vec::new();
+    write_shortvec_len(required, &mut prefix);
+    prefix.len() + required * SIG_LEN + serialize_message(message).len()
+}
+
+fn deadline_passed(deadline: Option<Instant>) -> bool {
+    deadline.is_some_and(|d| Instant::now() >= d)
+}
+
+fn is_on_curve(key: &PubkeyBase58) -> bool {
+    VerifyingKey::from_bytes(key.as_bytes()).is_ok()
+}
+
+/// Warn when a Token `CloseAccount` sends the reclaimed rent to someone other than the signer.
+fn check_token_close_account(
+    accounts: &[PubkeyBase58],
+    instr: &Instruction,
+    signer: &PubkeyBase58,
+    warnings: &mut Vec<AnalysisWarning>,
+) {
+    let Some(position) = decode_token_close_account_destination(&instr.data) else {
+        return;
+    };
+    let Some(destination) = instr
+        .accounts
+        .get(position)
+        .and_then(|&idx| accounts.get(idx as usize))
+    else {
+        return;
+    };
+
+    if destination != signer {
+        warnings.push(AnalysisWarning::TokenAccountClosedToOther {
+            destination: destination.clone(),
+        });
+    }
+}
+
+/// Record a Token mint/account initialization with its resolved accounts.
+fn record_token_setup(state: &mut AnalysisState, accounts: &[PubkeyBase58], instr: &Instruction) {
+    let Some(setup) = decode_token_setup(&instr.data) else {
+        return;
+    };
+    let account_at = |position: usize| {
+        instr
+            .accounts
+            .get(position)
+            .and_then(|&idx| accounts.get(idx as usize))
+            .cloned()
+    };
+
+    let action = match setup {
+        TokenSetupInstruction::InitializeMint {
+            decimals,
+            mint_authority,
+            freeze_authority,
+        } => account_at(0).map(|mint| TokenSetupAction::InitializeMint {
+            mint,
+            decimals,
+            mint_authority,
+            freeze_authority,
+        }),
+        TokenSetupInstruction::InitializeAccount => {
+            match (account_at(0), account_at(1), account_at(2)) {
+                (Some(account), Some(mint), Some(owner)) => {
+                    Some(TokenSetupAction::InitializeAccount { account, mint, owner })
+                }
+                _ => None,
+            }
+        }
+        TokenSetupInstruction::InitializeAccount3 { owner } => match (account_at(0), account_at(1)) {
+            (Some(account), Some(mint)) => {
+                Some(TokenSetupAction::InitializeAccount { account, mint, owner })
+            }
+            _ => None,
+        },
+    };
+    if let Some(TokenSetupAction::InitializeAccount { account, mint, .. }) = &action
+        && mint.to_string() == mints::NATIVE_MINT_ID
+    {
+        state.wsol_accounts.insert(account.clone());
+    }
+    state.token_setup.extend(action);
+}
+
+/// Remember an associated token account created for the native mint.
+fn record_wsol_associated_account(
+    state: &mut AnalysisState,
+    accounts: &[PubkeyBase58],
+    instr: &Instruction,
+) {
+    let Some((account_pos, mint_pos)) = decode_associated_token_create(&instr.data) else {
+        return;
+    };
+    let account_at = |position: usize| {
+        instr
+            .accounts
+            .get(position)
+            .and_then(|&idx| accounts.get(idx as usize))
+    };
+    if let (Some(account), Some(mint)) = (account_at(account_pos), account_at(mint_pos))
+        && mint.to_string() == mints::NATIVE_MINT_ID
+    {
+        state.wsol_accounts.insert(account.clone());
+    }
+}
+
+/// Wrapped SOL: `SyncNative` marks its account as wSOL; `CloseAccount` on a wSOL account
+/// returns the account's lamports to the destination as SOL (recorded as a `WsolUnwrap`).
+fn track_wrapped_sol(
+    state: &mut AnalysisState,
+    accounts: &[PubkeyBase58],
+    instr: &Instruction,
+    signer: &PubkeyBase58,
+) {
+    let account_at = |position: usize| {
+        instr
+            .accounts
+            .get(position)
+            .and_then(|&idx| accounts.get(idx as usize))
+    };
+
+    if let Some(account) = decode_token_sync_native_account(&instr.data).and_then(account_at) {
+        state.wsol_accounts.insert(account.clone());
+        return;
+    }
+
+    // CloseAccount accounts: [account, destination, owner]
+    let Some(destination) = decode_token_close_account_destination(&instr.data).and_then(account_at)
+    else {
+        return;
+    };
+    let Some(account) = account_at(0).filter(|account| state.wsol_accounts.contains(*account)) else {
+        return;
+    };
+
+    let account_str = account.to_string();
+    let wrapped_lamports = state
+        .transfers
+        .iter()
+        .filter(|t| t.from_is_signer && t.to == account_str)
+        .fold(0u64, |sum, t| sum.saturating_add(t.lamports));
+    state.wsol_unwraps.push(WsolUnwrap {
+        account: account.clone(),
+        destination: destination.clone(),
+        wrapped_lamports,
+        to_signer: destination == signer,
+    });
+}
+
+/// Record a System `CreateAccount` with the rent-exempt minimum for its space, and note
+/// when it creates the signer's own account.
+fn record_created_account(
+    state: &mut AnalysisState,
+    accounts: &[PubkeyBase58],
+    instr: &Instruction,
+    signer: &PubkeyBase58,
+    rent: &RentConfig,
+) {
+    let Some(create) = decode_system_create_account(&instr.data) else {
+        return;
+    };
+    let account_at = |position: usize| {
+        instr
+            .accounts
+            .get(position)
+            .and_then(|&idx| accounts.get(idx as usize))
+            .cloned()
+    };
+    if let (Some(funder), Some(account)) = (account_at(0), account_at(1)) {
+        if &account == signer && state.signer_created_with_owner.is_none() {
+            state.signer_created_with_owner = Some(create.owner.clone());
+        }
+        state.created_accounts.push(CreatedAccount {
+            account,
+            funder,
+            owner: create.owner,
+            space: create.space,
+            lamports: create.lamports,
+            rent_exempt_minimum: rent.minimum_balance(create.space),
+        });
     }
 }
 
+/// Warn when a Stake `Withdraw` pays out to neither the signer nor the withdraw authority.
+fn check_stake_withdraw(
+    accounts: &[PubkeyBase58],
+    instr: &Instruction,
+    signer: &PubkeyBase58,
+    warnings: &mut Vec<AnalysisWarning>,
+) {
+    let Some(withdraw) = decode_stake_withdraw(&instr.data) else {
+        return;
+    };
+    let account_at = |position: usize| {
+        instr
+            .accounts
+            .get(position)
+            .and_then(|&idx| accounts.get(idx as usize))
+    };
+    let Some(recipient) = account_at(withdraw.recipient_index) else {
+        return;
+    };
+
+    if recipient != signer && account_at(withdraw.authority_index) != Some(recipient) {
+        warnings.push(AnalysisWarning::StakeWithdrawToOther {
+            recipient: recipient.clone(),
+            lamports: withdraw.lamports,
+        });
+    }
+}
+
+/// Warn when a Token `Approve` / `ApproveChecked` grants a delegate; `Revoke` is harmless.
+fn check_token_approve(accounts: &[PubkeyBase58], instr: &Instruction, warnings: &mut Vec<AnalysisWarning>) {
+    let TokenDelegateAction::Approve {
+        delegate_index,
+        amount,
+    } = decode_token_delegate(&instr.data)
+    else {
+        return;
+    };
+    let Some(delegate) = instr
+        .accounts
+        .get(delegate_index)
+        .and_then(|&idx| accounts.get(idx as usize))
+    else {
+        return;
+    };
+
+    warnings.push(AnalysisWarning::TokenDelegateApproved {
+        delegate: delegate.clone(),
+        amount,
+    });
+}
+
+/// Classify a Token `SetAuthority` by whether the new authority is the signer.
+/// Removing an authority (`None`) is irreversible but hands control to nobody.
+fn check_token_set_authority(instr: &Instruction, signer: &PubkeyBase58, warnings: &mut Vec<AnalysisWarning>) {
+    let Some((authority_type, Some(new_authority))) = decode_token_set_authority(&instr.data) else {
+        return;
+    };
+    let authority_type = TokenAuthorityType::from(authority_type);
+
+    if new_authority == *signer {
+        warnings.push(AnalysisWarning::SetAuthorityToSelf { authority_type });
+    } else {
+        warnings.push(AnalysisWarning::SetAuthorityToOther {
+            authority_type,
+            new_authority,
+        });
+    }
+}
+
+/// Count exact-duplicate instructions (same program, accounts and data) and warn
+/// for each one repeated more than `REPEATED_INSTRUCTION_THRESHOLD` times.
+fn detect_repeated_instructions(instructions: &[Instruction], warnings: &mut Vec<AnalysisWarning>) {
+    let mut counts: HashMap<&Instruction, usize> = HashMap::new();
+    // Keep first-seen order so warnings are deterministic
+    let mut order: Vec<&Instruction> = Vec::new();
+
+    for instr in instructions {
+        let count = counts.entry(instr).or_insert(0);
+        if *count == 0 {
+            order.push(instr);
+        }
+        *count += 1;
+    }
+
+    for instr in order {
+        let count = counts[instr];
+        if count > REPEATED_INSTRUCTION_THRESHOLD {
+            warnings.push(AnalysisWarning::RepeatedInstruction { count });
+        }
+    }
+}
+
+/// Returns the full account list together with the number of static keys (where lookup-resolved accounts begin).
 fn resolve_message_components<'a>(
     message: &'a Message,
     tables: Option<&LookupTableEntry>,
     warnings: &mut Vec<AnalysisWarning>,
 ) -> (
     Cow<'a, [PubkeyBase58]>,
+    usize,
     &'a [Instruction],
     &'static str,
     Option<&'a [MessageAddressTableLookup]>,
 ) {
+    let lookups = match message {
+        Message::Legacy(_) => &[][..],
+        Message::V0(v0) => &v0.address_table_lookups[..],
+    };
+    check_unused_tables(lookups, tables, warnings);
+
     match message {
         Message::Legacy(m) => (
             Cow::Borrowed(&m.account_keys),
+            m.account_keys.len(),
             &m.instructions,
             "legacy",
             None,
         ),
-        Message::V0(v0) => (
-            Cow::Owned(resolve_v0_accounts(
+        Message::V0(v0) => {
+            let accounts = resolve_v0_accounts(
                 &v0.account_keys,
                 &v0.address_table_lookups,
                 tables,
                 warnings,
-            )),
-            &v0.instructions,
-            "v0",
-            Some(&v0.address_table_lookups),
-        ),
+            );
+            (
+                Cow::Owned(accounts),
+                v0.account_keys.len(),
+                &v0.instructions,
+                "v0",
+                Some(&v0.address_table_lookups),
+            )
+        }
     }
 }
 
+/// Tables declared in the tables file that no address table lookup references.
+fn check_unused_tables(
+    lookups: &[MessageAddressTableLookup],
+    table: Option<&LookupTableEntry>,
+    warnings: &mut Vec<AnalysisWarning>,
+) {
+    let Some(lut_entry) = table else {
+        return;
+    };
+    for declared in &lut_entry.tables {
+        if !lookups.iter().any(|lookup| &lookup.account_key == declared) {
+            warnings.push(AnalysisWarning::UnusedLookupTable {
+                table: declared.clone(),
+            });
+        }
+    }
+}
+
+/// Combine static keys with the lookup-resolved accounts, in the runtime order that
+/// `is_account_writable` assumes: the writable indexes of every lookup, then the readonly
+/// ones, in lookup order.
+///
+/// Each lookup takes as many addresses from the tables file as it has indexes; extra
+/// addresses are ignored. When the file runs short, that lookup is reported as
+/// `LookupTableMissing` and later positions are left unresolved rather than shifted into
+/// the wrong writable/readonly region.
 fn resolve_v0_accounts(
     static_keys: &[PubkeyBase58],
     lookups: &[MessageAddressTableLookup],
     table: Option<&LookupTableEntry>,
     warnings: &mut Vec<AnalysisWarning>,
 ) -> Vec<PubkeyBase58> {
-    let extra_capacity = table.map_or(0, |t| t.writable.len() + t.readonly.len());
-    let mut combined = Vec::with_capacity(static_keys.len() + extra_capacity);
+    let lookup_len: usize = lookups
+        .iter()
+        .map(|l| l.writable_indexes.len() + l.readonly_indexes.len())
+        .sum();
+    let mut combined = Vec::with_capacity(static_keys.len() + lookup_len);
 
     combined.extend_from_slice(static_keys);
 
-    if let Some(lut_entry) = table {
-        // Add all writable accounts from the lookup table
-        combined.extend_from_slice(&lut_entry.writable);
-        // Add all readonly accounts from the lookup table
-        combined.extend_from_slice(&lut_entry.readonly);
-    } else if !lookups.is_empty() {
-        warnings.push(AnalysisWarning::LookupTableNotProvided);
+    let Some(lut_entry) = table else {
+        if !lookups.is_empty() {
+            warnings.push(AnalysisWarning::LookupTableNotProvided);
+        }
+        return combined;
+    };
+
+    'resolve: for (addresses, writable) in [(&lut_entry.writable, true), (&lut_entry.readonly, false)] {
+        let mut remaining = addresses.iter();
+        for lookup in lookups {
+            let count = if writable {
+                lookup.writable_indexes.len()
+            } else {
+                lookup.readonly_indexes.len()
+            };
+            let before = combined.len();
+            combined.extend(remaining.by_ref().take(count).cloned());
+            if combined.len() - before < count {
+                warnings.push(AnalysisWarning::LookupTableMissing(lookup.account_key.clone()));
+                break 'resolve;
+            }
+        }
     }
 
     combined
@@ -217,16 +1070,12 @@ fn resolve_v0_accounts(
 
 fn process_transfer(
     state: &mut AnalysisState,
+    message: &Message,
     accounts: &[PubkeyBase58],
     instr: &Instruction,
     lamports: u64,
     signer: &PubkeyBase58,
 ) {
-    // Anti-DoS: Don't collect thousands of transfers
-    if state.transfers.len() >= MAX_TRANSFERS_TO_DISPLAY {
-        return;
-    }
-
     let Some(&from_idx) = instr.accounts.first() else {
         return;
     };
@@ -234,27 +1083,71 @@ fn process_transfer(
         return;
     };
 
-    let from = account_to_string(accounts, from_idx);
-    let to = account_to_string(accounts, to_idx);
-
     let from_is_signer = accounts
         .get(from_idx as usize)
         .map(|pk| pk == signer)
         .unwrap_or(false);
+    // Security flags must not depend on the display cap below
+    note_signer_debit(state, from_is_signer);
+
+    // Anti-DoS: Don't collect thousands of transfers
+    if state.transfers.len() >= MAX_TRANSFERS_TO_DISPLAY {
+        return;
+    }
 
-    if from_is_signer {
+    let from = account_to_string(accounts, from_idx);
+    let to = account_to_string(accounts, to_idx);
+
+    record_transfer(
+        state,
+        TransferView {
+            from,
+            to,
+            lamports,
+            from_is_signer,
+            from_writable: is_account_writable(message, from_idx as usize),
+            to_writable: is_account_writable(message, to_idx as usize),
+            to_is_fee_payer: to_idx == 0,
+        },
+    );
+}
+
+/// Collect a transfer and account for the signer's outflow (shared by System and registered decoders).
+fn record_transfer(state: &mut AnalysisState, transfer: TransferView) {
+    note_signer_debit(state, transfer.from_is_signer);
+
+    // Anti-DoS: Don't collect thousands of transfers
+    if state.transfers.len() >= MAX_TRANSFERS_TO_DISPLAY {
+        return;
+    }
+
+    if transfer.from_is_signer {
         // Safe saturating add to prevent overflow in accumulation
         state.total_sol_send_by_signer = state
             .total_sol_send_by_signer
-            .saturating_add(lamports as u128);
+            .saturating_add(transfer.lamports as u128);
+
+        let outflow = state
+            .signer_outflows_by_recipient
+            .entry(transfer.to.clone())
+            .or_default();
+        *outflow = outflow.saturating_add(transfer.lamports);
     }
 
-    state.transfers.push(TransferView {
-        from,
-        to,
-        lamports,
-        from_is_signer,
-    });
+    state.transfers.push(transfer);
+}
+
+/// Remember a debit of the signer that follows a `CreateAccount` of the signer's own account.
+fn note_signer_debit(state: &mut AnalysisState, from_is_signer: bool) {
+    if from_is_signer && state.signer_created_with_owner.is_some() {
+        state.signer_debited_after_create = true;
+    }
+}
+
+/// Priority fee in lamports for a Compute Budget price (micro-lamports per CU) and limit:
+/// `price_micro * limit / 1_000_000`, rounded down.
+pub fn priority_fee_for(price_micro: u64, limit: u32) -> u128 {
+    (price_micro as u128) * (limit as u128) / MICRO_LAMPORTS_PER_LAMPORT
 }
 
 fn finalize_analysis(
@@ -262,11 +1155,26 @@ fn finalize_analysis(
     state: AnalysisState,
     mut warnings: Vec<AnalysisWarning>,
     message_version: &'static str,
+    allowlist: Option<&HashSet<PubkeyBase58>>,
 ) -> TxAnalysis {
+    let size = serialized_transaction_size(message);
+    if size > packet::PACKET_DATA_SIZE {
+        warnings.push(AnalysisWarning::TransactionTooLarge { size });
+    }
     for &kind in &state.detected_programs {
         warnings.push(AnalysisWarning::TokenTransferDetected(kind));
     }
+    for &kind in &state.precompiles {
+        warnings.push(AnalysisWarning::PrecompileSignatureInstruction { kind });
+    }
+    for program in state.multisig_programs {
+        warnings.push(AnalysisWarning::MultisigProgramDetected { program });
+    }
     for program_id in state.unknown_programs {
+        // Trusted programs stay undecoded but are not worth a warning
+        if allowlist.is_some_and(|trusted| trusted.contains(&program_id)) {
+            continue;
+        }
         warnings.push(AnalysisWarning::UnknownProgram { program_id });
     }
     // Privacy Level Calculation
@@ -313,15 +1221,19 @@ fn finalize_analysis(
             .cu_limit
             .unwrap_or(compute_budget::DEFAULT_COMPUTE_UNIT_LIMIT);
 
-        // fee = (price * limit) / 1_000_000
-        let fee = (price_micro as u128)
-            .checked_mul(limit as u128)
-            .and_then(|prod| prod.checked_div(MICRO_LAMPORTS_PER_LAMPORT))
-            .unwrap_or(0);
-
         let estimated = state.cu_limit.is_none();
-        (fee, estimated)
+        (priority_fee_for(price_micro, limit), estimated)
     });
+    if state.signer_debited_after_create
+        && let Some(owner) = state.signer_created_with_owner.clone()
+    {
+        warnings.push(AnalysisWarning::SignerAccountCreatedAndDebited { owner });
+    }
+    if matches!(priority_fee_lamports, Some((_, true))) {
+        warnings.push(AnalysisWarning::PriorityFeeEstimated {
+            assumed_limit: compute_budget::DEFAULT_COMPUTE_UNIT_LIMIT,
+        });
+    }
 
     let total_fee_lamports = base_fee_lamports
         .checked_add(priority_fee_lamports.map(|(f, _)| f).unwrap_or(0))
@@ -333,10 +1245,12 @@ fn finalize_analysis(
         priority_fee_lamports,
         total_fee_lamports,
         total_sol_send_by_signer: state.total_sol_send_by_signer,
+        signer_outflows_by_recipient: state.signer_outflows_by_recipient,
         compute_unit_limit: state.cu_limit,
         compute_unit_price_micro: state.cu_price_micro,
         warnings,
         message_version,
+        recent_blockhash: message.recent_blockhash().to_string(),
         privacy_level,
         extension_actions: state.extension_actions,
         extension_notices: Vec::new(),
@@ -344,6 +1258,35 @@ fn finalize_analysis(
         storage_ops_count: state.storage_ops_count,
         is_fee_payer: state.is_fee_payer,
         has_non_sol_assets: !state.detected_programs.is_empty(),
+        unknown_instructions: state.unknown_instructions,
+        shape: transaction_shape(message),
+        token_setup: state.token_setup,
+        anchor_actions: state.anchor_actions,
+        created_accounts: state.created_accounts,
+        wsol_unwraps: state.wsol_unwraps,
+    }
+}
+
+fn transaction_shape(message: &Message) -> TransactionShape {
+    let header = message.header();
+    let static_account_count = message.account_keys().len();
+    let lookups: &[MessageAddressTableLookup] = match message {
+        Message::Legacy(_) => &[],
+        Message::V0(v0) => &v0.address_table_lookups,
+    };
+    let loaded = lookups
+        .iter()
+        .map(|l| l.writable_indexes.len() + l.readonly_indexes.len())
+        .sum::<usize>();
+
+    TransactionShape {
+        account_count: static_account_count + loaded,
+        static_account_count,
+        num_required_signatures: header.num_required_signatures,
+        num_readonly_signed_accounts: header.num_readonly_signed_accounts,
+        num_readonly_unsigned_accounts: header.num_readonly_unsigned_accounts,
+        instruction_count: message.instructions().len(),
+        lookup_table_count: lookups.len(),
     }
 }
 
@@ -351,10 +1294,6 @@ pub fn build_signing_summary(
     tx: &Transaction,
     analysis: &TxAnalysis,
 ) -> Result<SigningSummary, ToolError> {
-    let to_u64 = |v: u128| -> Result<u64, ToolError> {
-        u64::try_from(v).map_err(|_| ToolError::InvalidInput("lamports overflowed u64".into()))
-    };
-
     let raw = serialize_transaction(tx);
     let signed_tx_base64 = data_encoding::BASE64.encode(&raw);
     let signatures: Vec<String> = tx
@@ -362,38 +1301,60 @@ pub fn build_signing_summary(
         .iter()
         .map(|s| bs58::encode(s.to_bytes()).into_string())
         .collect();
+    // The first signature identifies the transaction (explorer links, RPC lookups)
+    let transaction_id = tx
+        .signatures
+        .first()
+        .filter(|s| s.to_bytes() != [0u8; SIG_LEN])
+        .map(|s| bs58::encode(s.to_bytes()).into_string());
 
     let (priority_fee_lamports, priority_fee_estimated) =
         if let Some((fee, est)) = analysis.priority_fee_lamports {
-            (to_u64(fee)?, est)
+            (to_lamports(fee, "priority_fee_lamports")?, est)
         } else {
-            (0, false)
+            (Lamports::ZERO, false)
         };
 
+    let base_fee_lamports = to_lamports(analysis.base_fee_lamports, "base_fee_lamports")?;
+    let total_fee_lamports = to_lamports(analysis.total_fee_lamports, "total_fee_lamports")?;
+    let total_sol_send_by_signer =
+        to_lamports(analysis.total_sol_send_by_signer, "total_sol_send_by_signer")?;
+
     // Safe addition for max cost
-    let max_cost = analysis
-        .total_fee_lamports
-        .checked_add(analysis.total_sol_send_by_signer)
-        .ok_or_else(|| ToolError::InvalidInput("Total cost overflowed u128".into()))?;
+    let max_cost = total_fee_lamports
+        .checked_add(total_sol_send_by_signer)
+        .ok_or(ToolError::AmountOverflow { field: "max_total_cost_lamports" })?;
 
     let is_fee_payer = analysis.is_fee_payer;
 
     Ok(SigningSummary {
         message_version: analysis.message_version.to_string(),
+        recent_blockhash: analysis.recent_blockhash.clone(),
         signatures,
+        transaction_id,
         signed_tx_base64,
-        base_fee_lamports: to_u64(analysis.base_fee_lamports)?,
+        base_fee_lamports,
         priority_fee_lamports,
         priority_fee_estimated,
         fee_is_estimate: priority_fee_estimated,
         compute_unit_price_micro: analysis.compute_unit_price_micro,
+        compute_unit_price_lamports_per_cu: analysis
+            .compute_unit_price_micro
+            .map(format_micro_lamports),
         compute_unit_limit: analysis.compute_unit_limit,
-        total_fee_lamports: to_u64(analysis.total_fee_lamports)?,
-        total_sol_send_by_signer: to_u64(analysis.total_sol_send_by_signer)?,
-        max_total_cost_lamports: to_u64(max_cost)?,
+        total_fee_lamports,
+        total_sol_send_by_signer,
+        signer_outflows_by_recipient: analysis.signer_outflows_by_recipient.clone(),
+        max_total_cost_lamports: max_cost,
         is_fee_payer,
         has_non_sol_assets: analysis.has_non_sol_assets,
         warnings: analysis.warnings.clone(),
+        unknown_instructions: analysis.unknown_instructions.clone(),
+        shape: analysis.shape.clone(),
+        token_setup: analysis.token_setup.clone(),
+        anchor_actions: analysis.anchor_actions.clone(),
+        created_accounts: analysis.created_accounts.clone(),
+        wsol_unwraps: analysis.wsol_unwraps.clone(),
         extension_actions: analysis
             .extension_actions
             .iter()
@@ -405,6 +1366,13 @@ pub fn build_signing_summary(
     })
 }
 
+/// Narrow a summary amount to `u64`, naming the field that overflowed.
+fn to_lamports(value: u128, field: &'static str) -> Result<Lamports, ToolError> {
+    u64::try_from(value)
+        .map(Lamports::from)
+        .map_err(|_| ToolError::AmountOverflow { field })
+}
+
 fn account_to_string(accounts: &[PubkeyBase58], index: u8) -> String {
     accounts
         .get(index as usize)
@@ -412,31 +1380,48 @@ fn account_to_string(accounts: &[PubkeyBase58], index: u8) -> String {
         .unwrap_or_else(|| format!("<unresolved: #{}>", index))
 }
 
-/// Runs all registered analysis extensions (plugins) on the transaction.
+/// Runs the given analysis extensions (plugins) on the transaction.
+///
+/// Returns `false` when `deadline` passed before every plugin could run.
 fn process_analysis_extensions(
     message: &Message,
     account_list: &[PubkeyBase58],
     signer: &PubkeyBase58,
     analysis: &mut TxAnalysis,
-) {
-    let plugins = registry::get_all_analyzers();
+    analyzers: &AnalyzerSet,
+    deadline: Option<Instant>,
+    timings: &mut Timings,
+) -> bool {
+    let plugins = analyzers.analyzers();
 
     for plugin in plugins {
+        if deadline_passed(deadline) {
+            return false;
+        }
         // Check if protocol is involved either via direct instructions or account presence
         let has_instructions = plugin.detect(message);
         
         // Also check in resolved account_list (includes lookup tables)
         let supported = match plugin.supported_programs() {
             Ok(programs) => programs,
-            Err(_) => &[],
+            Err(e) => {
+                log::warn!("{}: failed to initialize program ids: {}", plugin.name(), e);
+                &[]
+            }
         };
         
         let in_resolved_accounts = account_list.iter().any(|pk| supported.contains(pk));
         
         if has_instructions {
             // Full analysis when protocol is directly invoked
-            plugin.analyze(message, account_list, signer, analysis);
-            plugin.enrich_notice(analysis);
+            timings.measure(
+                || format!("{}::analyze", plugin.name()),
+                || plugin.analyze(message, account_list, signer, analysis),
+            );
+            timings.measure(
+                || format!("{}::enrich_notice", plugin.name()),
+                || plugin.enrich_notice(analysis),
+            );
 
             if let Ok(supported) = plugin.supported_programs() {
                 analysis.resolve_unknown_programs(supported);
@@ -478,4 +1463,5 @@ fn process_analysis_extensions(
             }
         }
     }
+    true
 }
@@ -1,5 +1,7 @@
+use crate::codec::deserialize_transaction_with_len;
 use crate::errors::Result;
-use crate::models::results::Base58Result;
+use crate::models::input_transaction::UiTransaction;
+use crate::models::results::{Base58Result, Base58TransactionResult};
 
 /// Perform Base58 encode and return a structured result.
 pub fn encode(data: &str) -> Result<Base58Result> {
@@ -23,3 +25,25 @@ pub fn decode(encoded: &str) -> Result<Base58Result> {
         output: decoded,
     })
 }
+
+/// Base58-decode and try to parse the bytes as a wire transaction.
+///
+/// Falls back to the decoded text when parsing fails or bytes remain after the message.
+pub fn decode_as_transaction(encoded: &str) -> Result<Base58TransactionResult> {
+    let bytes = bs58::decode(encoded).into_vec()?;
+
+    let transaction = match deserialize_transaction_with_len(&bytes) {
+        Ok((tx, consumed)) if consumed == bytes.len() => Some(UiTransaction::from(&tx)),
+        _ => None,
+    };
+    let output = match transaction {
+        Some(_) => None,
+        None => Some(String::from_utf8_lossy(&bytes).into()),
+    };
+
+    Ok(Base58TransactionResult {
+        input: encoded.into(),
+        transaction,
+        output,
+    })
+}
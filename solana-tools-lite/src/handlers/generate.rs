@@ -1,48 +1,133 @@
+#[cfg(feature = "io")]
 use crate::adapters::io_adapter::{read_mnemonic, read_passphrase};
-use crate::crypto::derive::{derive_key_from_seed, DerivationPath, SOLANA_DERIVATION_PATH};
+use crate::crypto::derive::{
+    derive_key_from_seed, master_fingerprint, DerivationPath, SOLANA_DERIVATION_PATH,
+};
 use crate::crypto::mnemonic::{
-    derive_seed_from_mnemonic, generate_mnemonic_with, parse_mnemonic, Bip39Config,
+    derive_seed_from_mnemonic, generate_mnemonic_with, parse_mnemonic, Bip39Config, Seed,
 };
-use crate::errors::Result;
-use crate::models::results::GenResult;
+use crate::crypto::signing::keypair_from_seed;
+use crate::errors::{GenError, Result, ToolError};
+#[cfg(feature = "io")]
+use crate::models::results::DeriveResult;
+use crate::models::results::{DeriveManyResult, DerivedAccount, GeneratedWallet, WalletSecret};
 use crate::utils::hex_encode;
-use ed25519_dalek::SigningKey;
-
-/// Generate or load a mnemonic, derive a keypair, and return a structured result.
-pub fn handle(mnemonic_path: Option<&str>, passphrase_path: Option<&str>) -> Result<GenResult> {
-    // Resolve mnemonic: read from file/stdin if provided, otherwise generate a new one.
-    let mnemonic = if let Some(p) = mnemonic_path {
-        let m = read_mnemonic(p)?; // file or "-" (stdin), with whitespace normalization
-        parse_mnemonic(&m)?
-    } else {
-        generate_mnemonic_with(Bip39Config::default())?
-    };
+use crate::crypto::secret::Zeroizing;
 
-    // Resolve passphrase securely: read from file or stdin when provided; default to empty
-    let passphrase_owned: Option<String> = match passphrase_path {
-        Some(p) => Some(read_passphrase(p)?),
-        None => None,
+/// Derive a wallet from `mnemonic` (or a freshly generated one) and return structured data.
+///
+/// Pure: takes the phrase and passphrase as text and performs no I/O. Secret material is
+/// included only when `include_secret` is set.
+pub fn handle(
+    mnemonic: Option<&str>,
+    passphrase: Option<&str>,
+    include_secret: bool,
+) -> Result<GeneratedWallet> {
+    let mnemonic = match mnemonic {
+        Some(phrase) => parse_mnemonic(phrase)?,
+        None => generate_mnemonic_with(Bip39Config::default())?,
     };
+    let passphrase = passphrase.unwrap_or("");
+    let seed = derive_seed_from_mnemonic(&mnemonic, passphrase);
 
-    let passphrase: &str = passphrase_owned.as_deref().unwrap_or("");
+    derive_wallet(&seed, Some(mnemonic.phrase()), !passphrase.is_empty(), include_secret)
+}
 
-    let seed = derive_seed_from_mnemonic(&mnemonic, passphrase);
+/// Derive a wallet from a raw 64-byte BIP-39 seed given as 128 hex characters.
+///
+/// Skips mnemonic parsing: the seed already includes any passphrase, so the result has
+/// no mnemonic and `passphrase_used` is false.
+pub fn handle_seed(seed_hex: &str, include_secret: bool) -> Result<GeneratedWallet> {
+    let seed = parse_seed_hex(seed_hex)?;
+    derive_wallet(&seed, None, false, include_secret)
+}
 
-    // Solana standard path: m/44'/501'/0'/0'
-    let path = DerivationPath::parse(SOLANA_DERIVATION_PATH)?;
-    let (key_bytes, _) = derive_key_from_seed(&seed, &path)?;
+/// Derive accounts `0..count` (`m/44'/501'/<index>'/0'`) from one mnemonic.
+///
+/// Pure like `handle`. Secret keys are included only when `include_secret` is set;
+/// `output_path` is left for the caller that writes the wallet files.
+pub fn derive_accounts(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    count: u32,
+    include_secret: bool,
+) -> Result<DeriveManyResult> {
+    if count == 0 {
+        return Err(ToolError::InvalidInput("account count must be at least 1".into()));
+    }
+    let mnemonic = parse_mnemonic(mnemonic)?;
+    let seed = derive_seed_from_mnemonic(&mnemonic, passphrase.unwrap_or(""));
+
+    let accounts = (0..count)
+        .map(|index| {
+            let path = DerivationPath::parse(&format!("m/44'/501'/{index}'/0'"))?;
+            let (key_bytes, _) = derive_key_from_seed(&seed, &path)?;
+            let signing_key = keypair_from_seed(&key_bytes)?;
+            Ok(DerivedAccount {
+                index,
+                derivation_path: path.to_string(),
+                public_key: bs58::encode(signing_key.verifying_key().to_bytes()).into_string(),
+                secret_key: include_secret
+                    .then(|| bs58::encode(signing_key.to_bytes()).into_string()),
+                output_path: None,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
 
-    let signing_key = SigningKey::from_bytes(&key_bytes);
+    Ok(DeriveManyResult { accounts })
+}
+
+/// Decode a 64-byte seed from hex (surrounding whitespace is ignored).
+pub fn parse_seed_hex(seed_hex: &str) -> Result<Seed> {
+    let bytes = Zeroizing::new(
+        hex::decode(seed_hex.trim())
+            .map_err(|e| ToolError::InvalidInput(format!("seed is not valid hex: {e}")))?,
+    );
+    let mut seed = Zeroizing::new([0u8; 64]);
+    if bytes.len() != seed.len() {
+        return Err(GenError::InvalidSeedLength.into());
+    }
+    seed.copy_from_slice(&bytes);
+    Ok(Seed::new(*seed))
+}
 
-    let pubkey_bytes = signing_key.verifying_key().to_bytes();
-    let pubkey_base58 = bs58::encode(pubkey_bytes).into_string();
+fn derive_wallet(
+    seed: &Seed,
+    mnemonic: Option<String>,
+    passphrase_used: bool,
+    include_secret: bool,
+) -> Result<GeneratedWallet> {
+    // Solana standard path: m/44'/501'/0'/0'
+    let path = DerivationPath::parse(SOLANA_DERIVATION_PATH)?;
+    let (key_bytes, _) = derive_key_from_seed(seed, &path)?;
+    let signing_key = keypair_from_seed(&key_bytes)?;
 
-    let result = GenResult {
-        mnemonic: mnemonic.phrase(),
-        public_key: pubkey_base58.clone(),
+    let secret = include_secret.then(|| WalletSecret {
         secret_key: bs58::encode(signing_key.to_bytes()).into_string(),
         seed_hex: hex_encode(seed.as_bytes()),
-    };
+    });
+
+    Ok(GeneratedWallet {
+        mnemonic,
+        passphrase_used,
+        pubkey: bs58::encode(signing_key.verifying_key().to_bytes()).into_string(),
+        derivation_path: path.to_string(),
+        master_fingerprint: hex_encode(&master_fingerprint(seed)),
+        secret,
+    })
+}
+
+/// Derive only the public address for an existing mnemonic.
+///
+/// Nothing secret is returned; the caller never needs to persist anything.
+#[cfg(feature = "io")]
+pub fn derive_only(mnemonic_path: &str, passphrase_path: Option<&str>) -> Result<DeriveResult> {
+    let mnemonic = read_mnemonic(mnemonic_path)?;
+    let passphrase = passphrase_path.map(read_passphrase).transpose()?;
+    let wallet = handle(Some(&mnemonic), passphrase.as_deref(), false)?;
 
-    Ok(result)
+    Ok(DeriveResult {
+        public_key: wallet.pubkey,
+        derivation_path: wallet.derivation_path,
+    })
 }
@@ -0,0 +1,86 @@
+use crate::handlers::analysis::is_account_writable;
+use crate::models::message::Message;
+use crate::models::network::Network;
+use crate::models::results::{
+    AccountRegion, InspectResult, InspectedAccount, InspectedInstruction, InspectedLookup,
+    InspectedSignature,
+};
+use crate::models::transaction::Transaction;
+use crate::utils::hex_encode;
+
+/// Dump the structure of a transaction: signatures with their status, header, account keys
+/// with their regions, blockhash, instructions and lookups. Nothing is interpreted; known
+/// programs are only labelled by their name on `network`.
+pub fn handle(tx: &Transaction, network: Network) -> InspectResult {
+    let message = &tx.message;
+    let header = *message.header();
+    let required = header.num_required_signatures as usize;
+    let keys = message.account_keys();
+
+    let signatures = tx
+        .signatures
+        .iter()
+        .zip(tx.verify_all())
+        .map(|(sig, status)| InspectedSignature {
+            signature: bs58::encode(sig.to_bytes()).into_string(),
+            status,
+        })
+        .collect();
+
+    let account_keys = keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| {
+            let region = match (index < required, is_account_writable(message, index)) {
+                (true, true) => AccountRegion::WritableSigner,
+                (true, false) => AccountRegion::ReadonlySigner,
+                (false, true) => AccountRegion::Writable,
+                (false, false) => AccountRegion::Readonly,
+            };
+            InspectedAccount {
+                index,
+                pubkey: key.to_string(),
+                region,
+            }
+        })
+        .collect();
+
+    let instructions = message
+        .instructions()
+        .iter()
+        .map(|instr| {
+            let program_id = instr.program_id(keys).map(ToString::to_string);
+            InspectedInstruction {
+                program_label: program_id.as_deref().and_then(|id| network.program_label(id)),
+                program_id,
+                accounts: instr.accounts.clone(),
+                data_hex: hex_encode(&instr.data),
+            }
+        })
+        .collect();
+
+    let (version, address_table_lookups) = match message {
+        Message::Legacy(_) => ("legacy", Vec::new()),
+        Message::V0(v0) => (
+            "v0",
+            v0.address_table_lookups
+                .iter()
+                .map(|lookup| InspectedLookup {
+                    account_key: lookup.account_key.to_string(),
+                    writable_indexes: lookup.writable_indexes.clone(),
+                    readonly_indexes: lookup.readonly_indexes.clone(),
+                })
+                .collect(),
+        ),
+    };
+
+    InspectResult {
+        signatures,
+        version,
+        header,
+        account_keys,
+        recent_blockhash: message.recent_blockhash().to_string(),
+        instructions,
+        address_table_lookups,
+    }
+}
@@ -1,4 +1,5 @@
 pub mod base58;
+pub mod inspect;
 pub mod analysis;
 pub mod generate;
 pub mod sign_message;
@@ -1,6 +1,9 @@
-use crate::crypto::signing::sign_message;
-use crate::errors::Result;
+use crate::codec::deserialize_transaction_with_len;
+use crate::crypto::signing::{sign_message, sign_raw_bytes};
+use crate::errors::{Result, ToolError};
 use crate::models::results::SignResult;
+use crate::models::Message;
+use data_encoding::BASE64;
 use ed25519_dalek::{Signature, SigningKey};
 
 /// Pure handler: sign a message with the provided SigningKey
@@ -18,3 +21,32 @@ pub fn handle(message: &str, signing_key: &SigningKey) -> Result<SignResult> {
         public_key: pubkey_b58,
     })
 }
+
+/// Pure handler: blind-sign raw bytes with the provided SigningKey.
+///
+/// Refuses bytes that decode as a serialized transaction or message, so a real
+/// transaction cannot be passed off as an opaque "message". `message` in the result
+/// holds the Base64 of the signed bytes.
+pub fn handle_raw(bytes: &[u8], signing_key: &SigningKey) -> Result<SignResult> {
+    if decodes_as_transaction(bytes) {
+        return Err(ToolError::InvalidInput(
+            "refusing to sign raw bytes: they decode as a Solana transaction (use sign-tx)"
+                .to_string(),
+        ));
+    }
+
+    let signature = sign_raw_bytes(signing_key, bytes);
+
+    Ok(SignResult {
+        message: BASE64.encode(bytes),
+        signature_base58: bs58::encode(signature.to_bytes()).into_string(),
+        public_key: bs58::encode(signing_key.verifying_key().to_bytes()).into_string(),
+    })
+}
+
+/// Whether `bytes` are exactly a serialized transaction or transaction message.
+fn decodes_as_transaction(bytes: &[u8]) -> bool {
+    let is_transaction =
+        matches!(deserialize_transaction_with_len(bytes), Ok((_, consumed)) if consumed == bytes.len());
+    is_transaction || Message::from_wire_bytes(bytes).is_ok()
+}
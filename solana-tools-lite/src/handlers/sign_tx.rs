@@ -1,12 +1,40 @@
-use crate::codec::serialize_message;
+use crate::codec::{serialize_message, serialize_transaction};
 use crate::crypto::signing::sign_message;
-use crate::errors::SignError;
+use crate::errors::{SignError, ToolError};
+use crate::handlers::analysis::{AnalysisConfig, analyze_transaction_with};
+use crate::models::analysis::{AnalysisWarning, TxAnalysis};
 use crate::models::input_transaction::InputTransaction;
-use crate::models::results::SignTxResult;
+use crate::models::rent::RentConfig;
+use crate::models::results::{SignOutcome, SignPlanResult, SignTxResult, SignerSlot};
 use crate::models::{PubkeyBase58, Transaction};
+use crate::serde::LookupTableEntry;
+use crate::serde::input_tx::parse_input_transaction;
 use crate::Result;
 
+use data_encoding::BASE64;
 use ed25519_dalek::{Signature, SigningKey};
+use std::collections::HashSet;
+
+/// Gates applied by `prepare_and_sign` between analysis and signing.
+#[derive(Default)]
+pub struct SignOptions<'a> {
+    /// Lookup tables used to resolve v0 accounts during analysis.
+    pub tables: Option<&'a LookupTableEntry>,
+    /// Programs trusted by the caller; they never produce `UnknownProgram` warnings.
+    pub allowlist: Option<&'a HashSet<PubkeyBase58>>,
+    /// Rent parameters for the rent-exempt minimum of created accounts.
+    pub rent: RentConfig,
+    /// Refuse when the total fee exceeds this many lamports.
+    pub max_fee: Option<u64>,
+    /// Refuse unless the signer is (`true`) or is not (`false`) the fee payer.
+    pub require_fee_payer: Option<bool>,
+    /// Refuse when any `UnknownProgram` warning remains after the allowlist.
+    pub deny_unknown_programs: bool,
+    /// Called with the fresh analysis before any gate, e.g. to order and display it.
+    pub review: Option<&'a dyn Fn(&mut TxAnalysis)>,
+    /// Last gate, e.g. an interactive prompt; returning `false` rejects signing.
+    pub confirm: Option<&'a dyn Fn(&TxAnalysis) -> bool>,
+}
 
 /// Pure handler: sign an input transaction with the given key and return a domain result.
 pub fn handle(
@@ -26,28 +54,172 @@ pub fn handle(
 /// Returns an error if the pubkey is not found or if it’s not a required signer.
 pub fn sign_transaction_by_key(tx: &mut Transaction, key: &SigningKey) -> Result<()> {
     let pubkey = PubkeyBase58::from(key.verifying_key().to_bytes());
+    let signer_index = signer_slot(tx, &pubkey)?;
+
+    let msg_bytes = serialize_message(&tx.message);
+    let sig = sign_message(key, &msg_bytes);
+
+    // Ensure signatures array is exactly the size required by the message header
+    let required_sigs = tx.message.header().num_required_signatures as usize;
+    if tx.signatures.len() < required_sigs {
+        tx.signatures
+            .resize(required_sigs, Signature::from_bytes(&[0u8; 64]));
+    }
 
+    tx.signatures[signer_index] = sig;
+    Ok(())
+}
+
+/// Signature slot `pubkey` fills: its index among the required signers.
+///
+/// Errors
+/// - `SignError::SignerKeyNotFound` when `pubkey` is not in `account_keys`
+/// - `SignError::SigningNotRequiredForKey` when it is present but not a required signer
+pub fn signer_slot(tx: &Transaction, pubkey: &PubkeyBase58) -> Result<usize> {
     let signer_index = tx
         .message
         .account_keys()
         .iter()
-        .position(|k| *k == pubkey)
+        .position(|k| k == pubkey)
         .ok_or(SignError::SignerKeyNotFound)?;
 
     if signer_index >= tx.message.header().num_required_signatures as usize {
         return Err(SignError::SigningNotRequiredForKey)?;
     }
+    Ok(signer_index)
+}
 
-    let msg_bytes = serialize_message(&tx.message);
-    let sig = sign_message(key, &msg_bytes);
+/// Dry run of `sign_transaction_by_key`: which required signer slots `keys` would fill.
+///
+/// Every required slot is listed with the position (in `keys`) of the first key that
+/// covers it; keys that fill no slot are reported as unused. Nothing is signed.
+pub fn plan(tx: &Transaction, keys: &[PubkeyBase58]) -> SignPlanResult {
+    let required = tx.message.header().num_required_signatures as usize;
+    let mut slots: Vec<SignerSlot> = tx
+        .message
+        .account_keys()
+        .iter()
+        .take(required)
+        .enumerate()
+        .map(|(index, pubkey)| SignerSlot {
+            index,
+            pubkey: pubkey.to_string(),
+            covered_by: None,
+        })
+        .collect();
 
-    // Ensure signatures array is exactly the size required by the message header
-    let required_sigs = tx.message.header().num_required_signatures as usize;
-    if tx.signatures.len() < required_sigs {
-        tx.signatures
-            .resize(required_sigs, Signature::from_bytes(&[0u8; 64]));
+    let mut unused_keys = Vec::new();
+    for (position, key) in keys.iter().enumerate() {
+        match signer_slot(tx, key) {
+            Ok(index) => {
+                let slot = &mut slots[index];
+                slot.covered_by.get_or_insert(position);
+            }
+            Err(_) => unused_keys.push(key.to_string()),
+        }
     }
 
-    tx.signatures[signer_index] = sig;
+    SignPlanResult {
+        fully_covered: slots.iter().all(|slot| slot.covered_by.is_some()),
+        slots,
+        unused_keys,
+    }
+}
+
+/// Parse, analyze, gate and sign a transaction in one pass.
+///
+/// `input` is auto-detected (JSON / Base64 / Base58) and parsed once; the rest is
+/// `prepare_and_sign_input`.
+pub fn prepare_and_sign(
+    input: &str,
+    keys: &[SigningKey],
+    opts: &SignOptions,
+) -> Result<SignOutcome> {
+    let input_tx = parse_input_transaction(Some(input))?;
+    prepare_and_sign_input(input_tx, keys, opts)
+}
+
+/// Analyze, gate and sign an already parsed transaction.
+///
+/// Behavior
+/// - The analysis is done for `keys[0]`; every key then signs its own slot
+/// - `review` sees the analysis first; gates then run in order: max fee, fee payer role,
+///   unknown programs, `confirm`
+///
+/// Errors
+/// - `InvalidInput` when `keys` is empty
+/// - `SignError::{FeeLimitExceeded, FeePayerMismatch, UnknownProgramDenied, Rejected}` from the gates
+/// - Parse and signing errors as in `handle`
+pub fn prepare_and_sign_input(
+    input_tx: InputTransaction,
+    keys: &[SigningKey],
+    opts: &SignOptions,
+) -> Result<SignOutcome> {
+    let first = keys
+        .first()
+        .ok_or_else(|| ToolError::InvalidInput("no signing key provided".into()))?;
+    let signer = PubkeyBase58::from(first.verifying_key().to_bytes());
+
+    let mut tx = Transaction::try_from(input_tx)?;
+    tx.message.sanitize()?;
+
+    let config = AnalysisConfig {
+        rent: opts.rent,
+        ..Default::default()
+    };
+    let mut analysis =
+        analyze_transaction_with(&tx.message, &signer, opts.tables, opts.allowlist, None, &config);
+    if let Some(review) = opts.review {
+        review(&mut analysis);
+    }
+
+    check_sign_gates(&analysis, opts)?;
+    if let Some(confirm) = opts.confirm
+        && !confirm(&analysis)
+    {
+        return Err(SignError::Rejected.into());
+    }
+
+    for key in keys {
+        sign_transaction_by_key(&mut tx, key)?;
+    }
+
+    let raw = serialize_transaction(&tx);
+    Ok(SignOutcome {
+        analysis,
+        signed_tx_base64: BASE64.encode(&raw),
+        signed_tx_base58: bs58::encode(&raw).into_string(),
+        signed_tx: tx,
+    })
+}
+
+/// Policy gates of `SignOptions` (max fee, fee payer role, unknown programs) against an
+/// analysis; `confirm` is not consulted.
+///
+/// Errors
+/// - `SignError::{FeeLimitExceeded, FeePayerMismatch, UnknownProgramDenied}` for the first failing gate
+pub fn check_sign_gates(analysis: &TxAnalysis, opts: &SignOptions) -> Result<()> {
+    if let Some(max_lamports) = opts.max_fee
+        && analysis.total_fee_lamports > max_lamports as u128
+    {
+        return Err(SignError::FeeLimitExceeded {
+            fee_lamports: analysis.total_fee_lamports,
+            max_lamports,
+        }
+        .into());
+    }
+    if let Some(required) = opts.require_fee_payer
+        && analysis.is_fee_payer != required
+    {
+        return Err(SignError::FeePayerMismatch { required }.into());
+    }
+    if opts.deny_unknown_programs
+        && let Some(program_id) = analysis.warnings.iter().find_map(|w| match w {
+            AnalysisWarning::UnknownProgram { program_id } => Some(program_id.to_string()),
+            _ => None,
+        })
+    {
+        return Err(SignError::UnknownProgramDenied { program_id }.into());
+    }
     Ok(())
 }
@@ -1,6 +1,46 @@
 use crate::crypto::signing::verify_signature_raw;
-use crate::errors::Result;
-use crate::models::results::VerifyResult;
+use crate::errors::{Result, ToolError, VerifyError};
+use data_encoding::BASE64;
+use crate::models::pubkey_base58::PubkeyBase58;
+use crate::models::results::{VerifyResult, VerifyTxResult, VerifyTxStatus};
+use crate::models::transaction::{SignatureStatus, Transaction};
+
+const SIG_LEN: usize = 64;
+const PUBKEY_LEN: usize = 32;
+
+/// Normalize a signature given as Base58 or Base64 to Base58.
+///
+/// The encoding is auto-detected: Base58 is tried first, then Base64; whichever decodes
+/// to 64 bytes wins. A value that decodes but to the wrong length is reported as
+/// `InvalidSignatureLength`.
+pub fn signature_to_base58(input: &str) -> std::result::Result<String, VerifyError> {
+    decode_auto(input, SIG_LEN).map_err(|err| match err {
+        Some(len) => VerifyError::InvalidSignatureLength(len),
+        None => VerifyError::InvalidSignatureFormat,
+    })
+}
+
+/// Normalize a public key given as Base58 or Base64 to Base58 (32 bytes after decoding).
+pub fn pubkey_to_base58(input: &str) -> std::result::Result<String, VerifyError> {
+    decode_auto(input, PUBKEY_LEN).map_err(|err| match err {
+        Some(len) => VerifyError::InvalidPubkeyLength(len),
+        None => VerifyError::InvalidPubkeyFormat,
+    })
+}
+
+/// Base58 of the first decoding with `expected_len` bytes; on failure, the decoded length
+/// of the first successful decoding (if any).
+fn decode_auto(input: &str, expected_len: usize) -> std::result::Result<String, Option<usize>> {
+    let b58 = bs58::decode(input).into_vec().ok();
+    if b58.as_ref().is_some_and(|bytes| bytes.len() == expected_len) {
+        return Ok(input.to_string());
+    }
+    let b64 = BASE64.decode(input.as_bytes()).ok();
+    if let Some(bytes) = b64.as_ref().filter(|bytes| bytes.len() == expected_len) {
+        return Ok(bs58::encode(bytes).into_string());
+    }
+    Err(b58.or(b64).map(|bytes| bytes.len()))
+}
 
 /// Verify a Base58 signature against a message and public key.
 pub fn handle(message: &str, signature_b58: &str, pubkey_b58: &str) -> Result<VerifyResult> {
@@ -11,5 +51,57 @@ pub fn handle(message: &str, signature_b58: &str, pubkey_b58: &str) -> Result<Ve
         pubkey: pubkey_b58.to_string(),
         signature: signature_b58.to_string(),
         valid: true,
+        candidate_index: None,
+    })
+}
+
+/// Verify a Base58 signature against several candidate public keys.
+///
+/// Ed25519 cannot recover the signer, so each candidate is tried in order and the
+/// first one that validates is reported. Fails with `VerificationFailed` when none
+/// matches; a malformed candidate or signature is an error.
+pub fn handle_candidates(
+    message: &str,
+    signature_b58: &str,
+    candidates: &[String],
+) -> Result<VerifyResult> {
+    for (index, pubkey_b58) in candidates.iter().enumerate() {
+        match verify_signature_raw(message, signature_b58, pubkey_b58) {
+            Ok(()) => {
+                return Ok(VerifyResult {
+                    message: message.to_string(),
+                    pubkey: pubkey_b58.clone(),
+                    signature: signature_b58.to_string(),
+                    valid: true,
+                    candidate_index: Some(index),
+                });
+            }
+            Err(VerifyError::VerificationFailed) => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Err(ToolError::Verify(VerifyError::VerificationFailed))
+}
+
+/// Verify the signature slot of `pubkey_b58` in a transaction.
+///
+/// Finds the signer's index among the required signers and checks its signature against
+/// the message signing bytes. A key outside the signer region is reported as
+/// `NotASigner`; only a malformed public key is an error.
+pub fn handle_transaction(tx: &Transaction, pubkey_b58: &str) -> Result<VerifyTxResult> {
+    let pubkey = PubkeyBase58::try_from(pubkey_b58)?;
+
+    let (signer_index, status) = match tx.verify_signer(&pubkey) {
+        Some((index, SignatureStatus::Valid)) => (Some(index), VerifyTxStatus::Valid),
+        Some((index, SignatureStatus::Invalid)) => (Some(index), VerifyTxStatus::Invalid),
+        Some((index, SignatureStatus::Unsigned)) => (Some(index), VerifyTxStatus::Unsigned),
+        None => (None, VerifyTxStatus::NotASigner),
+    };
+
+    Ok(VerifyTxResult {
+        pubkey: pubkey.to_string(),
+        signer_index,
+        status,
     })
 }
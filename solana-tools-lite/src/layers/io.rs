@@ -6,6 +6,9 @@ use std::os::unix::fs::PermissionsExt;
 
 const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024; // 10 MB limit for safety
 
+/// Default cap for text read from stdin (4 MB); a pipe has no length to check up front.
+pub const DEFAULT_MAX_STDIN_SIZE: u64 = 4 * 1024 * 1024;
+
 /// Reads all UTF-8 text from a file path.
 pub fn read_from_file(path: &Path) -> Result<String, io::Error> {
     let metadata = std::fs::metadata(path)?;
@@ -18,10 +21,47 @@ pub fn read_from_file(path: &Path) -> Result<String, io::Error> {
     std::fs::read_to_string(path)
 }
 
-/// Reads all UTF-8 text from stdin.
-pub fn read_from_stdin() -> Result<String, io::Error> {
+/// Reads all UTF-8 text from stdin, up to `limit` bytes.
+pub fn read_from_stdin(limit: u64) -> Result<String, io::Error> {
+    read_to_string_limited(io::stdin().lock(), limit)
+}
+
+/// Reads all UTF-8 text from `reader`, failing with `InvalidData` once more than
+/// `limit` bytes arrive. Never buffers more than `limit + 1` bytes.
+pub fn read_to_string_limited<R: Read>(reader: R, limit: u64) -> Result<String, io::Error> {
     let mut buf = String::new();
-    io::stdin().read_to_string(&mut buf)?;
+    reader.take(limit.saturating_add(1)).read_to_string(&mut buf)?;
+    if buf.len() as u64 > limit {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("input too large: more than {} bytes", limit),
+        ));
+    }
+    Ok(buf)
+}
+
+/// Reads all bytes from a file path (no UTF-8 requirement).
+pub fn read_bytes_from_file(path: &Path) -> Result<Vec<u8>, io::Error> {
+    let metadata = std::fs::metadata(path)?;
+    if metadata.len() > MAX_FILE_SIZE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("file too large: {} bytes (max {} MB)", metadata.len(), MAX_FILE_SIZE / 1024 / 1024),
+        ));
+    }
+    std::fs::read(path)
+}
+
+/// Reads all bytes from stdin, up to `limit` bytes.
+pub fn read_bytes_from_stdin(limit: u64) -> Result<Vec<u8>, io::Error> {
+    let mut buf = Vec::new();
+    io::stdin().lock().take(limit.saturating_add(1)).read_to_end(&mut buf)?;
+    if buf.len() as u64 > limit {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("input too large: more than {} bytes", limit),
+        ));
+    }
     Ok(buf)
 }
 
@@ -51,3 +91,100 @@ pub fn write_to_file(path: &Path, data: &str, perms: u32, force: bool) -> Result
 
     Ok(())
 }
+
+/// Writes a file atomically: the content goes to a sibling temp file which is then
+/// renamed over `path`, so a failure part-way never leaves a truncated target.
+/// - `fill` writes the content; any error it returns aborts before the rename
+/// - If file exists and `force == false`, returns AlreadyExists (the target is never replaced)
+/// - On Unix, the temp file is created with `perms` and keeps them after the rename
+/// - The temp file is removed on every error path
+pub fn write_atomic<F>(path: &Path, perms: u32, force: bool, fill: F) -> Result<(), io::Error>
+where
+    F: FnOnce(&mut std::fs::File) -> Result<(), io::Error>,
+{
+    use std::fs::{self, OpenOptions};
+
+    if !force && path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "file already exists",
+        ));
+    }
+
+    let tmp = temp_sibling_path(path)?;
+
+    let mut opts = OpenOptions::new();
+    opts.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        opts.mode(perms);
+    }
+    #[cfg(not(unix))]
+    let _ = perms;
+
+    let mut file = opts.open(&tmp)?;
+    let written = fill(&mut file).and_then(|_| file.sync_all());
+    drop(file);
+
+    let result = written.and_then(|_| commit_temp(&tmp, path, force));
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
+/// True when `path` exists and is neither a regular file nor a directory (FIFO, character device).
+pub fn is_special_file(path: &Path) -> bool {
+    std::fs::metadata(path)
+        .map(|meta| !meta.is_file() && !meta.is_dir())
+        .unwrap_or(false)
+}
+
+/// Writes into an existing non-regular file such as a FIFO.
+/// - Opens the path for writing only: nothing is created, truncated, renamed or chmod-ed
+/// - `force` does not apply; the consumer on the other end decides what happens to the data
+/// - Blocks until a reader opens the FIFO
+pub fn write_to_special_file(path: &Path, data: &[u8]) -> Result<(), io::Error> {
+    use std::fs::OpenOptions;
+    use std::io::Write;
+
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    file.write_all(data)?;
+    file.flush()
+}
+
+/// Builds `<dir>/.<name>.<random>.tmp` next to `path` so the final rename stays on one filesystem.
+fn temp_sibling_path(path: &Path) -> Result<std::path::PathBuf, io::Error> {
+    let name = path.file_name().ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, "output path has no file name")
+    })?;
+    let dir = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    };
+    let mut tmp_name = std::ffi::OsString::from(".");
+    tmp_name.push(name);
+    tmp_name.push(format!(".{:016x}.tmp", rand::random::<u64>()));
+    Ok(dir.join(tmp_name))
+}
+
+/// Moves the finished temp file into place.
+/// Without `force` a hard link is used so an existing target is never clobbered,
+/// falling back to rename where the filesystem has no hard links.
+fn commit_temp(tmp: &Path, path: &Path, force: bool) -> Result<(), io::Error> {
+    use std::fs;
+
+    if force {
+        return fs::rename(tmp, path);
+    }
+    match fs::hard_link(tmp, path) {
+        Ok(()) => fs::remove_file(tmp),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Err(e),
+        Err(_) if path.exists() => Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "file already exists",
+        )),
+        Err(_) => fs::rename(tmp, path),
+    }
+}
@@ -12,6 +12,7 @@ pub mod utils;
 
 pub mod codec;
 pub mod constants;
+pub mod portable;
 pub mod serde;
 
 // Re-exports for CLI and external consumers to minimize their Cargo.toml
@@ -28,11 +29,16 @@ pub use crate::errors::{Result, ToolError};
 /// Unified analysis facade.
 pub mod analysis {
     pub use crate::handlers::analysis::{
-        analyze_transaction, analyze_input_transaction, build_signing_summary
+        AnalysisConfig, analyze_fast_path, analyze_transaction, analyze_transaction_full,
+        analyze_transaction_with, analyze_input_transaction, build_signing_summary, is_account_writable
     };
+    #[cfg(feature = "timing")]
+    pub use crate::handlers::analysis::analyze_transaction_timed;
     pub use crate::serde::parse_lookup_tables;
     /// Analysis models for transaction inspection and summaries.
     pub use crate::models::analysis::{
-        AnalysisWarning, SigningSummary, TokenProgramKind, TransferView, TxAnalysis,
+        AnalysisWarning, AnchorAction, CreatedAccount, ExtensionActionView, PrecompileKind, SerializableTxAnalysis, Severity,
+        SigningSummary, TokenAuthorityType, TokenProgramKind, TokenSetupAction, TransactionShape,
+        TransferView, TxAnalysis, UnknownInstruction, WsolUnwrap,
     };
 }
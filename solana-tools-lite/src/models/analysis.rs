@@ -1,6 +1,8 @@
-use crate::extensions::analysis::AnalysisExtensionAction;
+use crate::extensions::analysis::{AnalysisExtensionAction, PrivacyImpact};
+use crate::models::lamports::Lamports;
 use crate::models::pubkey_base58::PubkeyBase58;
 use serde::Serialize;
+use std::collections::BTreeMap;
 
 /// Transaction analysis output used by CLI and other front-ends.
 #[derive(Debug)]
@@ -10,10 +12,15 @@ pub struct TxAnalysis {
     pub priority_fee_lamports: Option<(u128, bool)>, // (fee, estimated)
     pub total_fee_lamports: u128,
     pub total_sol_send_by_signer: u128,
+    /// Lamports sent by the signer, summed per recipient (Base58).
+    pub signer_outflows_by_recipient: BTreeMap<String, u64>,
     pub compute_unit_limit: Option<u32>,
+    /// Compute unit price in micro-lamports (10^-6 lamport) per CU.
     pub compute_unit_price_micro: Option<u64>,
     pub warnings: Vec<AnalysisWarning>,
     pub message_version: &'static str,
+    /// Recent blockhash of the message (Base58).
+    pub recent_blockhash: String,
     /// Privacy level of this transaction based on detected confidential operations
     pub privacy_level: PrivacyLevel,
     /// Actions detected by protocol extensions (e.g. Light Protocol).
@@ -28,10 +35,55 @@ pub struct TxAnalysis {
     pub is_fee_payer: bool,
     /// Whether non-SOL assets (SPL/Token-2022) are involved in movement.
     pub has_non_sol_assets: bool,
+    /// Raw instructions for programs nothing could decode, trusted programs excluded (bounded).
+    pub unknown_instructions: Vec<UnknownInstruction>,
+    /// Account, signature and instruction counts of the message.
+    pub shape: TransactionShape,
+    /// Token mints and accounts initialized by the transaction.
+    pub token_setup: Vec<TokenSetupAction>,
+    /// Instructions of registered Anchor programs, by IDL name.
+    pub anchor_actions: Vec<AnchorAction>,
+    /// Accounts created with System `CreateAccount`.
+    pub created_accounts: Vec<CreatedAccount>,
+    /// Token `CloseAccount`s on wrapped SOL accounts (SOL returned to the destination).
+    pub wsol_unwraps: Vec<WsolUnwrap>,
+}
+
+/// Empty analysis of a legacy message: no fees, transfers or warnings, `Public` privacy.
+/// Starting point for analyzers run outside `analyze_transaction`.
+impl Default for TxAnalysis {
+    fn default() -> Self {
+        Self {
+            transfers: Vec::new(),
+            base_fee_lamports: 0,
+            priority_fee_lamports: None,
+            total_fee_lamports: 0,
+            total_sol_send_by_signer: 0,
+            signer_outflows_by_recipient: BTreeMap::new(),
+            compute_unit_limit: None,
+            compute_unit_price_micro: None,
+            warnings: Vec::new(),
+            message_version: "legacy",
+            recent_blockhash: String::new(),
+            privacy_level: PrivacyLevel::Public,
+            extension_actions: Vec::new(),
+            extension_notices: Vec::new(),
+            confidential_ops_count: 0,
+            storage_ops_count: 0,
+            is_fee_payer: false,
+            has_non_sol_assets: false,
+            unknown_instructions: Vec::new(),
+            shape: TransactionShape::default(),
+            token_setup: Vec::new(),
+            anchor_actions: Vec::new(),
+            created_accounts: Vec::new(),
+            wsol_unwraps: Vec::new(),
+        }
+    }
 }
 
 impl TxAnalysis {
-    /// Removes UnknownProgram warnings for the given list of known programs.
+    /// Removes UnknownProgram warnings (and raw unknown instructions) for the given list of known programs.
     pub fn resolve_unknown_programs(&mut self, known_programs: &[PubkeyBase58]) {
         self.warnings.retain(|w| {
             match w {
@@ -41,34 +93,265 @@ impl TxAnalysis {
                 _ => true
             }
         });
+        self.unknown_instructions.retain(|ix| {
+            !known_programs.iter().any(|pk| pk.to_string() == ix.program_id)
+        });
+    }
+
+    /// Removes `SignerNotRequired` and `SignerNotReferencedByInstructions`, for analyzing
+    /// as a placeholder key that is not expected to sign (`--ignore-signer-check`).
+    pub fn drop_signer_check_warnings(&mut self) {
+        self.warnings.retain(|w| {
+            !matches!(
+                w,
+                AnalysisWarning::SignerNotRequired
+                    | AnalysisWarning::SignerNotReferencedByInstructions
+            )
+        });
+    }
+
+    /// Lamports the signer wrapped in this transaction that an unwrap sends back to it.
+    pub fn sol_returned_to_signer(&self) -> u128 {
+        self.wsol_unwraps
+            .iter()
+            .filter(|unwrap| unwrap.to_signer)
+            .map(|unwrap| unwrap.wrapped_lamports as u128)
+            .sum()
+    }
+
+    /// `total_sol_send_by_signer` net of `sol_returned_to_signer`: wrapping SOL and
+    /// unwrapping it back to yourself in the same transaction is not a send.
+    pub fn net_sol_send_by_signer(&self) -> u128 {
+        self.total_sol_send_by_signer
+            .saturating_sub(self.sol_returned_to_signer())
+    }
+
+    /// JSON-ready projection; extension actions are flattened to `ExtensionActionView`s.
+    pub fn to_serializable(&self) -> SerializableTxAnalysis<'_> {
+        let (priority_fee_lamports, priority_fee_estimated) = match self.priority_fee_lamports {
+            Some((fee, estimated)) => (Some(fee), estimated),
+            None => (None, false),
+        };
+        SerializableTxAnalysis {
+            message_version: self.message_version,
+            recent_blockhash: &self.recent_blockhash,
+            transfers: &self.transfers,
+            base_fee_lamports: self.base_fee_lamports,
+            priority_fee_lamports,
+            priority_fee_estimated,
+            total_fee_lamports: self.total_fee_lamports,
+            total_sol_send_by_signer: self.total_sol_send_by_signer,
+            signer_outflows_by_recipient: &self.signer_outflows_by_recipient,
+            compute_unit_limit: self.compute_unit_limit,
+            compute_unit_price_micro: self.compute_unit_price_micro,
+            warnings: &self.warnings,
+            privacy_level: self.privacy_level,
+            extension_actions: self
+                .extension_actions
+                .iter()
+                .map(|action| ExtensionActionView {
+                    instruction_index: action.instruction_index(),
+                    protocol: action.protocol_name(),
+                    action: action.action_name(),
+                    description: action.description(),
+                    privacy_impact: action.privacy_impact(),
+                })
+                .collect(),
+            extension_notices: &self.extension_notices,
+            confidential_ops_count: self.confidential_ops_count,
+            storage_ops_count: self.storage_ops_count,
+            is_fee_payer: self.is_fee_payer,
+            has_non_sol_assets: self.has_non_sol_assets,
+            unknown_instructions: &self.unknown_instructions,
+            token_setup: &self.token_setup,
+            anchor_actions: &self.anchor_actions,
+            created_accounts: &self.created_accounts,
+            wsol_unwraps: &self.wsol_unwraps,
+            shape: &self.shape,
+        }
+    }
+
+    /// Whether any warning is classified as `Severity::Critical`.
+    pub fn has_critical_warnings(&self) -> bool {
+        self.warnings
+            .iter()
+            .any(|w| w.severity() == Severity::Critical)
+    }
+
+    /// Highest severity among the warnings, `None` when there are none.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.warnings.iter().map(AnalysisWarning::severity).max()
+    }
+
+    /// Process exit code for scripts (`--exit-code-by-severity`): `EXIT_CODE_CLEAN` without
+    /// warnings, otherwise `Severity::exit_code` of the highest severity present.
+    pub fn severity_exit_code(&self) -> i32 {
+        self.max_severity()
+            .map_or(EXIT_CODE_CLEAN, Severity::exit_code)
     }
 }
 
-#[derive(Debug, Clone)]
+/// Serializable view of a `TxAnalysis` (see `TxAnalysis::to_serializable`).
+#[derive(Debug, Serialize)]
+pub struct SerializableTxAnalysis<'a> {
+    pub message_version: &'static str,
+    pub recent_blockhash: &'a str,
+    pub transfers: &'a [TransferView],
+    pub base_fee_lamports: u128,
+    pub priority_fee_lamports: Option<u128>,
+    pub priority_fee_estimated: bool,
+    pub total_fee_lamports: u128,
+    pub total_sol_send_by_signer: u128,
+    pub signer_outflows_by_recipient: &'a BTreeMap<String, u64>,
+    pub compute_unit_limit: Option<u32>,
+    pub compute_unit_price_micro: Option<u64>,
+    pub warnings: &'a [AnalysisWarning],
+    pub privacy_level: PrivacyLevel,
+    pub extension_actions: Vec<ExtensionActionView>,
+    pub extension_notices: &'a [String],
+    pub confidential_ops_count: usize,
+    pub storage_ops_count: usize,
+    pub is_fee_payer: bool,
+    pub has_non_sol_assets: bool,
+    pub unknown_instructions: &'a [UnknownInstruction],
+    pub token_setup: &'a [TokenSetupAction],
+    pub anchor_actions: &'a [AnchorAction],
+    pub created_accounts: &'a [CreatedAccount],
+    pub wsol_unwraps: &'a [WsolUnwrap],
+    pub shape: &'a TransactionShape,
+}
+
+/// Structured form of an extension action (protocol, action name, description, privacy).
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ExtensionActionView {
+    /// Instruction the action was decoded from, if the analyzer recorded it.
+    pub instruction_index: Option<usize>,
+    pub protocol: &'static str,
+    pub action: &'static str,
+    pub description: String,
+    pub privacy_impact: PrivacyImpact,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct TransferView {
     pub from: String,
     pub to: String,
     pub lamports: u64,
     pub from_is_signer: bool,
+    pub from_writable: bool,
+    pub to_writable: bool,
+    /// Recipient is account #0 (the fee payer)
+    pub to_is_fee_payer: bool,
+}
+
+/// Basic size metrics of a message, taken from its header and lists.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct TransactionShape {
+    /// Static account keys plus addresses loaded through lookup tables.
+    pub account_count: usize,
+    pub static_account_count: usize,
+    pub num_required_signatures: u8,
+    pub num_readonly_signed_accounts: u8,
+    pub num_readonly_unsigned_accounts: u8,
+    pub instruction_count: usize,
+    /// Address lookup tables referenced by a v0 message (0 for legacy).
+    pub lookup_table_count: usize,
+}
+
+/// An instruction whose program was not decoded, kept for offline investigation.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct UnknownInstruction {
+    /// Program id (Base58).
+    pub program_id: String,
+    /// Raw instruction data (Base64).
+    pub data_base64: String,
+}
+
+/// A Token / Token-2022 mint or account initialization (context, not a warning).
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub enum TokenSetupAction {
+    /// `InitializeMint` / `InitializeMint2`.
+    InitializeMint {
+        mint: PubkeyBase58,
+        decimals: u8,
+        mint_authority: PubkeyBase58,
+        freeze_authority: Option<PubkeyBase58>,
+    },
+    /// `InitializeAccount` / `InitializeAccount3`.
+    InitializeAccount {
+        account: PubkeyBase58,
+        mint: PubkeyBase58,
+        owner: PubkeyBase58,
+    },
+}
+
+/// An instruction of a registered Anchor program, matched by its discriminator.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct AnchorAction {
+    pub program: PubkeyBase58,
+    /// Instruction name from the program's IDL.
+    pub ix_name: String,
+}
+
+/// A System `CreateAccount`: what it funds next to what rent exemption requires.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct CreatedAccount {
+    pub account: PubkeyBase58,
+    pub funder: PubkeyBase58,
+    /// Program that will own the account.
+    pub owner: PubkeyBase58,
+    /// Data bytes allocated.
+    pub space: u64,
+    /// Lamports the instruction moves into the account.
+    pub lamports: u64,
+    /// Estimated rent-exempt minimum for `space` (see `analysis::AnalysisConfig::rent`).
+    pub rent_exempt_minimum: u64,
+}
+
+/// A Token `CloseAccount` on a wrapped SOL (native mint) account: its whole balance,
+/// rent included, goes to `destination` as SOL.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct WsolUnwrap {
+    pub account: PubkeyBase58,
+    pub destination: PubkeyBase58,
+    /// Lamports the signer moved into the account earlier in this transaction; any prior
+    /// balance is not visible offline.
+    pub wrapped_lamports: u64,
+    /// `destination` is the analyzed signer.
+    pub to_signer: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct SigningSummary {
     pub message_version: String,
+    /// Recent blockhash of the message (Base58), to match against "blockhash not found" errors.
+    pub recent_blockhash: String,
     pub signatures: Vec<String>,
+    /// Transaction id: the first signature (Base58), `None` while it is unsigned.
+    pub transaction_id: Option<String>,
     pub signed_tx_base64: String,
-    pub base_fee_lamports: u64,
-    pub priority_fee_lamports: u64,
+    pub base_fee_lamports: Lamports,
+    pub priority_fee_lamports: Lamports,
     pub priority_fee_estimated: bool,
     pub fee_is_estimate: bool,
+    /// Compute unit price in micro-lamports (10^-6 lamport) per CU, as set by Compute Budget.
     pub compute_unit_price_micro: Option<u64>,
+    /// The same price in lamports per CU as a decimal string (e.g. `"0.01"`).
+    pub compute_unit_price_lamports_per_cu: Option<String>,
     pub compute_unit_limit: Option<u32>,
-    pub total_fee_lamports: u64,
-    pub total_sol_send_by_signer: u64,
-    pub max_total_cost_lamports: u64,
+    pub total_fee_lamports: Lamports,
+    pub total_sol_send_by_signer: Lamports,
+    pub signer_outflows_by_recipient: BTreeMap<String, u64>,
+    pub max_total_cost_lamports: Lamports,
     pub is_fee_payer: bool,
     pub has_non_sol_assets: bool,
     pub warnings: Vec<AnalysisWarning>,
+    pub unknown_instructions: Vec<UnknownInstruction>,
+    pub shape: TransactionShape,
+    pub token_setup: Vec<TokenSetupAction>,
+    pub anchor_actions: Vec<AnchorAction>,
+    pub created_accounts: Vec<CreatedAccount>,
+    pub wsol_unwraps: Vec<WsolUnwrap>,
     /// Extension actions serialized as descriptions (not the full objects)
     pub extension_actions: Vec<String>,
     pub extension_notices: Vec<String>,
@@ -80,12 +363,176 @@ pub struct SigningSummary {
 pub enum AnalysisWarning {
     LookupTableMissing(PubkeyBase58),
     LookupTableNotProvided,
+    /// The tables file declares `table`, but no address table lookup of the transaction
+    /// references it (likely a mismatched `--tables` file).
+    UnusedLookupTable { table: PubkeyBase58 },
     TokenTransferDetected(TokenProgramKind),
+    /// Token `CloseAccount` sends the reclaimed rent to an account other than the signer.
+    TokenAccountClosedToOther { destination: PubkeyBase58 },
+    /// Token `SetAuthority` hands `authority_type` to the signer itself.
+    SetAuthorityToSelf { authority_type: TokenAuthorityType },
+    /// Token `SetAuthority` hands `authority_type` to a third party.
+    SetAuthorityToOther {
+        authority_type: TokenAuthorityType,
+        new_authority: PubkeyBase58,
+    },
+    /// Token `Approve` lets `delegate` move up to `amount` base units out of a token account.
+    TokenDelegateApproved { delegate: PubkeyBase58, amount: u64 },
     UnknownProgram { program_id: PubkeyBase58 },
     SignerNotRequired,
+    /// The signer is required by the header but no instruction references its account:
+    /// it only pays the fee, or is a dummy signer.
+    SignerNotReferencedByInstructions,
+    /// A System `CreateAccount` creates the signer's own account (to be owned by `owner`)
+    /// and a later instruction debits it: the account you sign for is not a wallet you hold.
+    SignerAccountCreatedAndDebited { owner: PubkeyBase58 },
+    /// The signer resolves only through an address lookup table, so it can never sign.
+    SignerFromLookupTable,
+    /// The required signer key at `index` is not on the Ed25519 curve (e.g. a PDA) and cannot sign.
+    SignerKeyOffCurve { index: usize },
+    /// The fee payer (first account) is off the Ed25519 curve, likely a PDA: it can never
+    /// sign, so nobody can pay the fee. Reported instead of `SignerKeyOffCurve { index: 0 }`.
+    FeePayerNotSignable { fee_payer: PubkeyBase58 },
+    /// The static account at `index` contradicts the header's ordering (writable signers,
+    /// readonly signers, writable non-signers, readonly non-signers).
+    AccountOrderingSuspect { index: usize },
+    /// The caller's deadline passed; instructions or extensions after that point were skipped.
+    AnalysisTruncatedByDeadline,
     CpiLimit,
     ConfidentialTransferDetected,
     MalformedInstruction,
+    RepeatedInstruction { count: usize },
+    /// The serialized transaction (`size` bytes) exceeds the 1232-byte packet limit.
+    TransactionTooLarge { size: usize },
+    /// The transaction embeds a signature verification precompile instruction.
+    PrecompileSignatureInstruction { kind: PrecompileKind },
+    /// Stake `Withdraw` sends `lamports` to `recipient`, which is neither the signer nor the withdraw authority.
+    StakeWithdrawToOther { recipient: PubkeyBase58, lamports: u64 },
+    /// A compute unit price is set without a limit, so the priority fee assumes `assumed_limit` units.
+    PriorityFeeEstimated { assumed_limit: u32 },
+    /// A known multisig program (e.g. Squads) is invoked; the signature may be an approval
+    /// for a vault (PDA) transaction rather than a direct execution.
+    MultisigProgramDetected { program: PubkeyBase58 },
+    /// Instruction #`instruction_index` passes no accounts (unusual outside Compute Budget
+    /// and the signature precompiles).
+    InstructionNoAccounts { instruction_index: usize },
+    /// `key` appears more than once in the resolved account list (static keys plus lookup
+    /// table accounts); the network rejects such messages and index-based checks may mislead.
+    DuplicateAccountKey { key: PubkeyBase58 },
+    /// Instruction #`instruction_index` carries `data_len` bytes of data; decoders scanned
+    /// only the first `scanned_len` (see `analysis::AnalysisConfig::max_scanned_data_len`).
+    InstructionDataTruncatedForAnalysis {
+        instruction_index: usize,
+        data_len: usize,
+        scanned_len: usize,
+    },
+}
+
+/// How serious a warning is; ordered from least to most severe.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Context for the user; nothing suspicious by itself.
+    Info,
+    /// Analysis is incomplete or something deserves a closer look.
+    Warn,
+    /// Likely loss of funds or a signature that cannot be valid.
+    Critical,
+}
+
+/// Exit code of an analysis without warnings (see `TxAnalysis::severity_exit_code`).
+pub const EXIT_CODE_CLEAN: i32 = 0;
+
+impl Severity {
+    /// Stable exit code when this is the highest severity present:
+    /// 3 = info only, 4 = warn, 5 = critical. Codes stay clear of 1 (generic failure),
+    /// 2 (clap usage errors) and the sysexits range used for errors.
+    pub const fn exit_code(self) -> i32 {
+        match self {
+            Self::Info => 3,
+            Self::Warn => 4,
+            Self::Critical => 5,
+        }
+    }
+}
+
+impl AnalysisWarning {
+    /// Classify this warning for presenters and confirmation prompts.
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::SignerNotRequired
+            | Self::SignerFromLookupTable
+            | Self::SignerKeyOffCurve { .. }
+            | Self::FeePayerNotSignable { .. }
+            | Self::TokenAccountClosedToOther { .. }
+            | Self::TokenDelegateApproved { .. }
+            | Self::SetAuthorityToOther { .. }
+            | Self::StakeWithdrawToOther { .. }
+            | Self::SignerAccountCreatedAndDebited { .. }
+            | Self::RepeatedInstruction { .. } => Severity::Critical,
+            Self::LookupTableMissing(_)
+            | Self::LookupTableNotProvided
+            | Self::UnknownProgram { .. }
+            | Self::SignerNotReferencedByInstructions
+            | Self::TransactionTooLarge { .. }
+            | Self::AccountOrderingSuspect { .. }
+            | Self::DuplicateAccountKey { .. }
+            | Self::InstructionDataTruncatedForAnalysis { .. }
+            | Self::AnalysisTruncatedByDeadline
+            | Self::MalformedInstruction => Severity::Warn,
+            Self::TokenTransferDetected(_)
+            | Self::CpiLimit
+            | Self::ConfidentialTransferDetected
+            | Self::PrecompileSignatureInstruction { .. }
+            | Self::SetAuthorityToSelf { .. }
+            | Self::PriorityFeeEstimated { .. }
+            | Self::MultisigProgramDetected { .. }
+            | Self::InstructionNoAccounts { .. }
+            | Self::UnusedLookupTable { .. } => Severity::Info,
+        }
+    }
+
+    /// Key of the deterministic warning order (`TxAnalysis::sort_warnings`): most severe
+    /// first, then by kind, then by detail.
+    pub fn order_key(&self) -> (std::cmp::Reverse<Severity>, usize, String) {
+        (std::cmp::Reverse(self.severity()), self.kind_index(), format!("{:?}", self))
+    }
+
+    /// Fixed index of the variant, the tie-breaker after severity. Indices follow the order
+    /// variants were added, not their declaration order; a new variant takes the next free
+    /// index so existing sorted outputs keep their order.
+    fn kind_index(&self) -> usize {
+        match self {
+            Self::LookupTableMissing(_) => 0,
+            Self::LookupTableNotProvided => 1,
+            Self::TokenTransferDetected(_) => 2,
+            Self::TokenAccountClosedToOther { .. } => 3,
+            Self::SetAuthorityToSelf { .. } => 4,
+            Self::SetAuthorityToOther { .. } => 5,
+            Self::TokenDelegateApproved { .. } => 6,
+            Self::UnknownProgram { .. } => 7,
+            Self::SignerNotRequired => 8,
+            Self::SignerFromLookupTable => 9,
+            Self::SignerKeyOffCurve { .. } => 10,
+            Self::AccountOrderingSuspect { .. } => 11,
+            Self::AnalysisTruncatedByDeadline => 12,
+            Self::CpiLimit => 13,
+            Self::ConfidentialTransferDetected => 14,
+            Self::MalformedInstruction => 15,
+            Self::RepeatedInstruction { .. } => 16,
+            Self::TransactionTooLarge { .. } => 17,
+            Self::PrecompileSignatureInstruction { .. } => 18,
+            Self::PriorityFeeEstimated { .. } => 19,
+            Self::StakeWithdrawToOther { .. } => 20,
+            Self::MultisigProgramDetected { .. } => 21,
+            Self::InstructionNoAccounts { .. } => 22,
+            Self::DuplicateAccountKey { .. } => 23,
+            Self::InstructionDataTruncatedForAnalysis { .. } => 24,
+            Self::FeePayerNotSignable { .. } => 25,
+            Self::UnusedLookupTable { .. } => 26,
+            Self::SignerNotReferencedByInstructions => 27,
+            Self::SignerAccountCreatedAndDebited { .. } => 28,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
@@ -95,6 +542,36 @@ pub enum TokenProgramKind {
     AssociatedToken,
 }
 
+/// Authority kinds of the Token `SetAuthority` instruction.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+pub enum TokenAuthorityType {
+    MintTokens,
+    FreezeAccount,
+    AccountOwner,
+    CloseAccount,
+    /// Token-2022 extension authorities, kept as the raw discriminant.
+    Other(u8),
+}
+
+impl From<u8> for TokenAuthorityType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::MintTokens,
+            1 => Self::FreezeAccount,
+            2 => Self::AccountOwner,
+            3 => Self::CloseAccount,
+            other => Self::Other(other),
+        }
+    }
+}
+
+/// Native signature verification precompiles.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+pub enum PrecompileKind {
+    Ed25519,
+    Secp256k1,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
 pub enum PrivacyLevel {
     /// Fully transparent transaction on the public ledger.
@@ -134,6 +611,25 @@ impl PrivacyLevel {
 }
 
 impl TxAnalysis {
+    /// Order warnings deterministically: most severe first, then by kind, then by detail.
+    ///
+    /// Detection order depends on pipeline internals; this order only depends on the
+    /// warnings themselves, so outputs stay diffable across versions.
+    pub fn sort_warnings(&mut self) {
+        self.warnings.sort_by_cached_key(AnalysisWarning::order_key);
+    }
+
+    /// Privacy impact contributed by each instruction, in action order.
+    ///
+    /// Only actions that carry an instruction index are listed (see
+    /// `AnalysisExtensionAction::at_instruction`).
+    pub fn privacy_impacts_by_instruction(&self) -> Vec<(usize, PrivacyImpact)> {
+        self.extension_actions
+            .iter()
+            .filter_map(|action| Some((action.instruction_index()?, action.privacy_impact())))
+            .collect()
+    }
+
     /// Recalculates the privacy level based on current metrics and extension actions.
     pub fn recalculate_privacy_level(&mut self) {
         use crate::extensions::analysis::PrivacyImpact;
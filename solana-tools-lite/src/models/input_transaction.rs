@@ -17,6 +17,17 @@ pub enum InputTransaction {
     Json(UiTransaction),
 }
 
+/// Input encodings a caller can force instead of auto-detection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputFormat {
+    /// JSON `UiTransaction` object.
+    Json,
+    /// Raw wire bytes encoded as Base64.
+    Base64,
+    /// Base58 text (see `InputTransaction::Base58`).
+    Base58,
+}
+
 /// Output formats for a serialized Solana transaction.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum OutputFormat {
@@ -43,7 +54,7 @@ impl InputTransaction {
 ///
 /// This struct corresponds to the standard JSON representation of transactions
 /// often used in API responses and CLI outputs.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UiTransaction {
     /// List of signatures (Base58 strings).
     pub signatures: Vec<String>,
@@ -51,7 +62,7 @@ pub struct UiTransaction {
     pub message: UiRawMessage,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum UiRawMessage {
     /// Legacy message layout without address table lookups.
@@ -60,7 +71,7 @@ pub enum UiRawMessage {
     V0(UiRawMessageV0),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 /// Legacy message fields for JSON representation.
@@ -73,7 +84,7 @@ pub struct UiRawMessageLegacy {
     pub instructions: Vec<UiCompiledInstruction>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 /// V0 message fields for JSON representation.
 pub struct UiRawMessageV0 {
@@ -87,7 +98,7 @@ pub struct UiRawMessageV0 {
     pub address_table_lookups: Vec<UiAddressTableLookup>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 /// Address lookup table entry in JSON form.
 pub struct UiAddressTableLookup {
@@ -96,7 +107,7 @@ pub struct UiAddressTableLookup {
     pub readonly_indexes: Vec<u8>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 /// Compiled instruction fields in JSON form.
 pub struct UiCompiledInstruction {
@@ -106,7 +117,7 @@ pub struct UiCompiledInstruction {
     pub data: String,
 }
 
-use crate::errors::TransactionParseError;
+use crate::errors::{ToolError, TransactionParseError};
 use crate::models::instruction::Instruction;
 use crate::models::pubkey_base58::PubkeyBase58;
 use bs58;
@@ -157,13 +168,75 @@ fn map_common_parts(
     Ok((keys, blockhash, inst))
 }
 
+impl UiTransaction {
+    /// Return a canonical copy suitable for hashing or diffing.
+    ///
+    /// Surrounding whitespace is trimmed, empty or missing signatures become the
+    /// all-zero placeholder, and keys, blockhash and instruction data are
+    /// validated and re-encoded from their decoded bytes.
+    pub fn canonicalize(&self) -> Result<UiTransaction, ToolError> {
+        // Empty and missing signature slots become the placeholder in the conversion
+        let signatures = self.signatures.clone();
+
+        let mut message = self.message.clone();
+        message.trim_strings();
+
+        let tx = Transaction::try_from(&UiTransaction { signatures, message })?;
+        Ok(UiTransaction::from(&tx))
+    }
+}
+
+impl UiRawMessage {
+    /// Number of signatures the header requires.
+    pub fn num_required_signatures(&self) -> usize {
+        self.header().num_required_signatures as usize
+    }
+
+    fn header(&self) -> &MessageHeader {
+        match self {
+            UiRawMessage::Legacy(msg) => &msg.header,
+            UiRawMessage::V0(msg) => &msg.header,
+        }
+    }
+
+    fn trim_strings(&mut self) {
+        fn trim(value: &mut String) {
+            let trimmed = value.trim();
+            if trimmed.len() != value.len() {
+                *value = trimmed.to_string();
+            }
+        }
+
+        let (account_keys, recent_blockhash, instructions) = match self {
+            UiRawMessage::Legacy(msg) => {
+                (&mut msg.account_keys, &mut msg.recent_blockhash, &mut msg.instructions)
+            }
+            UiRawMessage::V0(msg) => {
+                msg.address_table_lookups
+                    .iter_mut()
+                    .for_each(|lut| trim(&mut lut.account_key));
+                (&mut msg.account_keys, &mut msg.recent_blockhash, &mut msg.instructions)
+            }
+        };
+        account_keys.iter_mut().for_each(trim);
+        trim(recent_blockhash);
+        instructions.iter_mut().for_each(|ix| trim(&mut ix.data));
+    }
+}
+
+/// Missing signer slots are represented by the all-zero signature on both sides of the
+/// `Transaction` <-> `UiTransaction` conversion, so the count matches the header.
 impl From<&Transaction> for UiTransaction {
     fn from(tx: &Transaction) -> Self {
-        let signatures = tx
+        let required = tx.message.header().num_required_signatures as usize;
+        let mut signatures: Vec<String> = tx
             .signatures
             .iter()
             .map(|sig| bs58::encode(sig.to_bytes()).into_string())
             .collect();
+        if signatures.len() < required {
+            signatures.resize(required, bs58::encode([0u8; 64]).into_string());
+        }
 
         let message = match &tx.message {
             Message::Legacy(msg) => UiRawMessage::Legacy(UiRawMessageLegacy {
@@ -224,11 +297,15 @@ impl TryFrom<&UiTransaction> for Transaction {
     type Error = TransactionParseError;
 
     fn try_from(ui: &UiTransaction) -> Result<Self, Self::Error> {
-        let signatures = ui
+        let mut signatures = ui
             .signatures
             .iter()
             .map(|s| {
-                let bytes = bs58::decode(s).into_vec().map_err(|e| {
+                // Empty strings mark unsigned slots
+                if s.trim().is_empty() {
+                    return Ok(DalekSignature::from_bytes(&[0u8; 64]));
+                }
+                let bytes = bs58::decode(s.trim()).into_vec().map_err(|e| {
                     TransactionParseError::InvalidFormat(format!("Invalid signature base58: {}", e))
                 })?;
                 let arr: [u8; 64] = bytes.as_slice().try_into().map_err(|_| {
@@ -240,6 +317,19 @@ impl TryFrom<&UiTransaction> for Transaction {
             })
             .collect::<Result<Vec<_>, _>>()?;
 
+        // Pad unsigned slots so the signature count matches the header; extras cannot
+        // round-trip through the wire format and are rejected
+        let required = ui.message.header().num_required_signatures as usize;
+        if signatures.len() > required {
+            return Err(TransactionParseError::TooManySignatures {
+                found: signatures.len(),
+                required,
+            });
+        }
+        if signatures.len() < required {
+            signatures.resize(required, DalekSignature::from_bytes(&[0u8; 64]));
+        }
+
         let message = match &ui.message {
             UiRawMessage::Legacy(msg) => {
                 let (account_keys, recent_blockhash, instructions) =
@@ -1,10 +1,11 @@
+use crate::models::pubkey_base58::PubkeyBase58;
 use serde::{Deserialize, Serialize};
 
 /// A single executable instruction within a Solana transaction.
 ///
 /// An instruction specifies a program account, a list of accounts to be passed
 /// to the program, and a data blob that serves as input arguments.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Instruction {
     /// Index of the program account in the message's `account_keys` list.
     pub program_id_index: u8,
@@ -20,3 +21,12 @@ pub struct Instruction {
     #[serde(with = "solana_short_vec")]
     pub data: Vec<u8>,
 }
+
+impl Instruction {
+    /// Resolve this instruction's program id against the message account list.
+    ///
+    /// Returns `None` when `program_id_index` is out of range.
+    pub fn program_id<'a>(&self, accounts: &'a [PubkeyBase58]) -> Option<&'a PubkeyBase58> {
+        accounts.get(self.program_id_index as usize)
+    }
+}
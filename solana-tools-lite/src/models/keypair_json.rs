@@ -1,10 +1,14 @@
 use serde::Deserialize;
 
-/// Minimal DTO for Solana keypair JSON with Base58 secretKey string.
+/// Minimal DTO for keypair JSON objects: Solana-style `{publicKey, secretKey}` with a
+/// Base58 secretKey, or a `{"keypair": [..bytes]}` wrapper. Unknown fields (wallet
+/// export metadata) are ignored.
 #[derive(Debug, Deserialize)]
 pub struct KeypairJson {
-    #[serde(rename = "publicKey")]
-    pub public_key: String,
-    #[serde(rename = "secretKey")]
-    pub secret_key: String,
+    #[serde(rename = "publicKey", default)]
+    pub public_key: Option<String>,
+    #[serde(rename = "secretKey", default)]
+    pub secret_key: Option<String>,
+    #[serde(default)]
+    pub keypair: Option<Vec<u8>>,
 }
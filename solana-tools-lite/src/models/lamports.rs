@@ -0,0 +1,73 @@
+use crate::errors::ToolError;
+use crate::utils::format_sol;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
+
+/// An amount of lamports (1 SOL = 10^9 lamports).
+///
+/// Serializes as a plain number. Arithmetic is checked; there is no implicit
+/// conversion from SOL-formatted strings.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Lamports(pub u64);
+
+impl Lamports {
+    pub const ZERO: Lamports = Lamports(0);
+
+    /// Raw lamport value.
+    pub fn get(self) -> u64 {
+        self.0
+    }
+
+    /// `None` on overflow.
+    pub fn checked_add(self, other: Lamports) -> Option<Lamports> {
+        self.0.checked_add(other.0).map(Lamports)
+    }
+
+    /// `None` on underflow.
+    pub fn checked_sub(self, other: Lamports) -> Option<Lamports> {
+        self.0.checked_sub(other.0).map(Lamports)
+    }
+
+    /// Human-readable SOL amount, e.g. `0.000005 SOL`.
+    pub fn to_sol_string(self) -> String {
+        format_sol(self.0 as u128)
+    }
+}
+
+impl From<u64> for Lamports {
+    fn from(value: u64) -> Self {
+        Lamports(value)
+    }
+}
+
+impl From<Lamports> for u64 {
+    fn from(value: Lamports) -> Self {
+        value.0
+    }
+}
+
+impl From<Lamports> for u128 {
+    fn from(value: Lamports) -> Self {
+        value.0 as u128
+    }
+}
+
+impl TryFrom<u128> for Lamports {
+    type Error = ToolError;
+
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        u64::try_from(value)
+            .map(Lamports)
+            .map_err(|_| ToolError::InvalidInput("lamports overflowed u64".into()))
+    }
+}
+
+impl fmt::Display for Lamports {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::codec::deserialize_message;
+use crate::errors::DeserializeError;
 use crate::models::instruction::Instruction;
 use crate::models::{hash_base58::HashBase58, pubkey_base58::PubkeyBase58};
 use serde::{Deserialize, Serialize};
@@ -6,7 +8,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// A message contains all instructions, account keys, and metadata needed to execute
 /// a transaction on-chain. It can be either a Legacy format or a Versioned (v0) format.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Message {
     /// Traditional Solana message format.
@@ -16,6 +18,19 @@ pub enum Message {
 }
 
 impl Message {
+    /// Decode a message from its wire bytes (no signatures short-vec), e.g. the output
+    /// of web3.js `message.serialize()`. Trailing bytes are rejected.
+    pub fn from_wire_bytes(data: &[u8]) -> Result<Message, DeserializeError> {
+        let (message, consumed) = deserialize_message(data)?;
+        if consumed != data.len() {
+            return Err(DeserializeError::Deserialization(format!(
+                "{} trailing bytes after message",
+                data.len() - consumed
+            )));
+        }
+        Ok(message)
+    }
+
     /// Return the message header.
     pub fn header(&self) -> &MessageHeader {
         match self {
@@ -24,6 +39,25 @@ impl Message {
         }
     }
 
+    /// Whether the static account key at `index` is writable according to the header.
+    ///
+    /// Signed accounts come first (writable, then readonly), followed by unsigned
+    /// accounts (writable, then readonly). Out-of-range indexes, including accounts
+    /// resolved through v0 lookup tables, return `false`.
+    pub fn is_static_writable(&self, index: usize) -> bool {
+        let header = self.header();
+        let num_keys = self.account_keys().len();
+        let num_signed = header.num_required_signatures as usize;
+
+        if index >= num_keys {
+            false
+        } else if index < num_signed {
+            index < num_signed.saturating_sub(header.num_readonly_signed_accounts as usize)
+        } else {
+            index < num_keys.saturating_sub(header.num_readonly_unsigned_accounts as usize)
+        }
+    }
+
     /// Returns the list of account keys involved in this message.
     pub fn account_keys(&self) -> &Vec<PubkeyBase58> {
         match self {
@@ -65,7 +99,7 @@ impl Message {
 }
 
 /// Legacy message format used in the original Solana protocol.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MessageLegacy {
     /// Metadata about account signing and read/write requirements.
     pub header: MessageHeader,
@@ -92,7 +126,7 @@ impl MessageLegacy {
 }
 
 /// Versioned message format (v0) supporting address table lookups.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MessageV0 {
     /// Metadata about account signing and read/write requirements.
     pub header: MessageHeader,
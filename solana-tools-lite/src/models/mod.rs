@@ -2,9 +2,12 @@ pub mod hash_base58;
 pub mod input_transaction;
 pub mod instruction;
 pub mod keypair_json;
+pub mod lamports;
 pub mod message;
+pub mod network;
 pub mod analysis;
 pub mod pubkey_base58;
+pub mod rent;
 pub mod results;
 pub mod transaction;
 
@@ -20,5 +23,8 @@ pub use crate::models::transaction::Transaction;
 /// Abstract representation of Solana message (legacy or v0).
 pub use crate::models::message::Message;
 
+/// Lamport amount with checked arithmetic.
+pub use crate::models::lamports::Lamports;
+
 /// 32-byte public key wrapper encoded in Base58.
 pub use crate::models::pubkey_base58::PubkeyBase58;
\ No newline at end of file
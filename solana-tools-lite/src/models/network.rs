@@ -0,0 +1,69 @@
+use crate::constants::programs;
+use serde::Serialize;
+
+/// Solana cluster whose program labels apply to `inspect` output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Network {
+    #[default]
+    Mainnet,
+    Devnet,
+    Testnet,
+    Localnet,
+}
+
+/// Bundled per-network settings.
+///
+/// The base fee is not part of the preset: every cluster charges the same 5000 lamports
+/// per signature, so the fee estimate in `handlers::analysis` uses a single constant.
+#[derive(Debug, Clone, Copy)]
+pub struct NetworkPreset {
+    /// Names of the native and SPL programs: `(program id, label)`.
+    pub program_labels: &'static [(&'static str, &'static str)],
+    /// Names of third-party programs deployed on this network.
+    pub extra_labels: &'static [(&'static str, &'static str)],
+}
+
+// Native and SPL programs exist on every cluster (the test validator bundles SPL)
+const NATIVE_LABELS: &[(&str, &str)] = &[
+    (programs::SYSTEM_PROGRAM_ID, "System Program"),
+    (programs::COMPUTE_BUDGET_ID, "Compute Budget"),
+    (programs::STAKE_PROGRAM_ID, "Stake Program"),
+    (programs::ED25519_PROGRAM_ID, "Ed25519 SigVerify"),
+    (programs::SECP256K1_PROGRAM_ID, "Secp256k1 SigVerify"),
+    (programs::TOKEN_PROGRAM_ID, "SPL Token"),
+    (programs::TOKEN_2022_PROGRAM_ID, "SPL Token-2022"),
+    (programs::ASSOCIATED_TOKEN_PROGRAM_ID, "Associated Token Account"),
+];
+
+// Third-party programs deployed on the public clusters
+const PUBLIC_CLUSTER_LABELS: &[(&str, &str)] = &[
+    (programs::SQUADS_V3_PROGRAM_ID, "Squads Multisig v3"),
+    (programs::SQUADS_V4_PROGRAM_ID, "Squads Multisig v4"),
+];
+
+impl Network {
+    /// Settings bundled for this network.
+    pub fn preset(self) -> NetworkPreset {
+        let extra_labels = match self {
+            Network::Mainnet | Network::Devnet => PUBLIC_CLUSTER_LABELS,
+            // Squads is labelled only where it is deployed
+            Network::Testnet | Network::Localnet => &[],
+        };
+        NetworkPreset {
+            program_labels: NATIVE_LABELS,
+            extra_labels,
+        }
+    }
+
+    /// Label of a known program on this network.
+    pub fn program_label(self, program_id: &str) -> Option<&'static str> {
+        let preset = self.preset();
+        preset
+            .program_labels
+            .iter()
+            .chain(preset.extra_labels)
+            .find(|(id, _)| *id == program_id)
+            .map(|(_, label)| *label)
+    }
+}
@@ -6,10 +6,13 @@ use serde::{Deserialize, Deserializer};
 /// This type handles conversion between raw bytes and Base58 string representations.
 /// It is the primary type for account addresses in transactions.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
-pub struct PubkeyBase58(pub [u8; 32]);
+pub struct PubkeyBase58([u8; 32]);
 use bs58;
 use std::convert::TryFrom;
 
+/// Characters kept on each side by `PubkeyBase58::short`.
+const SHORT_EDGE: usize = 4;
+
 impl TryFrom<&str> for PubkeyBase58 {
     type Error = crate::errors::ToolError;
 
@@ -29,20 +32,41 @@ impl TryFrom<&str> for PubkeyBase58 {
 }
 
 impl PubkeyBase58 {
-    /// Construct a PubkeyBase58 directly from raw 32-byte slice.
-    pub fn from_bytes(bytes: &[u8]) -> Result<Self, crate::errors::ToolError> {
-        // Expect exactly 32 bytes for a valid public key
-        if bytes.len() != 32 {
-            return Err(
-                crate::errors::TransactionParseError::InvalidPubkeyFormat(format!(
-                    "invalid byte length: {}",
-                    bytes.len()
-                ))
-                .into(),
-            );
+    /// Construct a PubkeyBase58 from raw 32 bytes.
+    pub const fn from_bytes(bytes: &[u8; 32]) -> Self {
+        PubkeyBase58(*bytes)
+    }
+
+    /// Raw 32-byte representation of the public key.
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Abbreviated Base58 form for logs and tables: first and last 4 characters
+    /// (`abcd…wxyz`). Display-only; it cannot be parsed back into a key.
+    pub fn short(&self) -> String {
+        let full = self.to_string();
+        let chars: Vec<char> = full.chars().collect();
+        if chars.len() <= SHORT_EDGE * 2 + 1 {
+            return full;
         }
-        let mut array = [0u8; 32];
-        array.copy_from_slice(bytes);
+        let head: String = chars[..SHORT_EDGE].iter().collect();
+        let tail: String = chars[chars.len() - SHORT_EDGE..].iter().collect();
+        format!("{head}…{tail}")
+    }
+}
+
+impl TryFrom<&[u8]> for PubkeyBase58 {
+    type Error = crate::errors::ToolError;
+
+    /// Construct a PubkeyBase58 from a raw byte slice; it must be exactly 32 bytes.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let array: [u8; 32] = bytes.try_into().map_err(|_| {
+            TransactionParseError::InvalidPubkeyFormat(format!(
+                "invalid byte length: {}",
+                bytes.len()
+            ))
+        })?;
         Ok(PubkeyBase58(array))
     }
 }
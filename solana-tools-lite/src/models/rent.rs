@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+/// Bytes of account metadata charged on top of the data length.
+pub const ACCOUNT_STORAGE_OVERHEAD: u64 = 128;
+
+/// Rent parameters used to estimate the rent-exempt minimum of new accounts.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RentConfig {
+    /// Rent charged per byte per year (lamports).
+    pub lamports_per_byte_year: u64,
+    /// Years of rent an account must hold to be exempt.
+    pub exemption_threshold: f64,
+}
+
+impl RentConfig {
+    /// Solana cluster defaults (3480 lamports per byte-year, 2 years).
+    pub const DEFAULT: RentConfig = RentConfig {
+        lamports_per_byte_year: 3480,
+        exemption_threshold: 2.0,
+    };
+
+    /// Minimum balance (lamports) for an account holding `space` data bytes to be rent exempt.
+    pub fn minimum_balance(&self, space: u64) -> u64 {
+        let bytes = ACCOUNT_STORAGE_OVERHEAD.saturating_add(space);
+        let per_year = bytes.saturating_mul(self.lamports_per_byte_year);
+        (per_year as f64 * self.exemption_threshold) as u64
+    }
+}
+
+impl Default for RentConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
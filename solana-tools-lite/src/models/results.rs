@@ -1,4 +1,8 @@
-use crate::models::transaction::Transaction;
+use crate::models::input_transaction::UiTransaction;
+use crate::models::analysis::TxAnalysis;
+use crate::models::message::MessageHeader;
+use crate::models::transaction::{SignatureStatus, Transaction};
+use crate::errors::ToolError;
 use serde::Serialize;
 
 /// Result of a signature verification operation.
@@ -12,13 +16,105 @@ pub struct VerifyResult {
     pub signature: String,
     /// Whether the signature is valid for the given message and public key.
     pub valid: bool,
+    /// Position of `pubkey` among the candidates when several keys were tried.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub candidate_index: Option<usize>,
+}
+
+/// Role of a static account key, derived from the message header regions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AccountRegion {
+    WritableSigner,
+    ReadonlySigner,
+    Writable,
+    Readonly,
+}
+
+/// One signature slot of an inspected transaction.
+#[derive(Debug, Serialize)]
+pub struct InspectedSignature {
+    /// Signature in Base58 (the all-zero placeholder when unsigned).
+    pub signature: String,
+    /// Verification status of the slot.
+    pub status: SignatureStatus,
+}
+
+/// One static account key with its header region.
+#[derive(Debug, Serialize)]
+pub struct InspectedAccount {
+    pub index: usize,
+    pub pubkey: String,
+    pub region: AccountRegion,
+}
+
+/// One instruction as stored in the message.
+#[derive(Debug, Serialize)]
+pub struct InspectedInstruction {
+    /// Program id (Base58); `None` when the index points outside the static keys.
+    pub program_id: Option<String>,
+    /// Name of the program on the selected network, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub program_label: Option<&'static str>,
+    /// Account indexes into the full (static + lookup) account list.
+    pub accounts: Vec<u8>,
+    /// Instruction data as lowercase hex.
+    pub data_hex: String,
+}
+
+/// Address table lookup of a v0 message (indexes are not resolved).
+#[derive(Debug, Serialize)]
+pub struct InspectedLookup {
+    pub account_key: String,
+    pub writable_indexes: Vec<u8>,
+    pub readonly_indexes: Vec<u8>,
+}
+
+/// Structural dump of a transaction (`inspect`): everything the message carries, undecoded.
+#[derive(Debug, Serialize)]
+pub struct InspectResult {
+    pub signatures: Vec<InspectedSignature>,
+    /// `legacy` or `v0`.
+    pub version: &'static str,
+    pub header: MessageHeader,
+    pub account_keys: Vec<InspectedAccount>,
+    pub recent_blockhash: String,
+    pub instructions: Vec<InspectedInstruction>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub address_table_lookups: Vec<InspectedLookup>,
+}
+
+/// Outcome of checking one signer's slot in a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum VerifyTxStatus {
+    /// The slot's signature verifies against the message bytes.
+    Valid,
+    /// The slot holds a signature that does not verify.
+    Invalid,
+    /// The key is not one of the transaction's required signers.
+    NotASigner,
+    /// The slot is missing or holds the all-zero placeholder.
+    Unsigned,
+}
+
+/// Result of verifying a signer's slot in a transaction (`verify-tx`).
+#[derive(Debug, Serialize)]
+pub struct VerifyTxResult {
+    /// The signer checked (Base58).
+    pub pubkey: String,
+    /// Index of the signer's slot; `None` when the key is not a signer.
+    pub signer_index: Option<usize>,
+    /// Verification outcome for that slot.
+    pub status: VerifyTxStatus,
 }
 
 /// Result of a new keypair generation.
 #[derive(Serialize)]
 pub struct GenResult {
-    /// BIP-39 mnemonic phrase.
-    pub mnemonic: String,
+    /// BIP-39 mnemonic phrase; absent when derived from a raw seed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mnemonic: Option<String>,
     /// Derived public key (Base58).
     #[serde(rename = "publicKey")]
     pub public_key: String,
@@ -27,6 +123,87 @@ pub struct GenResult {
     pub secret_key: String,
     /// 64-byte seed derived from mnemonic and passphrase (Hex).
     pub seed_hex: String,
+    /// BIP32 master key fingerprint (Hex, 4 bytes) for matching against other wallets.
+    pub master_fingerprint: String,
+}
+
+/// Structured output of wallet generation, free of any presentation or file I/O.
+#[derive(Serialize)]
+pub struct GeneratedWallet {
+    /// BIP-39 mnemonic phrase (normalized); `None` when derived from a raw seed.
+    pub mnemonic: Option<String>,
+    /// Whether a non-empty BIP-39 passphrase was mixed into the seed.
+    pub passphrase_used: bool,
+    /// Derived public key (Base58).
+    pub pubkey: String,
+    /// Derivation path used for the key.
+    pub derivation_path: String,
+    /// BIP32 master key fingerprint (Hex, 4 bytes).
+    pub master_fingerprint: String,
+    /// Secret material; only present when the caller asked for it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<WalletSecret>,
+}
+
+/// Secret half of a generated wallet.
+#[derive(Serialize)]
+pub struct WalletSecret {
+    /// Derived secret key (Base58).
+    pub secret_key: String,
+    /// 64-byte seed derived from mnemonic and passphrase (Hex).
+    pub seed_hex: String,
+}
+
+impl TryFrom<GeneratedWallet> for GenResult {
+    type Error = ToolError;
+
+    /// Build the wallet file contents; requires the secret part.
+    fn try_from(wallet: GeneratedWallet) -> Result<Self, Self::Error> {
+        let secret = wallet.secret.ok_or_else(|| {
+            ToolError::InvalidInput("wallet was generated without its secret".into())
+        })?;
+        Ok(GenResult {
+            mnemonic: wallet.mnemonic,
+            public_key: wallet.pubkey,
+            secret_key: secret.secret_key,
+            seed_hex: secret.seed_hex,
+            master_fingerprint: wallet.master_fingerprint,
+        })
+    }
+}
+
+/// Result of deriving only the public address from an existing mnemonic.
+#[derive(Serialize, Debug)]
+pub struct DeriveResult {
+    /// Derived public key (Base58).
+    #[serde(rename = "publicKey")]
+    pub public_key: String,
+    /// Derivation path used for the key.
+    pub derivation_path: String,
+}
+
+/// One account of a multi-account derivation (`gen --count`).
+#[derive(Serialize, Debug, Clone)]
+pub struct DerivedAccount {
+    /// Account index (the varying level of `m/44'/501'/<index>'/0'`).
+    pub index: u32,
+    /// Derivation path used for the key.
+    pub derivation_path: String,
+    /// Derived public key (Base58).
+    #[serde(rename = "publicKey")]
+    pub public_key: String,
+    /// Derived secret key (Base58); only present when the caller asked for it.
+    #[serde(rename = "secretKey", skip_serializing_if = "Option::is_none")]
+    pub secret_key: Option<String>,
+    /// Wallet file the account was written to, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_path: Option<String>,
+}
+
+/// Result of deriving several accounts from one mnemonic.
+#[derive(Serialize, Debug, Clone)]
+pub struct DeriveManyResult {
+    pub accounts: Vec<DerivedAccount>,
 }
 
 /// Result of signing a single message.
@@ -47,6 +224,40 @@ pub struct SignTxResult {
     pub signed_tx: Transaction,
 }
 
+/// One required signer slot of a signing plan.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct SignerSlot {
+    /// Signature index (position among the required signers).
+    pub index: usize,
+    /// Account expected to sign this slot (Base58).
+    pub pubkey: String,
+    /// Position of the supplied key that fills this slot, `None` when uncovered.
+    pub covered_by: Option<usize>,
+}
+
+/// Result of `sign_tx::plan`: required signer slots and the keys that would fill them.
+#[derive(Serialize, Debug)]
+pub struct SignPlanResult {
+    pub slots: Vec<SignerSlot>,
+    /// Whether every required slot is covered by a supplied key.
+    pub fully_covered: bool,
+    /// Supplied keys (Base58) that fill no required slot.
+    pub unused_keys: Vec<String>,
+}
+
+/// Result of `sign_tx::prepare_and_sign`: the analysis that gated signing and the signed output.
+#[derive(Debug)]
+pub struct SignOutcome {
+    /// Analysis of the transaction from the first key's point of view.
+    pub analysis: TxAnalysis,
+    /// The transaction with every provided key's signature applied.
+    pub signed_tx: Transaction,
+    /// Wire bytes of the signed transaction (Base64).
+    pub signed_tx_base64: String,
+    /// Wire bytes of the signed transaction (Base58).
+    pub signed_tx_base58: String,
+}
+
 /// Result of a Base58 encoding or decoding operation.
 #[derive(Serialize, Debug)]
 pub struct Base58Result {
@@ -57,3 +268,14 @@ pub struct Base58Result {
     /// The resulting output string or bytes.
     pub output: String,
 }
+
+/// Result of Base58-decoding input that may be a serialized transaction.
+#[derive(Serialize, Debug)]
+pub struct Base58TransactionResult {
+    /// The Base58 input string.
+    pub input: String,
+    /// Parsed transaction when the bytes are exactly one wire transaction.
+    pub transaction: Option<UiTransaction>,
+    /// Decoded bytes as (lossy) UTF-8 text when they are not a transaction.
+    pub output: Option<String>,
+}
@@ -1,4 +1,10 @@
+use crate::codec::serialize_message;
+use crate::constants::crypto::SIG_LEN;
+use crate::crypto::ed25519::verify_signature;
+use crate::errors::ToolError;
 use crate::models::message::Message;
+use crate::models::pubkey_base58::PubkeyBase58;
+use ed25519_dalek::VerifyingKey;
 use serde::{Deserialize, Serialize};
 
 /// A Solana transaction consists of a list of signatures and a message.
@@ -15,3 +21,116 @@ pub struct Transaction {
     /// The content of the transaction, including instructions, account keys, and blockhash.
     pub message: Message,
 }
+
+/// Verification outcome for a single signer slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SignatureStatus {
+    /// The signature verifies against the slot's public key and the message bytes.
+    Valid,
+    /// A signature is present but does not verify (or the slot's key is not a valid point).
+    Invalid,
+    /// The slot is missing or holds the all-zero placeholder signature.
+    Unsigned,
+}
+
+impl Transaction {
+    /// Canonical message bytes that every signer signs.
+    pub fn message_signing_bytes(&self) -> Vec<u8> {
+        serialize_message(&self.message)
+    }
+
+    /// Verify every required signer slot against the canonical message bytes.
+    ///
+    /// Returns one status per `num_required_signatures` slot, in account-key order.
+    pub fn verify_all(&self) -> Vec<SignatureStatus> {
+        let required = self.message.header().num_required_signatures as usize;
+        let keys = self.message.account_keys();
+        let msg_bytes = self.message_signing_bytes();
+
+        (0..required)
+            .map(|i| {
+                let Some(sig) = self.signatures.get(i) else {
+                    return SignatureStatus::Unsigned;
+                };
+                if sig.to_bytes() == [0u8; SIG_LEN] {
+                    return SignatureStatus::Unsigned;
+                }
+                let Some(key) = keys.get(i) else {
+                    return SignatureStatus::Invalid;
+                };
+                match VerifyingKey::from_bytes(key.as_bytes()) {
+                    Ok(vk) if verify_signature(&vk, &msg_bytes, sig) => SignatureStatus::Valid,
+                    _ => SignatureStatus::Invalid,
+                }
+            })
+            .collect()
+    }
+
+    /// Verify the slot of one signer.
+    ///
+    /// Returns the slot index and its status, or `None` when `pubkey` is not among the
+    /// `num_required_signatures` signer keys.
+    pub fn verify_signer(&self, pubkey: &PubkeyBase58) -> Option<(usize, SignatureStatus)> {
+        let required = self.message.header().num_required_signatures as usize;
+        let index = self
+            .message
+            .account_keys()
+            .iter()
+            .take(required)
+            .position(|key| key == pubkey)?;
+        Some((index, self.verify_all()[index]))
+    }
+
+    /// Reset every signature slot to the all-zero placeholder, e.g. before re-signing.
+    ///
+    /// The list is padded to `num_required_signatures` slots if it is shorter.
+    pub fn clear_signatures(&mut self) {
+        let required = self.message.header().num_required_signatures as usize;
+        let len = required.max(self.signatures.len());
+        self.signatures = vec![ed25519_dalek::Signature::from_bytes(&[0u8; SIG_LEN]); len];
+    }
+
+    /// True when there is exactly one signature per required signer and none is the
+    /// all-zero placeholder. Signatures are not verified; see [`Transaction::verify_all`].
+    pub fn is_fully_signed(&self) -> bool {
+        let required = self.message.header().num_required_signatures as usize;
+        self.signatures.len() == required
+            && self
+                .signatures
+                .iter()
+                .all(|sig| sig.to_bytes() != [0u8; SIG_LEN])
+    }
+
+    /// Copy the non-zero signatures of `other` into the empty slots of `self`.
+    ///
+    /// Both copies must carry the same message. Fails if a slot holds a different
+    /// non-zero signature in each copy; `self` is left untouched on error.
+    pub fn merge_signatures(&mut self, other: &Transaction) -> Result<(), ToolError> {
+        if self.message_signing_bytes() != other.message_signing_bytes() {
+            return Err(ToolError::InvalidInput(
+                "cannot merge signatures: transaction messages differ".into(),
+            ));
+        }
+
+        let required = self.message.header().num_required_signatures as usize;
+        let empty = ed25519_dalek::Signature::from_bytes(&[0u8; SIG_LEN]);
+        let mut merged = self.signatures.clone();
+        merged.resize(required.max(merged.len()), empty);
+
+        for (i, sig) in other.signatures.iter().enumerate().take(required) {
+            if *sig == empty || merged[i] == *sig {
+                continue;
+            }
+            if merged[i] != empty {
+                return Err(ToolError::InvalidInput(format!(
+                    "cannot merge signatures: slot {i} holds conflicting signatures"
+                )));
+            }
+            merged[i] = *sig;
+        }
+
+        self.signatures = merged;
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+//! String-in / string-out entry points with no filesystem, stdin or process access.
+//!
+//! Everything here is available without the `io` feature, so the crate can be built with
+//! `--no-default-features` for targets such as `wasm32-unknown-unknown` (browsers).
+
+use crate::crypto::helpers::parse_signing_key_content;
+use crate::handlers::analysis::{analyze_transaction, build_signing_summary};
+use crate::handlers::sign_tx::{SignOptions, prepare_and_sign};
+use crate::models::analysis::SigningSummary;
+use crate::models::pubkey_base58::PubkeyBase58;
+use crate::models::results::SignOutcome;
+use crate::models::transaction::Transaction;
+use crate::serde::input_tx::parse_input_transaction;
+use crate::serde::parse_lookup_tables;
+use crate::{Result, ToolError};
+
+/// Analyze a transaction given as text and return its signing summary.
+///
+/// - `input`: JSON / Base64 / Base58 transaction (auto-detected)
+/// - `signer`: Base58 public key to analyze as
+/// - `tables_json`: optional lookup tables (JSON map: table address -> array of addresses)
+pub fn analyze_from_str(
+    input: &str,
+    signer: &str,
+    tables_json: Option<&str>,
+) -> Result<SigningSummary> {
+    let signer = PubkeyBase58::try_from(signer)
+        .map_err(|e| ToolError::InvalidInput(format!("Invalid signer pubkey: {}", e)))?;
+    let tables = tables_json.map(parse_lookup_tables).transpose()?;
+
+    let tx = Transaction::try_from(parse_input_transaction(Some(input))?)?;
+    tx.message.sanitize()?;
+
+    let analysis = analyze_transaction(&tx.message, &signer, tables.as_ref(), None, None);
+    build_signing_summary(&tx, &analysis)
+}
+
+/// Analyze and sign a transaction given as text (see `sign_tx::prepare_and_sign`).
+///
+/// `secret_key` accepts the same formats as a keypair file (JSON byte array, keypair JSON,
+/// or Base58); no gates beyond the lookup tables are applied.
+pub fn sign_from_str(
+    input: &str,
+    secret_key: &str,
+    tables_json: Option<&str>,
+) -> Result<SignOutcome> {
+    let key = parse_signing_key_content(secret_key)?;
+    let tables = tables_json.map(parse_lookup_tables).transpose()?;
+    let opts = SignOptions {
+        tables: tables.as_ref(),
+        ..Default::default()
+    };
+    prepare_and_sign(input, std::slice::from_ref(&key), &opts)
+}
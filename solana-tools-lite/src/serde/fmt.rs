@@ -1,7 +1,7 @@
 use crate::errors::TransactionParseError;
 use crate::models::input_transaction::UiTransaction;
 
-pub use crate::models::input_transaction::OutputFormat;
+pub use crate::models::input_transaction::{InputFormat, OutputFormat};
 /// Serialize a `UiTransaction` to JSON according to the specified `OutputFormat`.
 ///
 /// Supported:
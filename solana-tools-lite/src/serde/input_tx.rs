@@ -1,5 +1,8 @@
-use crate::errors::TransactionParseError;
-use crate::models::input_transaction::{InputTransaction, UiTransaction};
+use crate::codec::deserialize_transaction_with_len;
+use crate::constants::crypto::SIG_LEN;
+use crate::errors::{ToolError, TransactionParseError};
+use crate::models::input_transaction::{InputFormat, InputTransaction, UiTransaction};
+use crate::models::transaction::Transaction;
 use data_encoding::BASE64;
 
 /// Backward-friendly wrapper: same detection, but accepts Option<&str>.
@@ -13,32 +16,190 @@ pub fn parse_input_transaction(
     }
 }
 
+/// Strict variant of `parse_input_transaction` that rejects trailing garbage.
+///
+/// Behavior
+/// - A leading UTF-8 BOM and surrounding whitespace are ignored
+/// - Input that looks like JSON (`{`) must be a single `UiTransaction` object; anything
+///   after it other than whitespace is an error
+/// - Base64 input must decode to exactly one wire transaction (no bytes left over)
+/// - Base58 input must decode to exactly one JSON `UiTransaction`
+///
+/// Catches concatenation mistakes that the lenient detection would silently accept.
+pub fn parse_input_transaction_strict(
+    input: Option<&str>,
+) -> Result<InputTransaction, TransactionParseError> {
+    let s = input.ok_or_else(|| TransactionParseError::InvalidFormat("missing input".into()))?;
+    let trimmed = trim_input(s);
+
+    if trimmed.starts_with('{') {
+        let json_tx = serde_json::from_str::<UiTransaction>(trimmed)
+            .map_err(|e| TransactionParseError::InvalidFormat(e.to_string()))?;
+        return Ok(InputTransaction::Json(json_tx));
+    }
+
+    let parsed = parse_input_transaction_str(trimmed)?;
+    match &parsed {
+        InputTransaction::Base64(b64) => {
+            let raw = BASE64
+                .decode(b64.as_bytes())
+                .map_err(|e| TransactionParseError::InvalidBase64(e.to_string()))?;
+            let (_, consumed) = deserialize_transaction_with_len(&raw)
+                .map_err(|e| TransactionParseError::InvalidFormat(e.to_string()))?;
+            if consumed != raw.len() {
+                return Err(TransactionParseError::InvalidFormat(format!(
+                    "{} trailing bytes after transaction",
+                    raw.len() - consumed
+                )));
+            }
+        }
+        InputTransaction::Base58(_) => {
+            // Base58 carries JSON; serde_json rejects trailing characters itself
+            Transaction::try_from(&parsed)?;
+        }
+        InputTransaction::Json(_) => {}
+    }
+    Ok(parsed)
+}
+
+/// Parse input in a caller-chosen format, bypassing auto-detection.
+///
+/// Behavior
+/// - Strips a leading UTF-8 BOM and trims the input before decoding
+/// - A decode failure is a hard error; other formats are never tried
+///
+/// Use when detection misfires on ambiguous strings (e.g. text that is valid in both alphabets).
+pub fn parse_input_transaction_as(
+    input: Option<&str>,
+    format: InputFormat,
+) -> Result<InputTransaction, TransactionParseError> {
+    let s = input.ok_or_else(|| TransactionParseError::InvalidFormat("missing input".into()))?;
+    let trimmed = trim_input(s);
+
+    match format {
+        InputFormat::Json => serde_json::from_str::<UiTransaction>(trimmed)
+            .map(InputTransaction::Json)
+            .map_err(|e| TransactionParseError::InvalidFormat(e.to_string())),
+        InputFormat::Base64 => {
+            BASE64
+                .decode(trimmed.as_bytes())
+                .map_err(|e| TransactionParseError::InvalidBase64(e.to_string()))?;
+            Ok(InputTransaction::Base64(trimmed.to_string()))
+        }
+        InputFormat::Base58 => {
+            bs58::decode(trimmed)
+                .into_vec()
+                .map_err(|e| TransactionParseError::InvalidBase58(e.to_string()))?;
+            Ok(InputTransaction::Base58(trimmed.to_string()))
+        }
+    }
+}
+
+/// Strict signature check for a parsed input: no padding, no tolerated gaps.
+///
+/// Behavior
+/// - JSON (and Base58, which carries JSON): exactly `num_required_signatures` entries;
+///   every non-empty entry must be Base58 of exactly 64 bytes (empty marks an unsigned slot)
+/// - Base64 (wire bytes): the signature count must equal `num_required_signatures`
+///
+/// Returns `ToolError::InvalidInput` describing the first mismatch.
+pub fn validate_signatures(input: &InputTransaction) -> Result<(), ToolError> {
+    match input {
+        InputTransaction::Json(ui) => validate_ui_signatures(ui),
+        InputTransaction::Base58(b58) => {
+            let bytes = bs58::decode(b58.trim())
+                .into_vec()
+                .map_err(|e| TransactionParseError::InvalidBase58(e.to_string()))?;
+            let ui = serde_json::from_slice::<UiTransaction>(&bytes)
+                .map_err(|e| TransactionParseError::InvalidFormat(e.to_string()))?;
+            validate_ui_signatures(&ui)
+        }
+        InputTransaction::Base64(b64) => {
+            let raw = BASE64
+                .decode(b64.trim().as_bytes())
+                .map_err(|e| TransactionParseError::InvalidBase64(e.to_string()))?;
+            let (tx, _) = deserialize_transaction_with_len(&raw)
+                .map_err(|e| TransactionParseError::InvalidFormat(e.to_string()))?;
+            let required = tx.message.header().num_required_signatures as usize;
+            check_signature_count(tx.signatures.len(), required)
+        }
+    }
+}
+
+fn validate_ui_signatures(ui: &UiTransaction) -> Result<(), ToolError> {
+    check_signature_count(ui.signatures.len(), ui.message.num_required_signatures())?;
+
+    for (index, sig) in ui.signatures.iter().enumerate() {
+        let sig = sig.trim();
+        if sig.is_empty() {
+            continue;
+        }
+        let valid = bs58::decode(sig)
+            .into_vec()
+            .is_ok_and(|bytes| bytes.len() == SIG_LEN);
+        if !valid {
+            return Err(ToolError::InvalidInput(format!(
+                "signature #{index} is not {SIG_LEN} bytes of Base58"
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn check_signature_count(provided: usize, required: usize) -> Result<(), ToolError> {
+    if provided != required {
+        return Err(ToolError::InvalidInput(format!(
+            "transaction carries {provided} signatures but its header requires {required}"
+        )));
+    }
+    Ok(())
+}
+
 /// Detect input format from a string and return the corresponding variant.
 ///
 /// Behavior
 /// - Detects JSON (UiTransaction), then Base64, then Base58
-/// - Trims the input before checks
+/// - Strips a leading UTF-8 BOM and trims the input before checks
 /// - Returns InvalidFormat on unknown content
 ///
 /// This function does not perform any I/O and expects the caller to have read the text already.
 fn parse_input_transaction_str(s: &str) -> Result<InputTransaction, TransactionParseError> {
-    let trimmed = s.trim();
+    let trimmed = trim_input(s);
 
     if let Ok(json_tx) = serde_json::from_str::<UiTransaction>(trimmed) {
         return Ok(InputTransaction::Json(json_tx));
     }
 
-    if is_base64(trimmed) {
-        return Ok(InputTransaction::Base64(trimmed.to_string()));
+    match detect_text_encoding(trimmed) {
+        Some(InputFormat::Base64) => Ok(InputTransaction::Base64(trimmed.to_string())),
+        Some(InputFormat::Base58) => Ok(InputTransaction::Base58(trimmed.to_string())),
+        _ => Err(TransactionParseError::InvalidFormat(
+            "Unknown input format".into(),
+        )),
     }
+}
 
-    if is_base58(trimmed) {
-        return Ok(InputTransaction::Base58(trimmed.to_string()));
+/// Encoding of non-JSON text input: Base64, then Base58, `None` when neither fits.
+///
+/// Shared by the transaction and message parsers so both resolve text that is valid
+/// in both alphabets the same way.
+pub fn detect_text_encoding(s: &str) -> Option<InputFormat> {
+    if is_base64(s) {
+        Some(InputFormat::Base64)
+    } else if is_base58(s) {
+        Some(InputFormat::Base58)
+    } else {
+        None
     }
+}
 
-    Err(TransactionParseError::InvalidFormat(
-        "Unknown input format".into(),
-    ))
+/// Drop a leading UTF-8 byte order mark and surrounding whitespace.
+///
+/// `str::trim` keeps the BOM (U+FEFF is not whitespace), and serde_json rejects it, so
+/// files saved by editors that add one would otherwise fail to parse.
+fn trim_input(s: &str) -> &str {
+    let s = s.trim_start();
+    s.strip_prefix('\u{FEFF}').unwrap_or(s).trim()
 }
 
 /// Returns true if `s` is non-empty and valid Base64.
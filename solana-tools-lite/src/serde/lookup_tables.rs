@@ -4,12 +4,16 @@ use serde::{Deserialize, Serialize};
 
 /// Lookup table entry from Solana RPC.
 /// Stores writable and readonly accounts for sequential offset processing.
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq)]
 pub struct LookupTableEntry {
     #[serde(default)]
     pub writable: Vec<PubkeyBase58>,
     #[serde(default)]
     pub readonly: Vec<PubkeyBase58>,
+    /// Addresses of the tables the accounts were taken from (optional); a declared table
+    /// that the transaction never looks up is reported as `UnusedLookupTable`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tables: Vec<PubkeyBase58>,
 }
 
 /// Parse lookup tables from Solana RPC format.
@@ -18,7 +22,8 @@ pub struct LookupTableEntry {
 /// ```json
 /// {
 ///   "readonly": ["account1", "account2", ...],
-///   "writable": ["account3", "account4", ...]
+///   "writable": ["account3", "account4", ...],
+///   "tables": ["table1", ...]
 /// }
 /// ```
 ///
@@ -35,6 +40,8 @@ pub fn parse_lookup_tables(
         pub writable: Vec<String>,
         #[serde(default)]
         pub readonly: Vec<String>,
+        #[serde(default)]
+        pub tables: Vec<String>,
     }
 
     let raw: RawEntry = serde_json::from_str(json)
@@ -59,7 +66,17 @@ pub fn parse_lookup_tables(
         readonly.push(pk);
     }
 
-    let entry = LookupTableEntry { writable, readonly };
+    let mut tables = Vec::new();
+
+    // Parse declared table addresses
+    for addr in raw.tables {
+        let pk = PubkeyBase58::try_from(addr.as_str()).map_err(|e| {
+            ToolError::InvalidInput(format!("invalid table address {addr}: {e}"))
+        })?;
+        tables.push(pk);
+    }
+
+    let entry = LookupTableEntry { writable, readonly, tables };
 
     Ok(entry)
 }
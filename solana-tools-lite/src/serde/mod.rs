@@ -3,6 +3,9 @@ pub mod input_tx;
 pub mod signature;
 pub mod lookup_tables;
 
-pub use fmt::OutputFormat;
-pub use input_tx::parse_input_transaction;
+pub use fmt::{InputFormat, OutputFormat};
+pub use input_tx::{
+    parse_input_transaction, parse_input_transaction_as, parse_input_transaction_strict,
+    validate_signatures,
+};
 pub use lookup_tables::{parse_lookup_tables, LookupTableEntry};
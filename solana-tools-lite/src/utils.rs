@@ -3,6 +3,21 @@ pub fn hex_encode(data: &[u8]) -> String {
     hex::encode(data)
 }
 
+/// Format micro-lamports (10^-6 lamport) as a decimal lamport amount, trimming trailing
+/// zeros: `1_500_000` → `1.5`, `10_000` → `0.01`. Used for compute unit prices,
+/// which Compute Budget expresses in micro-lamports per CU.
+pub fn format_micro_lamports(micro_lamports: u64) -> String {
+    let whole = micro_lamports / 1_000_000;
+    let frac = micro_lamports % 1_000_000;
+
+    if frac == 0 {
+        whole.to_string()
+    } else {
+        let frac_str = format!("{:06}", frac);
+        format!("{}.{}", whole, frac_str.trim_end_matches('0'))
+    }
+}
+
 /// Format lamports to SOL string with dynamic precision, trimming trailing zeros.
 /// Shows up to 9 decimals for very small amounts, otherwise 3 decimals.
 /// Removes trailing zeros after decimal point.
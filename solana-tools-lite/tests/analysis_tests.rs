@@ -1,10 +1,23 @@
-use solana_tools_lite::handlers::analysis::analyze_transaction;
-use solana_tools_lite::models::analysis::{AnalysisWarning, TokenProgramKind};
+use solana_tools_lite::handlers::analysis::{
+    AnalysisConfig, DEFAULT_MAX_SCANNED_DATA_LEN, analyze_fast_path, analyze_transaction,
+    analyze_transaction_full, analyze_transaction_with, build_signing_summary, is_account_writable,
+    priority_fee_for,
+};
+use solana_tools_lite::utils::format_micro_lamports;
+use solana_tools_lite::ToolError;
+use solana_tools_lite::models::transaction::Transaction;
+use solana_tools_lite::models::analysis::{
+    AnalysisWarning, EXIT_CODE_CLEAN, PrecompileKind, Severity, TokenAuthorityType, TokenProgramKind, TokenSetupAction,
+    TransactionShape, TxAnalysis, WsolUnwrap,
+};
 use solana_tools_lite::models::instruction::Instruction;
 use solana_tools_lite::models::message::{Message, MessageAddressTableLookup, MessageHeader, MessageLegacy, MessageV0};
 use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
 use solana_tools_lite::models::hash_base58::HashBase58;
-use solana_tools_lite::constants::programs;
+use solana_tools_lite::constants::{mints, programs};
+use solana_tools_lite::serde::LookupTableEntry;
+use std::collections::HashSet;
+use std::time::Instant;
 
 #[test]
 fn analyze_legacy_system_transfer() {
@@ -34,7 +47,7 @@ fn analyze_legacy_system_transfer() {
         instructions: vec![instr],
     });
 
-    let analysis = analyze_transaction(&msg, &signer, None);
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
 
     assert_eq!(analysis.transfers.len(), 1);
     assert_eq!(analysis.total_sol_send_by_signer, 1_500);
@@ -44,6 +57,373 @@ fn analyze_legacy_system_transfer() {
     assert!(analysis.warnings.is_empty());
 }
 
+fn transfer_instruction(from: u8, to: u8, program_id_index: u8) -> Instruction {
+    let mut data = Vec::new();
+    data.extend_from_slice(&2u32.to_le_bytes()); // SystemProgram::Transfer
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    Instruction {
+        program_id_index,
+        accounts: vec![from, to],
+        data,
+    }
+}
+
+#[test]
+fn analyze_groups_signer_outflows_by_recipient() {
+    let signer = PubkeyBase58::from([1u8; 32]);
+    let alice = PubkeyBase58::from([2u8; 32]);
+    let bob = PubkeyBase58::from([3u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    let transfer = |to: u8, lamports: u64| {
+        let mut ix = transfer_instruction(0, to, 3);
+        ix.data[4..12].copy_from_slice(&lamports.to_le_bytes());
+        ix
+    };
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), alice.clone(), bob.clone(), system_program],
+        recent_blockhash: HashBase58([3u8; 32]),
+        instructions: vec![
+            transfer(1, 1_000_000_000),
+            transfer(2, 500_000_000),
+            transfer(1, 250_000_000),
+        ],
+    });
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+
+    assert_eq!(analysis.total_sol_send_by_signer, 1_750_000_000);
+    assert_eq!(analysis.signer_outflows_by_recipient.len(), 2);
+    assert_eq!(analysis.signer_outflows_by_recipient[&alice.to_string()], 1_250_000_000);
+    assert_eq!(analysis.signer_outflows_by_recipient[&bob.to_string()], 500_000_000);
+}
+
+#[test]
+fn analyze_transfer_flags_follow_header_regions() {
+    let payer = PubkeyBase58::from([1u8; 32]);
+    let readonly_signer = PubkeyBase58::from([2u8; 32]);
+    let writable_dest = PubkeyBase58::from([3u8; 32]);
+    let readonly_dest = PubkeyBase58::from([4u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    // [payer (w, s), readonly_signer (ro, s), writable_dest (w), readonly_dest (ro), system (ro)]
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 2,
+            num_readonly_signed_accounts: 1,
+            num_readonly_unsigned_accounts: 2,
+        },
+        account_keys: vec![payer.clone(), readonly_signer, writable_dest, readonly_dest, system_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![
+            transfer_instruction(0, 2, 4),
+            transfer_instruction(1, 3, 4),
+            transfer_instruction(2, 0, 4),
+        ],
+    });
+
+    let analysis = analyze_transaction(&msg, &payer, None, None, None);
+    let flags: Vec<(bool, bool, bool)> = analysis
+        .transfers
+        .iter()
+        .map(|t| (t.from_writable, t.to_writable, t.to_is_fee_payer))
+        .collect();
+
+    assert_eq!(
+        flags,
+        vec![(true, true, false), (false, false, false), (true, true, true)]
+    );
+}
+
+#[test]
+fn analyze_transfer_flags_for_lookup_accounts() {
+    let payer = PubkeyBase58::from([1u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    // Resolved list: [payer, system, lut writable, lut readonly]
+    let msg = Message::V0(MessageV0 {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![payer.clone(), system_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![transfer_instruction(0, 2, 1), transfer_instruction(0, 3, 1)],
+        address_table_lookups: vec![MessageAddressTableLookup {
+            account_key: PubkeyBase58::from([9u8; 32]),
+            writable_indexes: vec![0],
+            readonly_indexes: vec![1],
+        }],
+    });
+    let tables = LookupTableEntry {
+        writable: vec![PubkeyBase58::from([5u8; 32])],
+        readonly: vec![PubkeyBase58::from([6u8; 32])],
+        tables: vec![],
+    };
+
+    let analysis = analyze_transaction(&msg, &payer, Some(&tables), None, None);
+    let to_writable: Vec<bool> = analysis.transfers.iter().map(|t| t.to_writable).collect();
+
+    assert_eq!(to_writable, vec![true, false]);
+    assert!(analysis.transfers.iter().all(|t| t.from_writable));
+}
+
+#[test]
+fn analyze_lookup_accounts_follow_the_index_split() {
+    let payer = PubkeyBase58::from([1u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+    let writable = PubkeyBase58::from([5u8; 32]);
+    let extra = PubkeyBase58::from([7u8; 32]);
+    let readonly = PubkeyBase58::from([6u8; 32]);
+
+    // One writable and one readonly index; the tables file lists an extra writable address
+    let msg = Message::V0(MessageV0 {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![payer.clone(), system_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![transfer_instruction(0, 2, 1), transfer_instruction(0, 3, 1)],
+        address_table_lookups: vec![MessageAddressTableLookup {
+            account_key: PubkeyBase58::from([9u8; 32]),
+            writable_indexes: vec![0],
+            readonly_indexes: vec![1],
+        }],
+    });
+    let tables = LookupTableEntry {
+        writable: vec![writable.clone(), extra],
+        readonly: vec![readonly.clone()],
+        tables: vec![],
+    };
+
+    let analysis = analyze_transaction(&msg, &payer, Some(&tables), None, None);
+    let targets: Vec<(String, bool)> = analysis
+        .transfers
+        .iter()
+        .map(|t| (t.to.clone(), t.to_writable))
+        .collect();
+    assert_eq!(targets, vec![(writable.to_string(), true), (readonly.to_string(), false)]);
+
+    // A file that runs short reports the lookup instead of shifting accounts
+    let short = LookupTableEntry {
+        writable: vec![],
+        readonly: vec![readonly],
+        tables: vec![],
+    };
+    let analysis = analyze_transaction(&msg, &payer, Some(&short), None, None);
+    assert!(analysis.warnings.iter().any(|w| matches!(
+        w,
+        AnalysisWarning::LookupTableMissing(key) if key == &PubkeyBase58::from([9u8; 32])
+    )));
+}
+
+#[test]
+fn analyze_reports_declared_table_not_looked_up() {
+    let payer = PubkeyBase58::from([1u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+    let used_table = PubkeyBase58::from([9u8; 32]);
+    let unused_table = PubkeyBase58::from([8u8; 32]);
+
+    let msg = Message::V0(MessageV0 {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![payer.clone(), system_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![transfer_instruction(0, 2, 1)],
+        address_table_lookups: vec![MessageAddressTableLookup {
+            account_key: used_table.clone(),
+            writable_indexes: vec![0],
+            readonly_indexes: vec![],
+        }],
+    });
+    let tables = LookupTableEntry {
+        writable: vec![PubkeyBase58::from([5u8; 32])],
+        readonly: vec![],
+        tables: vec![used_table, unused_table.clone()],
+    };
+
+    let analysis = analyze_transaction(&msg, &payer, Some(&tables), None, None);
+    let unused: Vec<&PubkeyBase58> = analysis
+        .warnings
+        .iter()
+        .filter_map(|w| match w {
+            AnalysisWarning::UnusedLookupTable { table } => Some(table),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(unused, vec![&unused_table]);
+}
+
+#[test]
+fn analyze_warns_when_required_signer_is_absent_from_instructions() {
+    let fee_payer = PubkeyBase58::from([1u8; 32]);
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let recipient = PubkeyBase58::from([2u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    // `signer` is in the signer region, but the only transfer is fee payer -> recipient
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 2,
+            num_readonly_signed_accounts: 1,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![fee_payer.clone(), signer.clone(), recipient, system_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![transfer_instruction(0, 2, 3)],
+    });
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+    let flagged = |a: &TxAnalysis| {
+        a.warnings
+            .iter()
+            .any(|w| matches!(w, AnalysisWarning::SignerNotReferencedByInstructions))
+    };
+    assert!(flagged(&analysis));
+    assert!(!analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::SignerNotRequired)));
+
+    // The fee payer is referenced by the transfer
+    assert!(!flagged(&analyze_transaction(&msg, &fee_payer, None, None, None)));
+}
+
+#[test]
+fn drop_signer_check_warnings_keeps_other_warnings() {
+    let signer = PubkeyBase58::from([10u8; 32]);
+    let placeholder = PubkeyBase58::from([11u8; 32]);
+    let unknown = PubkeyBase58::from([12u8; 32]);
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer, unknown.clone()],
+        recent_blockhash: HashBase58([3u8; 32]),
+        instructions: vec![Instruction { program_id_index: 1, accounts: vec![0], data: vec![] }],
+    });
+
+    let mut analysis = analyze_transaction(&msg, &placeholder, None, None, None);
+    assert!(analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::SignerNotRequired)));
+
+    analysis.drop_signer_check_warnings();
+    assert!(!analysis.warnings.iter().any(|w| matches!(
+        w,
+        AnalysisWarning::SignerNotRequired | AnalysisWarning::SignerNotReferencedByInstructions
+    )));
+    assert!(analysis.warnings.iter().any(
+        |w| matches!(w, AnalysisWarning::UnknownProgram { program_id } if *program_id == unknown)
+    ));
+}
+
+#[test]
+fn account_writability_covers_legacy_regions() {
+    // [writable signer, readonly signer, writable unsigned, readonly unsigned]
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 2,
+            num_readonly_signed_accounts: 1,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: (1u8..=4).map(|n| PubkeyBase58::from([n; 32])).collect(),
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![],
+    });
+
+    let flags: Vec<bool> = (0..5).map(|i| is_account_writable(&msg, i)).collect();
+    assert_eq!(flags, vec![true, false, true, false, false]);
+}
+
+#[test]
+fn account_writability_covers_v0_lookup_indexes() {
+    // Static: [payer (w, s), program (ro)]; lookups resolve to
+    // [A writable x2, B writable x1, A readonly x1, B readonly x2]
+    let msg = Message::V0(MessageV0 {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![PubkeyBase58::from([1u8; 32]), PubkeyBase58::from([2u8; 32])],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![],
+        address_table_lookups: vec![
+            MessageAddressTableLookup {
+                account_key: PubkeyBase58::from([9u8; 32]),
+                writable_indexes: vec![0, 1],
+                readonly_indexes: vec![2],
+            },
+            MessageAddressTableLookup {
+                account_key: PubkeyBase58::from([10u8; 32]),
+                writable_indexes: vec![4],
+                readonly_indexes: vec![5, 6],
+            },
+        ],
+    });
+
+    let flags: Vec<bool> = (0..9).map(|i| is_account_writable(&msg, i)).collect();
+    assert_eq!(
+        flags,
+        vec![true, false, true, true, true, false, false, false, false]
+    );
+}
+
+#[test]
+fn analyze_reports_v0_transaction_shape() {
+    let payer = PubkeyBase58::from([1u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    let msg = Message::V0(MessageV0 {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![payer.clone(), system_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![transfer_instruction(0, 2, 1), transfer_instruction(0, 3, 1)],
+        address_table_lookups: vec![
+            MessageAddressTableLookup {
+                account_key: PubkeyBase58::from([9u8; 32]),
+                writable_indexes: vec![0],
+                readonly_indexes: vec![1],
+            },
+            MessageAddressTableLookup {
+                account_key: PubkeyBase58::from([8u8; 32]),
+                writable_indexes: vec![],
+                readonly_indexes: vec![0, 2],
+            },
+        ],
+    });
+
+    let analysis = analyze_transaction(&msg, &payer, None, None, None);
+    assert_eq!(
+        analysis.shape,
+        TransactionShape {
+            account_count: 6,
+            static_account_count: 2,
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+            instruction_count: 2,
+            lookup_table_count: 2,
+        }
+    );
+}
+
 #[test]
 fn analyze_v0_missing_lookup_tables_warns() {
     let signer = PubkeyBase58::from([3u8; 32]);
@@ -66,10 +446,48 @@ fn analyze_v0_missing_lookup_tables_warns() {
         }],
     });
 
-    let analysis = analyze_transaction(&msg, &signer, None);
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
     assert!(analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::LookupTableNotProvided)));
 }
 
+#[test]
+fn analyze_signer_only_in_lookup_table_warns() {
+    let fee_payer = PubkeyBase58::from([2u8; 32]);
+    let signer = PubkeyBase58::from([3u8; 32]);
+    let lookup_key = PubkeyBase58::from([4u8; 32]);
+
+    let msg = Message::V0(MessageV0 {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 0,
+        },
+        account_keys: vec![fee_payer],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: Vec::new(),
+        address_table_lookups: vec![MessageAddressTableLookup {
+            account_key: lookup_key,
+            writable_indexes: vec![0],
+            readonly_indexes: vec![],
+        }],
+    });
+
+    let tables = LookupTableEntry {
+        writable: vec![signer.clone()],
+        readonly: vec![],
+        tables: vec![],
+    };
+
+    let analysis = analyze_transaction(&msg, &signer, Some(&tables), None, None);
+    assert!(analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::SignerNotRequired)));
+    assert!(analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::SignerFromLookupTable)));
+
+    // A signer that is simply absent does not get the lookup warning
+    let stranger = PubkeyBase58::from([9u8; 32]);
+    let analysis = analyze_transaction(&msg, &stranger, Some(&tables), None, None);
+    assert!(!analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::SignerFromLookupTable)));
+}
+
 #[test]
 fn analyze_compute_budget_sets_priority_fee() {
     let signer = PubkeyBase58::from([9u8; 32]);
@@ -104,10 +522,54 @@ fn analyze_compute_budget_sets_priority_fee() {
         ],
     });
 
-    let analysis = analyze_transaction(&msg, &signer, None);
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
     let (fee, estimated) = analysis.priority_fee_lamports.expect("priority fee expected");
     assert!(!estimated, "limit provided, fee should not be estimated");
     assert!(fee > 0, "priority fee should be positive");
+    // price_micro * limit / 1_000_000: 10_000 * 300_000 / 1_000_000
+    assert_eq!(fee, 3_000);
+    assert_eq!(fee, priority_fee_for(10_000, 300_000));
+    assert_eq!(analysis.compute_unit_price_micro, Some(10_000));
+    assert_eq!(format_micro_lamports(10_000), "0.01");
+    assert_eq!(format_micro_lamports(1_500_000), "1.5");
+    assert_eq!(format_micro_lamports(2_000_000), "2");
+    assert!(
+        !analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::PriorityFeeEstimated { .. }))
+    );
+}
+
+#[test]
+fn analyze_price_without_limit_warns_fee_estimated() {
+    let signer = PubkeyBase58::from([9u8; 32]);
+    let program = PubkeyBase58::try_from(programs::COMPUTE_BUDGET_ID).unwrap();
+
+    let mut data_price = vec![3u8];
+    data_price.extend_from_slice(&10_000u64.to_le_bytes());
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), program],
+        recent_blockhash: HashBase58([7u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 1,
+            accounts: vec![],
+            data: data_price,
+        }],
+    });
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+    let (fee, estimated) = analysis.priority_fee_lamports.expect("priority fee expected");
+    assert!(estimated, "no limit set, fee should be estimated");
+    // 10_000 micro-lamports * 200_000 default CU
+    assert_eq!(fee, 2_000);
+    assert!(analysis.warnings.iter().any(|w| matches!(
+        w,
+        AnalysisWarning::PriorityFeeEstimated { assumed_limit: 200_000 }
+    )));
 }
 
 
@@ -152,7 +614,7 @@ fn analyze_token_and_unknown_programs_warn() {
         ],
     });
 
-    let analysis = analyze_transaction(&msg, &signer, None);
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
 
     assert!(analysis.warnings.iter().any(|w| {
         matches!(
@@ -170,3 +632,1359 @@ fn analyze_token_and_unknown_programs_warn() {
         matches!(w, AnalysisWarning::UnknownProgram { program_id } if *program_id == unknown_program)
     }));
 }
+
+#[test]
+fn sorted_warnings_are_deterministic() {
+    let signer = PubkeyBase58::from([10u8; 32]);
+    let outsider = PubkeyBase58::from([11u8; 32]);
+    let token_program = PubkeyBase58::try_from(programs::TOKEN_PROGRAM_ID).unwrap();
+    let unknown_a = PubkeyBase58::from([12u8; 32]);
+    let unknown_b = PubkeyBase58::from([13u8; 32]);
+    let call = |program_id_index| Instruction { program_id_index, accounts: vec![0], data: vec![] };
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 3,
+        },
+        account_keys: vec![signer, unknown_b, token_program, unknown_a],
+        recent_blockhash: HashBase58([3u8; 32]),
+        instructions: vec![call(1), call(2), call(3)],
+    });
+
+    // Analyzed as a key that is not a signer: critical + warn + info warnings
+    let mut analysis = analyze_transaction(&msg, &outsider, None, None, None);
+    analysis.sort_warnings();
+    let sorted: Vec<String> = analysis.warnings.iter().map(|w| format!("{:?}", w)).collect();
+
+    let severities: Vec<Severity> = analysis.warnings.iter().map(|w| w.severity()).collect();
+    assert!(severities.windows(2).all(|pair| pair[0] >= pair[1]), "most severe first");
+    assert!(matches!(analysis.warnings[0], AnalysisWarning::SignerNotRequired));
+    assert!(matches!(
+        analysis.warnings.last(),
+        Some(AnalysisWarning::TokenTransferDetected(TokenProgramKind::SplToken))
+    ));
+
+    // Detection order does not matter
+    analysis.warnings.reverse();
+    analysis.sort_warnings();
+    let resorted: Vec<String> = analysis.warnings.iter().map(|w| format!("{:?}", w)).collect();
+    assert_eq!(sorted, resorted);
+}
+
+#[test]
+fn analyze_keeps_raw_data_of_unknown_instructions() {
+    let signer = PubkeyBase58::from([10u8; 32]);
+    let unknown_program = PubkeyBase58::from([12u8; 32]);
+    let data = vec![0xde, 0xad, 0xbe, 0xef, 0x01];
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), unknown_program.clone()],
+        recent_blockhash: HashBase58([3u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: data.clone(),
+        }],
+    });
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+    assert_eq!(analysis.unknown_instructions.len(), 1);
+    let raw = &analysis.unknown_instructions[0];
+    assert_eq!(raw.program_id, unknown_program.to_string());
+    assert_eq!(
+        solana_tools_lite::data_encoding::BASE64.decode(raw.data_base64.as_bytes()).unwrap(),
+        data
+    );
+
+    // Trusted programs are not retained
+    let trusted: HashSet<PubkeyBase58> = [unknown_program.clone()].into_iter().collect();
+    let analysis = analyze_transaction(&msg, &signer, None, Some(&trusted), None);
+    assert!(analysis.unknown_instructions.is_empty());
+
+    // ...and do not use up the capped slots ahead of untrusted ones
+    let untrusted = PubkeyBase58::from([13u8; 32]);
+    let mut instructions: Vec<Instruction> = (0..20u8)
+        .map(|i| Instruction { program_id_index: 1, accounts: vec![0], data: vec![i] })
+        .collect();
+    instructions.push(Instruction { program_id_index: 2, accounts: vec![0], data: data.clone() });
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 2,
+        },
+        account_keys: vec![signer.clone(), unknown_program, untrusted.clone()],
+        recent_blockhash: HashBase58([3u8; 32]),
+        instructions,
+    });
+    let analysis = analyze_transaction(&msg, &signer, None, Some(&trusted), None);
+    let kept: Vec<&str> = analysis.unknown_instructions.iter().map(|ix| ix.program_id.as_str()).collect();
+    assert_eq!(kept, vec![untrusted.to_string().as_str()]);
+}
+
+#[test]
+fn analyze_precompile_instructions_are_detected() {
+    let signer = PubkeyBase58::from([10u8; 32]);
+    let ed25519 = PubkeyBase58::try_from(programs::ED25519_PROGRAM_ID).unwrap();
+    let secp256k1 = PubkeyBase58::try_from(programs::SECP256K1_PROGRAM_ID).unwrap();
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 2,
+        },
+        account_keys: vec![signer.clone(), ed25519, secp256k1],
+        recent_blockhash: HashBase58([3u8; 32]),
+        instructions: vec![
+            Instruction {
+                program_id_index: 1,
+                accounts: vec![],
+                data: vec![1, 0],
+            },
+            Instruction {
+                program_id_index: 2,
+                accounts: vec![],
+                data: vec![1],
+            },
+        ],
+    });
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+
+    for expected in [PrecompileKind::Ed25519, PrecompileKind::Secp256k1] {
+        assert!(analysis.warnings.iter().any(|w| {
+            matches!(w, AnalysisWarning::PrecompileSignatureInstruction { kind } if *kind == expected)
+        }));
+    }
+    assert!(
+        !analysis
+            .warnings
+            .iter()
+            .any(|w| matches!(w, AnalysisWarning::UnknownProgram { .. })),
+        "precompiles must not be reported as unknown programs"
+    );
+}
+
+#[test]
+fn analyze_allowlist_suppresses_only_trusted_unknown_programs() {
+    let signer = PubkeyBase58::from([10u8; 32]);
+    let trusted_program = PubkeyBase58::from([12u8; 32]);
+    let other_program = PubkeyBase58::from([13u8; 32]);
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 2,
+        },
+        account_keys: vec![signer.clone(), trusted_program.clone(), other_program.clone()],
+        recent_blockhash: HashBase58([3u8; 32]),
+        instructions: vec![
+            Instruction {
+                program_id_index: 1,
+                accounts: vec![0],
+                data: vec![1],
+            },
+            Instruction {
+                program_id_index: 2,
+                accounts: vec![0],
+                data: vec![2],
+            },
+        ],
+    });
+
+    let allowlist = HashSet::from([trusted_program.clone()]);
+    let analysis = analyze_transaction(&msg, &signer, None, Some(&allowlist), None);
+
+    let unknown: Vec<&PubkeyBase58> = analysis
+        .warnings
+        .iter()
+        .filter_map(|w| match w {
+            AnalysisWarning::UnknownProgram { program_id } => Some(program_id),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(unknown, vec![&other_program]);
+
+    // Without the allowlist both programs are reported
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+    let unknown_count = analysis
+        .warnings
+        .iter()
+        .filter(|w| matches!(w, AnalysisWarning::UnknownProgram { .. }))
+        .count();
+    assert_eq!(unknown_count, 2);
+}
+
+#[test]
+fn analyze_repeated_identical_transfers_warns() {
+    let signer = PubkeyBase58::from([20u8; 32]);
+    let recipient = PubkeyBase58::from([21u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&2u32.to_le_bytes()); // SystemProgram::Transfer
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+
+    let instr = Instruction {
+        program_id_index: 2,
+        accounts: vec![0, 1],
+        data,
+    };
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), recipient, system_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![instr; 10],
+    });
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+
+    assert_eq!(analysis.transfers.len(), 10);
+    let repeated: Vec<_> = analysis
+        .warnings
+        .iter()
+        .filter(|w| matches!(w, AnalysisWarning::RepeatedInstruction { .. }))
+        .collect();
+    assert_eq!(repeated.len(), 1);
+    assert!(matches!(
+        repeated[0],
+        AnalysisWarning::RepeatedInstruction { count: 10 }
+    ));
+}
+
+#[test]
+fn analyze_few_identical_instructions_do_not_warn() {
+    let signer = PubkeyBase58::from([22u8; 32]);
+    let recipient = PubkeyBase58::from([23u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&2u32.to_le_bytes());
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), recipient, system_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![
+            Instruction {
+                program_id_index: 2,
+                accounts: vec![0, 1],
+                data,
+            };
+            2
+        ],
+    });
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+
+    assert!(!analysis
+        .warnings
+        .iter()
+        .any(|w| matches!(w, AnalysisWarning::RepeatedInstruction { .. })));
+}
+
+fn close_account_message(signer: &PubkeyBase58, destination: Option<&PubkeyBase58>) -> Message {
+    let token_account = PubkeyBase58::from([21u8; 32]);
+    let token_program = PubkeyBase58::try_from(programs::TOKEN_PROGRAM_ID).unwrap();
+
+    // accounts: [account, destination, owner]; `None` sends the rent back to the signer
+    let (account_keys, close_accounts) = match destination {
+        Some(dest) => (
+            vec![signer.clone(), token_account, dest.clone(), token_program],
+            vec![1, 2, 0],
+        ),
+        None => (vec![signer.clone(), token_account, token_program], vec![1, 0, 0]),
+    };
+    let program_id_index = (account_keys.len() - 1) as u8;
+
+    Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys,
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index,
+            accounts: close_accounts,
+            data: vec![9], // CloseAccount
+        }],
+    })
+}
+
+#[test]
+fn analyze_token_close_account_to_other_warns() {
+    let signer = PubkeyBase58::from([20u8; 32]);
+    let attacker = PubkeyBase58::from([22u8; 32]);
+
+    let analysis = analyze_transaction(&close_account_message(&signer, Some(&attacker)), &signer, None, None, None);
+    assert!(analysis.warnings.iter().any(|w| {
+        matches!(w, AnalysisWarning::TokenAccountClosedToOther { destination } if *destination == attacker)
+    }));
+
+    let analysis = analyze_transaction(&close_account_message(&signer, None), &signer, None, None, None);
+    assert!(!analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::TokenAccountClosedToOther { .. })));
+}
+
+#[test]
+fn analyze_wsol_close_account_returns_sol_to_signer() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let wsol_account = PubkeyBase58::from([21u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+    let token_program = PubkeyBase58::try_from(programs::TOKEN_PROGRAM_ID).unwrap();
+    let ata_program = PubkeyBase58::try_from(programs::ASSOCIATED_TOKEN_PROGRAM_ID).unwrap();
+    let native_mint = PubkeyBase58::try_from(mints::NATIVE_MINT_ID).unwrap();
+
+    // Wrap 1_000 lamports into the signer's wSOL ATA, sync it, then unwrap back to the signer
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 4,
+        },
+        account_keys: vec![
+            signer.clone(),
+            wsol_account.clone(),
+            system_program,
+            token_program,
+            ata_program,
+            native_mint,
+        ],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![
+            Instruction { program_id_index: 4, accounts: vec![0, 1, 0, 5, 2, 3], data: vec![1] },
+            transfer_instruction(0, 1, 2),
+            Instruction { program_id_index: 3, accounts: vec![1], data: vec![17] }, // SyncNative
+            Instruction { program_id_index: 3, accounts: vec![1, 0, 0], data: vec![9] }, // CloseAccount
+        ],
+    });
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+    assert_eq!(
+        analysis.wsol_unwraps,
+        vec![WsolUnwrap {
+            account: wsol_account,
+            destination: signer.clone(),
+            wrapped_lamports: 1_000,
+            to_signer: true,
+        }]
+    );
+    assert_eq!(analysis.total_sol_send_by_signer, 1_000);
+    assert_eq!(analysis.sol_returned_to_signer(), 1_000);
+    assert_eq!(analysis.net_sol_send_by_signer(), 0);
+    assert!(!analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::TokenAccountClosedToOther { .. })));
+
+    // A token account not known to hold wSOL is not an unwrap
+    let analysis = analyze_transaction(&close_account_message(&signer, None), &signer, None, None, None);
+    assert!(analysis.wsol_unwraps.is_empty());
+}
+
+fn stake_withdraw_message(signer: &PubkeyBase58, recipient: &PubkeyBase58) -> Message {
+    let stake_account = PubkeyBase58::from([21u8; 32]);
+    let clock = PubkeyBase58::from([23u8; 32]);
+    let stake_history = PubkeyBase58::from([24u8; 32]);
+    let stake_program = PubkeyBase58::try_from(programs::STAKE_PROGRAM_ID).unwrap();
+
+    let mut data = 4u32.to_le_bytes().to_vec(); // Withdraw
+    data.extend_from_slice(&7_000_000_000u64.to_le_bytes());
+
+    Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 3,
+        },
+        account_keys: vec![
+            signer.clone(),
+            stake_account,
+            recipient.clone(),
+            clock,
+            stake_history,
+            stake_program,
+        ],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 5,
+            // [stake, recipient, clock, stake_history, withdraw_authority]
+            accounts: vec![1, 2, 3, 4, 0],
+            data,
+        }],
+    })
+}
+
+#[test]
+fn analyze_stake_withdraw_to_other_warns() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let attacker = PubkeyBase58::from([22u8; 32]);
+
+    let analysis = analyze_transaction(&stake_withdraw_message(&signer, &attacker), &signer, None, None, None);
+    assert!(analysis.warnings.iter().any(|w| {
+        matches!(
+            w,
+            AnalysisWarning::StakeWithdrawToOther { recipient, lamports: 7_000_000_000 } if *recipient == attacker
+        )
+    }));
+    assert!(analysis.has_critical_warnings());
+    assert!(!analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::UnknownProgram { .. })));
+
+    let analysis = analyze_transaction(&stake_withdraw_message(&signer, &signer), &signer, None, None, None);
+    assert!(!analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::StakeWithdrawToOther { .. })));
+}
+
+#[test]
+fn analyze_token_setup_decodes_mint2_and_account3() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let mint = PubkeyBase58::from([21u8; 32]);
+    let token_account = PubkeyBase58::from([23u8; 32]);
+    let freeze = PubkeyBase58::from([24u8; 32]);
+    let token_program = PubkeyBase58::try_from(programs::TOKEN_PROGRAM_ID).unwrap();
+
+    // InitializeMint2: tag, decimals, mint authority, COption<freeze authority>
+    let mut mint_data = vec![20, 6];
+    mint_data.extend_from_slice(signer.as_bytes());
+    mint_data.push(1);
+    mint_data.extend_from_slice(freeze.as_bytes());
+    // InitializeAccount3: tag, owner
+    let mut account_data = vec![18];
+    account_data.extend_from_slice(signer.as_bytes());
+
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), mint.clone(), token_account.clone(), token_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![
+            Instruction { program_id_index: 3, accounts: vec![1], data: mint_data },
+            Instruction { program_id_index: 3, accounts: vec![2, 1], data: account_data },
+        ],
+    });
+
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
+    assert_eq!(
+        analysis.token_setup,
+        vec![
+            TokenSetupAction::InitializeMint {
+                mint: mint.clone(),
+                decimals: 6,
+                mint_authority: signer.clone(),
+                freeze_authority: Some(freeze),
+            },
+            TokenSetupAction::InitializeAccount { account: token_account, mint, owner: signer },
+        ]
+    );
+}
+
+#[test]
+fn analyze_squads_instruction_warns_multisig() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let multisig = PubkeyBase58::from([21u8; 32]);
+    let squads = PubkeyBase58::try_from(programs::SQUADS_V4_PROGRAM_ID).unwrap();
+
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), multisig, squads.clone()],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![
+            Instruction { program_id_index: 2, accounts: vec![1, 0], data: vec![1, 2, 3] },
+            Instruction { program_id_index: 2, accounts: vec![1, 0], data: vec![4, 5, 6] },
+        ],
+    });
+
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
+    let detected: Vec<_> = analysis
+        .warnings
+        .iter()
+        .filter(|w| matches!(w, AnalysisWarning::MultisigProgramDetected { program } if *program == squads))
+        .collect();
+    assert_eq!(detected.len(), 1, "one warning per program: {:?}", analysis.warnings);
+    assert!(!analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::UnknownProgram { .. })));
+    assert!(!analysis.has_critical_warnings());
+}
+
+#[test]
+fn analyze_instruction_without_accounts_warns() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+    let compute_budget = PubkeyBase58::try_from(programs::COMPUTE_BUDGET_ID).unwrap();
+    let memo_v1 = PubkeyBase58::try_from(programs::MEMO_V1_PROGRAM_ID).unwrap();
+    let memo = PubkeyBase58::try_from(programs::MEMO_PROGRAM_ID).unwrap();
+
+    let mut set_limit = vec![2];
+    set_limit.extend_from_slice(&200_000u32.to_le_bytes());
+    let mut transfer = 2u32.to_le_bytes().to_vec();
+    transfer.extend_from_slice(&1_000u64.to_le_bytes());
+
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 4,
+        },
+        account_keys: vec![signer.clone(), system_program, compute_budget, memo_v1, memo],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![
+            // Compute Budget never takes accounts: no warning
+            Instruction { program_id_index: 2, accounts: vec![], data: set_limit },
+            Instruction { program_id_index: 1, accounts: vec![], data: transfer },
+            // Neither do plain memos (v1 and v2)
+            Instruction { program_id_index: 3, accounts: vec![], data: b"order 42".to_vec() },
+            Instruction { program_id_index: 4, accounts: vec![], data: b"order 42".to_vec() },
+        ],
+    });
+
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
+    let flagged: Vec<usize> = analysis
+        .warnings
+        .iter()
+        .filter_map(|w| match w {
+            AnalysisWarning::InstructionNoAccounts { instruction_index } => Some(*instruction_index),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(flagged, vec![1]);
+    assert!(analysis.transfers.is_empty());
+}
+
+#[test]
+fn analyze_duplicate_signer_key_warns_once() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let recipient = PubkeyBase58::from([2u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    // The signer appears as account #0 and again as #2 (and #3)
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), recipient, signer.clone(), signer.clone(), system_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![transfer_instruction(2, 1, 4)],
+    });
+
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
+    let duplicates: Vec<&PubkeyBase58> = analysis
+        .warnings
+        .iter()
+        .filter_map(|w| match w {
+            AnalysisWarning::DuplicateAccountKey { key } => Some(key),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(duplicates, vec![&signer]);
+}
+
+#[test]
+fn analyze_duplicate_key_on_fast_path_shape_warns() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    // One small transfer between [signer, signer, system]: otherwise the fast-path shape
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), signer.clone(), system_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![transfer_instruction(0, 1, 2)],
+    });
+
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
+    assert!(
+        analysis
+            .warnings
+            .iter()
+            .any(|w| matches!(w, AnalysisWarning::DuplicateAccountKey { key } if key == &signer))
+    );
+}
+
+fn token_delegate_message(signer: &PubkeyBase58, delegate: &PubkeyBase58, data: Vec<u8>) -> Message {
+    let token_account = PubkeyBase58::from([21u8; 32]);
+    let token_program = PubkeyBase58::try_from(programs::TOKEN_PROGRAM_ID).unwrap();
+
+    Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 2,
+        },
+        account_keys: vec![signer.clone(), token_account, delegate.clone(), token_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 3,
+            // Approve: [source, delegate, owner]; Revoke: [source, owner]
+            accounts: if data.first() == Some(&5) { vec![1, 0] } else { vec![1, 2, 0] },
+            data,
+        }],
+    })
+}
+
+#[test]
+fn analyze_token_approve_warns_with_delegate_and_amount() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let delegate = PubkeyBase58::from([22u8; 32]);
+
+    let mut data = vec![4]; // Approve
+    data.extend_from_slice(&u64::MAX.to_le_bytes());
+    let analysis = analyze_transaction(&token_delegate_message(&signer, &delegate, data), &signer, None, None, None);
+
+    assert!(analysis.warnings.iter().any(|w| {
+        matches!(w, AnalysisWarning::TokenDelegateApproved { delegate: d, amount } if *d == delegate && *amount == u64::MAX)
+    }));
+
+    // ApproveChecked carries the mint before the delegate
+    let mut checked = vec![13];
+    checked.extend_from_slice(&500u64.to_le_bytes());
+    checked.push(6);
+    assert!(matches!(
+        solana_tools_lite::codec::decode_token_delegate(&checked),
+        solana_tools_lite::codec::TokenDelegateAction::Approve { delegate_index: 2, amount: 500 }
+    ));
+}
+
+#[test]
+fn analyze_token_revoke_does_not_warn() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let delegate = PubkeyBase58::from([22u8; 32]);
+
+    let analysis = analyze_transaction(&token_delegate_message(&signer, &delegate, vec![5]), &signer, None, None, None);
+
+    assert!(!analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::TokenDelegateApproved { .. })));
+    assert!(!analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::MalformedInstruction)));
+}
+
+fn padded_transfer_message(signer: &PubkeyBase58, padding: usize) -> Message {
+    let recipient = PubkeyBase58::from([22u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&2u32.to_le_bytes());
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.resize(data.len() + padding, 0);
+
+    Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), recipient, system_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 2,
+            accounts: vec![0, 1],
+            data,
+        }],
+    })
+}
+
+#[test]
+fn analyze_past_deadline_is_truncated() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let recipient = PubkeyBase58::from([22u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), recipient, system_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: (0..40u64)
+            .map(|i| {
+                let mut data = 2u32.to_le_bytes().to_vec();
+                data.extend_from_slice(&(i + 1).to_le_bytes());
+                Instruction { program_id_index: 2, accounts: vec![0, 1], data }
+            })
+            .collect(),
+    });
+    let truncated = |a: &TxAnalysis| {
+        a.warnings
+            .iter()
+            .any(|w| matches!(w, AnalysisWarning::AnalysisTruncatedByDeadline))
+    };
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, Some(Instant::now()));
+    assert!(truncated(&analysis));
+    assert!(analysis.total_sol_send_by_signer < 820);
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+    assert!(!truncated(&analysis));
+    assert_eq!(analysis.total_sol_send_by_signer, 820); // 1 + 2 + ... + 40
+}
+
+#[test]
+fn analyze_misordered_account_keys_warns() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let recipient = PubkeyBase58::from([22u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+    let suspect = |w: &AnalysisWarning| matches!(w, AnalysisWarning::AccountOrderingSuspect { .. });
+
+    // The program id sits before the recipient, so it falls into the writable region
+    let mut message = padded_transfer_message(&signer, 0);
+    if let Message::Legacy(m) = &mut message {
+        m.account_keys = vec![signer.clone(), system_program, recipient];
+        m.instructions[0].program_id_index = 1;
+        m.instructions[0].accounts = vec![0, 2];
+    }
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
+    assert!(analysis.warnings.iter().any(|w| {
+        matches!(w, AnalysisWarning::AccountOrderingSuspect { index: 1 })
+    }));
+
+    // Canonical order: no warning
+    let analysis = analyze_transaction(&padded_transfer_message(&signer, 0), &signer, None, None, None);
+    assert!(!analysis.warnings.iter().any(suspect));
+}
+
+#[test]
+fn analyze_transaction_over_packet_limit_warns() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let too_large = |w: &AnalysisWarning| matches!(w, AnalysisWarning::TransactionTooLarge { .. });
+
+    let analysis = analyze_transaction(&padded_transfer_message(&signer, 0), &signer, None, None, None);
+    assert!(!analysis.warnings.iter().any(too_large));
+
+    let message = padded_transfer_message(&signer, 1232);
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
+    let size = 1 + 64 + solana_tools_lite::codec::serialize_message(&message).len();
+    assert!(size > 1232);
+    assert!(analysis.warnings.iter().any(|w| {
+        matches!(w, AnalysisWarning::TransactionTooLarge { size: s } if *s == size)
+    }));
+}
+
+fn set_authority_message(signer: &PubkeyBase58, authority_type: u8, new_authority: &PubkeyBase58) -> Message {
+    let mint = PubkeyBase58::from([21u8; 32]);
+    let token_program = PubkeyBase58::try_from(programs::TOKEN_PROGRAM_ID).unwrap();
+
+    // authority type + COption::Some(new authority)
+    let mut data = vec![6, authority_type, 1];
+    data.extend_from_slice(new_authority.as_bytes());
+
+    Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), mint, token_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 2,
+            accounts: vec![1, 0], // [mint, current authority]
+            data,
+        }],
+    })
+}
+
+#[test]
+fn analyze_set_authority_to_other_is_critical() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let attacker = PubkeyBase58::from([22u8; 32]);
+
+    let analysis = analyze_transaction(&set_authority_message(&signer, 0, &attacker), &signer, None, None, None);
+    assert!(analysis.warnings.iter().any(|w| matches!(
+        w,
+        AnalysisWarning::SetAuthorityToOther { authority_type: TokenAuthorityType::MintTokens, new_authority }
+            if *new_authority == attacker
+    )));
+    assert!(analysis.has_critical_warnings());
+}
+
+#[test]
+fn analyze_set_authority_to_self_is_informational() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+
+    let analysis = analyze_transaction(&set_authority_message(&signer, 1, &signer), &signer, None, None, None);
+    assert!(analysis.warnings.iter().any(|w| matches!(
+        w,
+        AnalysisWarning::SetAuthorityToSelf { authority_type: TokenAuthorityType::FreezeAccount }
+    )));
+    assert!(!analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::SetAuthorityToOther { .. })));
+}
+
+#[test]
+fn analyze_withdraw_nonce_to_signer_is_incoming_transfer() {
+    // Signer keys must be valid curve points to avoid the off-curve warning
+    let signer = PubkeyBase58::from([29u8; 32]);
+    let nonce_account = PubkeyBase58::from([31u8; 32]);
+    let authority = PubkeyBase58::from([32u8; 32]);
+    let recent_blockhashes_sysvar = PubkeyBase58::from([33u8; 32]);
+    let rent_sysvar = PubkeyBase58::from([34u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&5u32.to_le_bytes()); // SystemProgram::WithdrawNonceAccount
+    data.extend_from_slice(&42_000u64.to_le_bytes());
+
+    // Accounts: nonce, recipient, recent blockhashes sysvar, rent sysvar, nonce authority
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 2,
+            num_readonly_signed_accounts: 1,
+            num_readonly_unsigned_accounts: 3,
+        },
+        account_keys: vec![
+            signer.clone(),
+            authority,
+            nonce_account.clone(),
+            recent_blockhashes_sysvar,
+            rent_sysvar,
+            system_program,
+        ],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 5,
+            accounts: vec![2, 0, 3, 4, 1],
+            data,
+        }],
+    });
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+
+    assert_eq!(analysis.transfers.len(), 1);
+    let transfer = &analysis.transfers[0];
+    assert_eq!(transfer.from, nonce_account.to_string());
+    assert_eq!(transfer.to, signer.to_string());
+    assert_eq!(transfer.lamports, 42_000);
+    assert!(!transfer.from_is_signer);
+    assert_eq!(analysis.total_sol_send_by_signer, 0);
+    assert!(analysis.warnings.is_empty());
+}
+
+fn small_transfer_message(signer: &PubkeyBase58, lamports: u64) -> Message {
+    let recipient = PubkeyBase58::from([41u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&2u32.to_le_bytes()); // SystemProgram::Transfer
+    data.extend_from_slice(&lamports.to_le_bytes());
+
+    Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), recipient, system_program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 2,
+            accounts: vec![0, 1],
+            data,
+        }],
+    })
+}
+
+#[test]
+fn analyze_fast_path_matches_full_path() {
+    let signer = PubkeyBase58::from([40u8; 32]);
+    let msg = small_transfer_message(&signer, 25_000);
+
+    let fast = analyze_fast_path(&msg, &signer).expect("shape should take the fast path");
+    let full = analyze_transaction_full(&msg, &signer, None, None, None);
+
+    assert_eq!(format!("{fast:?}"), format!("{full:?}"));
+    assert_eq!(
+        format!("{:?}", analyze_transaction(&msg, &signer, None, None, None)),
+        format!("{full:?}")
+    );
+}
+
+#[test]
+fn analyze_fast_path_declines_other_shapes() {
+    let signer = PubkeyBase58::from([40u8; 32]);
+    let other = PubkeyBase58::from([42u8; 32]);
+
+    // Large amount
+    let msg = small_transfer_message(&signer, 5_000_000_000);
+    assert!(analyze_fast_path(&msg, &signer).is_none());
+
+    // Sender is not the analyzed signer
+    let msg = small_transfer_message(&signer, 25_000);
+    assert!(analyze_fast_path(&msg, &other).is_none());
+
+    // Duplicate account keys
+    let mut msg = small_transfer_message(&signer, 25_000);
+    if let Message::Legacy(m) = &mut msg {
+        m.account_keys[1] = signer.clone();
+    }
+    assert!(analyze_fast_path(&msg, &signer).is_none());
+
+    // Data beyond the scan window
+    let mut msg = small_transfer_message(&signer, 25_000);
+    if let Message::Legacy(m) = &mut msg {
+        m.instructions[0].data.resize(DEFAULT_MAX_SCANNED_DATA_LEN + 1, 0);
+    }
+    assert!(analyze_fast_path(&msg, &signer).is_none());
+}
+
+#[test]
+fn analyze_message_decoded_from_wire_bytes() {
+    use solana_tools_lite::codec::serialize_message;
+
+    let signer = PubkeyBase58::from([50u8; 32]);
+    let original = small_transfer_message(&signer, 7_000);
+    let wire = serialize_message(&original);
+
+    let decoded = Message::from_wire_bytes(&wire).expect("decode message-only bytes");
+    let analysis = analyze_transaction(&decoded, &signer, None, None, None);
+
+    assert_eq!(analysis.transfers.len(), 1);
+    assert_eq!(analysis.total_sol_send_by_signer, 7_000);
+    assert!(analysis.is_fee_payer);
+
+    // Trailing bytes are rejected
+    let mut padded = wire.clone();
+    padded.push(0);
+    assert!(Message::from_wire_bytes(&padded).is_err());
+}
+
+#[test]
+fn warning_severity_classification() {
+    let pk = PubkeyBase58::from([1u8; 32]);
+    let cases = vec![
+        (AnalysisWarning::LookupTableMissing(pk.clone()), Severity::Warn),
+        (AnalysisWarning::LookupTableNotProvided, Severity::Warn),
+        (AnalysisWarning::TokenTransferDetected(TokenProgramKind::SplToken), Severity::Info),
+        (AnalysisWarning::TokenAccountClosedToOther { destination: pk.clone() }, Severity::Critical),
+        (AnalysisWarning::TokenDelegateApproved { delegate: pk.clone(), amount: 1 }, Severity::Critical),
+        (
+            AnalysisWarning::SetAuthorityToOther {
+                authority_type: TokenAuthorityType::MintTokens,
+                new_authority: pk.clone(),
+            },
+            Severity::Critical,
+        ),
+        (
+            AnalysisWarning::SetAuthorityToSelf { authority_type: TokenAuthorityType::FreezeAccount },
+            Severity::Info,
+        ),
+        (AnalysisWarning::UnknownProgram { program_id: pk }, Severity::Warn),
+        (AnalysisWarning::SignerNotRequired, Severity::Critical),
+        (AnalysisWarning::SignerNotReferencedByInstructions, Severity::Warn),
+        (AnalysisWarning::SignerFromLookupTable, Severity::Critical),
+        (AnalysisWarning::CpiLimit, Severity::Info),
+        (AnalysisWarning::ConfidentialTransferDetected, Severity::Info),
+        (AnalysisWarning::MalformedInstruction, Severity::Warn),
+        (AnalysisWarning::TransactionTooLarge { size: 1300 }, Severity::Warn),
+        (AnalysisWarning::AccountOrderingSuspect { index: 1 }, Severity::Warn),
+        (AnalysisWarning::AnalysisTruncatedByDeadline, Severity::Warn),
+        (
+            AnalysisWarning::StakeWithdrawToOther { recipient: PubkeyBase58::from([5u8; 32]), lamports: 1 },
+            Severity::Critical,
+        ),
+        (AnalysisWarning::PriorityFeeEstimated { assumed_limit: 200_000 }, Severity::Info),
+        (AnalysisWarning::MultisigProgramDetected { program: PubkeyBase58::from([6u8; 32]) }, Severity::Info),
+        (AnalysisWarning::InstructionNoAccounts { instruction_index: 0 }, Severity::Info),
+        (AnalysisWarning::DuplicateAccountKey { key: PubkeyBase58::from([7u8; 32]) }, Severity::Warn),
+        (
+            AnalysisWarning::InstructionDataTruncatedForAnalysis { instruction_index: 0, data_len: 20_000, scanned_len: 16_384 },
+            Severity::Warn,
+        ),
+        (AnalysisWarning::RepeatedInstruction { count: 4 }, Severity::Critical),
+        (AnalysisWarning::SignerKeyOffCurve { index: 0 }, Severity::Critical),
+        (AnalysisWarning::FeePayerNotSignable { fee_payer: PubkeyBase58::from([2u8; 32]) }, Severity::Critical),
+        (AnalysisWarning::UnusedLookupTable { table: PubkeyBase58::from([8u8; 32]) }, Severity::Info),
+        (
+            AnalysisWarning::SignerAccountCreatedAndDebited { owner: PubkeyBase58::from([9u8; 32]) },
+            Severity::Critical,
+        ),
+    ];
+
+    for (warning, expected) in cases {
+        assert_eq!(warning.severity(), expected, "{:?}", warning);
+    }
+    assert!(Severity::Critical > Severity::Warn && Severity::Warn > Severity::Info);
+}
+
+#[test]
+fn analysis_reports_critical_warnings() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let attacker = PubkeyBase58::from([22u8; 32]);
+
+    let analysis: TxAnalysis =
+        analyze_transaction(&close_account_message(&signer, Some(&attacker)), &signer, None, None, None);
+    assert!(analysis.has_critical_warnings());
+
+    let analysis = analyze_transaction(&small_transfer_message(&signer, 1_000), &signer, None, None, None);
+    assert!(!analysis.has_critical_warnings());
+}
+
+#[test]
+fn analyze_flags_signer_account_created_then_debited() {
+    let funder = PubkeyBase58::from([1u8; 32]);
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let attacker = PubkeyBase58::from([22u8; 32]);
+    let owner = PubkeyBase58::from([4u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    let mut data = Vec::new();
+    data.extend_from_slice(&0u32.to_le_bytes()); // SystemProgram::CreateAccount
+    data.extend_from_slice(&2_000_000u64.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.extend_from_slice(owner.as_bytes());
+    let create = Instruction { program_id_index: 3, accounts: vec![0, 1], data };
+
+    // [funder (w, s), signer (w, s), attacker (w), system (ro)]
+    let message = |instructions: Vec<Instruction>| {
+        Message::Legacy(MessageLegacy {
+            header: MessageHeader {
+                num_required_signatures: 2,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![funder.clone(), signer.clone(), attacker.clone(), system_program.clone()],
+            recent_blockhash: HashBase58([0u8; 32]),
+            instructions,
+        })
+    };
+    let flagged = |analysis: &TxAnalysis| {
+        analysis.warnings.iter().any(|w| {
+            matches!(w, AnalysisWarning::SignerAccountCreatedAndDebited { owner: o } if o == &owner)
+        })
+    };
+
+    let drained = message(vec![create.clone(), transfer_instruction(1, 2, 3)]);
+    let analysis = analyze_transaction(&drained, &signer, None, None, None);
+    assert!(flagged(&analysis));
+    assert!(analysis.has_critical_warnings());
+
+    let created_only = message(vec![create.clone()]);
+    assert!(!flagged(&analyze_transaction(&created_only, &signer, None, None, None)));
+
+    let debited_first = message(vec![transfer_instruction(1, 2, 3), create.clone()]);
+    assert!(!flagged(&analyze_transaction(&debited_first, &signer, None, None, None)));
+
+    // The debit is still seen once the displayed transfers are capped
+    let mut past_cap = vec![create];
+    past_cap.extend((0..60).map(|_| transfer_instruction(0, 2, 3)));
+    past_cap.push(transfer_instruction(1, 2, 3));
+    let analysis = analyze_transaction(&message(past_cap), &signer, None, None, None);
+    assert!(analysis.transfers.len() < 61);
+    assert!(flagged(&analysis));
+}
+
+#[test]
+fn severity_exit_codes_are_stable() {
+    assert_eq!(EXIT_CODE_CLEAN, 0);
+    assert_eq!(Severity::Info.exit_code(), 3);
+    assert_eq!(Severity::Warn.exit_code(), 4);
+    assert_eq!(Severity::Critical.exit_code(), 5);
+
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let attacker = PubkeyBase58::from([22u8; 32]);
+    let mut analysis =
+        analyze_transaction(&close_account_message(&signer, Some(&attacker)), &signer, None, None, None);
+    assert_eq!(analysis.max_severity(), Some(Severity::Critical));
+    assert_eq!(analysis.severity_exit_code(), 5);
+
+    analysis.warnings.clear();
+    assert_eq!(analysis.max_severity(), None);
+    assert_eq!(analysis.severity_exit_code(), EXIT_CODE_CLEAN);
+}
+
+#[test]
+fn analyze_off_curve_signer_key_warns() {
+    let signer = PubkeyBase58::from([1u8; 32]);
+    // Not a valid Ed25519 point, like a PDA: no private key exists for it
+    let off_curve = PubkeyBase58::from([2u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 2,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), off_curve, system_program],
+        recent_blockhash: HashBase58([3u8; 32]),
+        instructions: vec![transfer_instruction(1, 0, 2)],
+    });
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+    let off_curve_indexes: Vec<usize> = analysis
+        .warnings
+        .iter()
+        .filter_map(|w| match w {
+            AnalysisWarning::SignerKeyOffCurve { index } => Some(*index),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(off_curve_indexes, vec![1]);
+    assert!(analysis.has_critical_warnings());
+}
+
+#[test]
+fn analyze_pda_fee_payer_warns_not_signable() {
+    let pda = PubkeyBase58::from([2u8; 32]); // off-curve, like a PDA
+    let signer = PubkeyBase58::from([1u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 2,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![pda.clone(), signer.clone(), system_program],
+        recent_blockhash: HashBase58([3u8; 32]),
+        instructions: vec![transfer_instruction(1, 0, 2)],
+    });
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+    let fee_payers: Vec<&PubkeyBase58> = analysis
+        .warnings
+        .iter()
+        .filter_map(|w| match w {
+            AnalysisWarning::FeePayerNotSignable { fee_payer } => Some(fee_payer),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(fee_payers, vec![&pda]);
+    // Reported once, not again as an off-curve signer
+    assert!(!analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::SignerKeyOffCurve { .. })));
+    assert!(analysis.has_critical_warnings());
+    assert!(!analysis.is_fee_payer);
+}
+
+#[test]
+fn signing_summary_names_overflowed_field() {
+    let signer = PubkeyBase58::from([1u8; 32]);
+    let recipient = PubkeyBase58::from([19u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), recipient, system_program],
+        recent_blockhash: HashBase58([3u8; 32]),
+        instructions: vec![transfer_instruction(0, 1, 2)],
+    });
+    let tx = Transaction {
+        signatures: vec![ed25519_dalek::Signature::from_bytes(&[0u8; 64])],
+        message: msg,
+    };
+
+    let mut analysis = analyze_transaction(&tx.message, &signer, None, None, None);
+    analysis.total_sol_send_by_signer = u64::MAX as u128 + 1;
+
+    let err = build_signing_summary(&tx, &analysis).unwrap_err();
+    assert!(
+        matches!(err, ToolError::AmountOverflow { field: "total_sol_send_by_signer" }),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn signing_summary_transaction_id_is_first_signature() {
+    let key = solana_tools_lite::crypto::ed25519::keypair_from_seed(&[4u8; 32]).unwrap();
+    let signer = PubkeyBase58::from(key.verifying_key().to_bytes());
+    let recipient = PubkeyBase58::from([2u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), recipient, system_program],
+        recent_blockhash: HashBase58([3u8; 32]),
+        instructions: vec![transfer_instruction(0, 1, 2)],
+    });
+    let mut tx = Transaction {
+        signatures: vec![ed25519_dalek::Signature::from_bytes(&[0u8; 64])],
+        message: msg,
+    };
+    let analysis = analyze_transaction(&tx.message, &signer, None, None, None);
+
+    // Placeholder signature: no id yet
+    assert_eq!(build_signing_summary(&tx, &analysis).unwrap().transaction_id, None);
+
+    solana_tools_lite::handlers::sign_tx::sign_transaction_by_key(&mut tx, &key).unwrap();
+    let summary = build_signing_summary(&tx, &analysis).unwrap();
+    let expected = bs58::encode(tx.signatures[0].to_bytes()).into_string();
+    assert_eq!(summary.transaction_id.as_deref(), Some(expected.as_str()));
+    assert_eq!(summary.signatures[0], expected);
+}
+
+#[test]
+fn signing_summary_carries_recent_blockhash_for_both_message_kinds() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let system_program = PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap();
+    let header = MessageHeader {
+        num_required_signatures: 1,
+        num_readonly_signed_accounts: 0,
+        num_readonly_unsigned_accounts: 1,
+    };
+    let blockhash = HashBase58([3u8; 32]);
+    let legacy = Message::Legacy(MessageLegacy {
+        header,
+        account_keys: vec![signer.clone(), system_program.clone()],
+        recent_blockhash: blockhash,
+        instructions: vec![],
+    });
+    let v0 = Message::V0(MessageV0 {
+        header,
+        account_keys: vec![signer.clone(), system_program],
+        recent_blockhash: blockhash,
+        instructions: vec![],
+        address_table_lookups: vec![],
+    });
+
+    for message in [legacy, v0] {
+        assert_eq!(message.recent_blockhash().to_string(), blockhash.to_string());
+        let analysis = analyze_transaction(&message, &signer, None, None, None);
+        let tx = Transaction {
+            signatures: vec![ed25519_dalek::Signature::from_bytes(&[0u8; 64])],
+            message,
+        };
+        let summary = build_signing_summary(&tx, &analysis).unwrap();
+        assert_eq!(summary.recent_blockhash, blockhash.to_string());
+        assert_eq!(analysis.recent_blockhash, summary.recent_blockhash);
+    }
+}
+
+#[test]
+fn analyze_oversized_instruction_data_is_scanned_up_to_the_cap() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let program = PubkeyBase58::from([77u8; 32]);
+    let data_len = 4 * 1024 * 1024;
+
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: vec![0xAB; data_len],
+        }],
+    });
+
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
+
+    let cap = DEFAULT_MAX_SCANNED_DATA_LEN;
+    let truncated: Vec<(usize, usize, usize)> = analysis
+        .warnings
+        .iter()
+        .filter_map(|w| match w {
+            AnalysisWarning::InstructionDataTruncatedForAnalysis {
+                instruction_index,
+                data_len,
+                scanned_len,
+            } => Some((*instruction_index, *data_len, *scanned_len)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(truncated, vec![(0, data_len, cap)]);
+    // The kept raw data is limited to the scanned window as well
+    let kept = &analysis.unknown_instructions[0].data_base64;
+    assert!(kept.len() <= cap.div_ceil(3) * 4, "kept {} Base64 chars", kept.len());
+}
+
+#[test]
+fn analyze_scan_cap_comes_from_the_config() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let program = PubkeyBase58::from([77u8; 32]);
+
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: vec![0xAB; 64],
+        }],
+    });
+    let config = AnalysisConfig {
+        max_scanned_data_len: 16,
+        ..Default::default()
+    };
+
+    let analysis = analyze_transaction_with(&message, &signer, None, None, None, &config);
+    assert!(analysis.warnings.iter().any(|w| matches!(
+        w,
+        AnalysisWarning::InstructionDataTruncatedForAnalysis { data_len: 64, scanned_len: 16, .. }
+    )));
+
+    // The default cap scans all of it
+    let analysis = analyze_transaction(&message, &signer, None, None, None);
+    assert!(!analysis.warnings.iter().any(|w| matches!(
+        w,
+        AnalysisWarning::InstructionDataTruncatedForAnalysis { .. }
+    )));
+}
+
+#[test]
+fn analyze_fast_path_shape_keeps_scan_and_deadline_limits() {
+    let signer = PubkeyBase58::from([40u8; 32]);
+
+    // A passed (zero) deadline is reported even for a single small transfer
+    let msg = small_transfer_message(&signer, 25_000);
+    let analysis = analyze_transaction(&msg, &signer, None, None, Some(Instant::now()));
+    assert!(
+        analysis
+            .warnings
+            .iter()
+            .any(|w| matches!(w, AnalysisWarning::AnalysisTruncatedByDeadline))
+    );
+
+    // Transfer data beyond the scan window is reported too
+    let mut msg = small_transfer_message(&signer, 25_000);
+    let data_len = DEFAULT_MAX_SCANNED_DATA_LEN + 8;
+    if let Message::Legacy(m) = &mut msg {
+        m.instructions[0].data.resize(data_len, 0);
+    }
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+    assert!(analysis.warnings.iter().any(|w| matches!(
+        w,
+        AnalysisWarning::InstructionDataTruncatedForAnalysis { instruction_index: 0, data_len: len, .. }
+            if *len == data_len
+    )));
+}
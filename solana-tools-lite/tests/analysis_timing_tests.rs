@@ -0,0 +1,40 @@
+#![cfg(feature = "timing")]
+
+use extensions::analysis::light_protocol::constants::{
+    DISCRIMINATOR_INVOKE, LIGHT_SYSTEM_PROGRAM_ID,
+};
+use solana_tools_lite::analysis::analyze_transaction_timed;
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+
+#[test]
+fn timed_analysis_records_core_and_registered_analyzers() {
+    extensions::init();
+
+    let signer = PubkeyBase58::from([1u8; 32]);
+    let light_system = PubkeyBase58::try_from(LIGHT_SYSTEM_PROGRAM_ID).unwrap();
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), light_system],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: DISCRIMINATOR_INVOKE.to_vec(),
+        }],
+    });
+
+    let (analysis, timings) = analyze_transaction_timed(&msg, &signer, None, None);
+    let labels: Vec<&str> = timings.iter().map(|(label, _)| label.as_str()).collect();
+
+    assert!(!analysis.extension_actions.is_empty());
+    assert_eq!(labels.first(), Some(&"core"));
+    assert!(labels.contains(&"Light Protocol::analyze"), "labels: {labels:?}");
+    assert!(labels.contains(&"Light Protocol::enrich_notice"), "labels: {labels:?}");
+}
@@ -0,0 +1,79 @@
+use solana_tools_lite::ToolError;
+use solana_tools_lite::extensions::registry::AnalyzerSet;
+use solana_tools_lite::extensions::traits::ProtocolAnalyzer;
+use solana_tools_lite::handlers::analysis::{AnalysisConfig, analyze_transaction_with};
+use solana_tools_lite::models::analysis::{AnalysisWarning, TxAnalysis};
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+use std::sync::{Arc, OnceLock};
+
+fn program_id() -> PubkeyBase58 {
+    PubkeyBase58::from([77u8; 32])
+}
+
+/// Claims the test program and leaves a notice naming itself.
+struct NoticeAnalyzer(&'static str);
+
+impl ProtocolAnalyzer for NoticeAnalyzer {
+    fn name(&self) -> &'static str {
+        self.0
+    }
+
+    fn supported_programs(&self) -> Result<&'static [PubkeyBase58], ToolError> {
+        static PROGRAMS: OnceLock<Vec<PubkeyBase58>> = OnceLock::new();
+        Ok(PROGRAMS.get_or_init(|| vec![program_id()]))
+    }
+
+    fn analyze(&self, _: &Message, _: &[PubkeyBase58], _: &PubkeyBase58, _: &mut TxAnalysis) {}
+
+    fn enrich_notice(&self, analysis: &mut TxAnalysis) {
+        analysis.extension_notices.push(format!("seen by {}", self.0));
+    }
+}
+
+fn config(analyzers: &AnalyzerSet) -> AnalysisConfig<'_> {
+    AnalysisConfig {
+        analyzers,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn analyzer_sets_are_independent() {
+    let signer = PubkeyBase58::from([1u8; 32]);
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), program_id()],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: vec![1, 2, 3],
+        }],
+    });
+
+    let first = AnalyzerSet::new(vec![Arc::new(NoticeAnalyzer("first"))]);
+    let mut both = first.clone();
+    both.push(Arc::new(NoticeAnalyzer("second")));
+
+    let analysis = analyze_transaction_with(&msg, &signer, None, None, None, &config(&first));
+    assert_eq!(analysis.extension_notices, vec!["seen by first".to_string()]);
+    assert!(!analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::UnknownProgram { .. })));
+
+    let analysis = analyze_transaction_with(&msg, &signer, None, None, None, &config(&both));
+    assert_eq!(
+        analysis.extension_notices,
+        vec!["seen by first".to_string(), "seen by second".to_string()]
+    );
+
+    // No analyzers: the program stays unknown
+    let analysis = analyze_transaction_with(&msg, &signer, None, None, None, &config(&AnalyzerSet::default()));
+    assert!(analysis.extension_notices.is_empty());
+    assert!(analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::UnknownProgram { .. })));
+}
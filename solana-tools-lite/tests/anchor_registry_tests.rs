@@ -0,0 +1,48 @@
+use solana_tools_lite::extensions::registry::{anchor_discriminator, register_anchor_program};
+use solana_tools_lite::handlers::analysis::analyze_transaction;
+use solana_tools_lite::models::analysis::{AnalysisWarning, AnchorAction};
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+
+#[test]
+fn registered_anchor_instruction_is_decoded_by_name() {
+    let signer = PubkeyBase58::from([1u8; 32]);
+    let program = PubkeyBase58::from([88u8; 32]);
+    register_anchor_program(program.clone(), &["initialize", "deposit"]);
+
+    let mut data = anchor_discriminator("deposit").to_vec();
+    data.extend_from_slice(&500u64.to_le_bytes());
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), program.clone()],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![
+            Instruction { program_id_index: 1, accounts: vec![0], data },
+            // Unregistered discriminator of the same program stays unknown
+            Instruction { program_id_index: 1, accounts: vec![0], data: vec![9u8; 8] },
+        ],
+    });
+
+    let analysis = analyze_transaction(&msg, &signer, None, None, None);
+    assert_eq!(
+        analysis.anchor_actions,
+        vec![AnchorAction { program: program.clone(), ix_name: "deposit".to_string() }]
+    );
+    assert_eq!(analysis.unknown_instructions.len(), 1);
+    assert!(analysis.warnings.iter().any(|w| matches!(
+        w,
+        AnalysisWarning::UnknownProgram { program_id } if *program_id == program
+    )));
+}
+
+#[test]
+fn anchor_discriminator_matches_idl_hash() {
+    // sha256("global:initialize")[..8], as emitted in Anchor IDLs
+    assert_eq!(anchor_discriminator("initialize"), [175, 175, 109, 31, 13, 152, 155, 237]);
+}
@@ -1,5 +1,13 @@
+use solana_tools_lite::codec::serialize_transaction;
+use solana_tools_lite::crypto::signing::Signature;
 use solana_tools_lite::errors::ToolError;
 use solana_tools_lite::handlers::base58;
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::input_transaction::UiRawMessage;
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+use solana_tools_lite::models::transaction::Transaction;
 
 #[test]
 fn encode_decode_roundtrip() {
@@ -21,3 +29,47 @@ fn decode_invalid_base58_returns_error() {
         other => panic!("unexpected error: {other:?}"),
     }
 }
+
+#[test]
+fn decode_as_transaction_parses_wire_transaction() {
+    let tx = Transaction {
+        signatures: vec![Signature::from_bytes(&[0u8; 64])],
+        message: Message::Legacy(MessageLegacy {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![
+                PubkeyBase58::from([1u8; 32]),
+                PubkeyBase58::from([2u8; 32]),
+                PubkeyBase58::from([0u8; 32]),
+            ],
+            recent_blockhash: HashBase58([9u8; 32]),
+            instructions: vec![Instruction {
+                program_id_index: 2,
+                accounts: vec![0, 1],
+                data: vec![2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            }],
+        }),
+    };
+    let encoded = bs58::encode(serialize_transaction(&tx)).into_string();
+
+    let result = base58::decode_as_transaction(&encoded).expect("decode");
+    assert!(result.output.is_none());
+    let ui = result.transaction.expect("parsed transaction");
+    assert_eq!(ui.signatures.len(), 1);
+    match ui.message {
+        UiRawMessage::Legacy(msg) => assert_eq!(msg.account_keys.len(), 3),
+        UiRawMessage::V0(_) => panic!("expected legacy message"),
+    }
+}
+
+#[test]
+fn decode_as_transaction_falls_back_to_text() {
+    let encoded = base58::encode("hello world").expect("encode").output;
+
+    let result = base58::decode_as_transaction(&encoded).expect("decode");
+    assert!(result.transaction.is_none());
+    assert_eq!(result.output.as_deref(), Some("hello world"));
+}
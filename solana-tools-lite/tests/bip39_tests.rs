@@ -4,6 +4,10 @@ use std::error::Error;
 use solana_tools_lite::crypto::bip39::{
     Bip39Config, derive_seed_from_mnemonic, generate_mnemonic_with, parse_mnemonic, validate_mnemonic,
 };
+#[cfg(feature = "zeroize")]
+use solana_tools_lite::crypto::bip39::Seed;
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
 #[test]
 fn test_generate_and_validate_mnemonic() -> Result<(), Box<dyn Error>> {
     let m = generate_mnemonic_with(Bip39Config::default())?;
@@ -69,3 +73,17 @@ fn hex_to_arr64(s: &str) -> [u8; 64] {
     arr.copy_from_slice(&bytes);
     arr
 }
+
+#[cfg(feature = "zeroize")]
+fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_seed_zeroize_wipes_bytes() {
+    // Drop-time wiping is not observable without unsafe; check the contract and the explicit wipe.
+    assert_zeroize_on_drop::<Seed>();
+
+    let mut seed = Seed::new([7u8; 64]);
+    seed.zeroize();
+    assert!(seed.as_bytes().iter().all(|b| *b == 0));
+}
@@ -1,4 +1,7 @@
-use solana_tools_lite::crypto::derive::{derive_key_from_seed, DerivationPath, SOLANA_DERIVATION_PATH};
+use solana_tools_lite::crypto::derive::{
+    derive_key_from_seed, master_fingerprint, DerivationPath, SOLANA_DERIVATION_PATH,
+};
+use solana_tools_lite::crypto::mnemonic::Seed;
 use solana_tools_lite::crypto::mnemonic::{derive_seed_from_mnemonic, parse_mnemonic};
 
 #[test]
@@ -39,3 +42,17 @@ fn derivation_path_display_roundtrip() {
     let path = DerivationPath::parse("m/44'/501'/0'/0'").expect("parse path");
     assert_eq!(path.to_string(), "m/44'/501'/0'/0'");
 }
+
+#[test]
+fn master_fingerprint_matches_slip10_vector() {
+    // SLIP-0010 Ed25519 test vector 2: parent fingerprint of m/0H is the master fingerprint
+    let seed_hex = "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a2\
+                    9f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542";
+    let bytes: [u8; 64] = hex::decode(seed_hex)
+        .expect("hex seed")
+        .try_into()
+        .expect("64-byte seed");
+    let seed = Seed::new(bytes);
+
+    assert_eq!(master_fingerprint(&seed), [0x31, 0x98, 0x1b, 0x50]);
+}
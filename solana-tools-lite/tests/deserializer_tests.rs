@@ -5,6 +5,7 @@ mod deserialize_tests {
         deserialize_message_legacy, deserialize_message_v0, deserialize_transaction,
         parse_instruction, read_shortvec_len, serialize_transaction, write_shortvec_len,
     };
+    use solana_tools_lite::errors::DeserializeError;
     use solana_tools_lite::handlers::sign_tx::sign_transaction_by_key;
 
     use solana_tools_lite::crypto::ed25519;
@@ -386,4 +387,41 @@ mod deserialize_tests {
         let res = deserialize_message_v0(&msg_bytes);
         assert!(res.is_err(), "expected program_id_index oob");
     }
+
+    fn v0_message_bytes() -> Vec<u8> {
+        let mut msg_bytes = vec![0x80]; // version 0
+        msg_bytes.extend_from_slice(&[1, 0, 1]); // header
+        write_shortvec_len(2, &mut msg_bytes); // two account keys
+        msg_bytes.extend_from_slice(&[1u8; 32]);
+        msg_bytes.extend_from_slice(&[3u8; 32]);
+        msg_bytes.extend_from_slice(&[2u8; 32]); // blockhash
+        write_shortvec_len(0, &mut msg_bytes); // no instructions
+        write_shortvec_len(0, &mut msg_bytes); // no lookups
+        msg_bytes
+    }
+
+    // deserialize_message_legacy: a v0 blob is a version mismatch, not a 128-signer header
+    #[test]
+    fn test_legacy_rejects_v0_message() {
+        let msg_bytes = v0_message_bytes();
+        assert!(deserialize_message_v0(&msg_bytes).is_ok());
+
+        let err = deserialize_message_legacy(&msg_bytes).expect_err("v0 bytes must not parse as legacy");
+        assert!(matches!(
+            err,
+            DeserializeError::VersionMismatch { expected: "legacy", found: "versioned" }
+        ));
+        assert!(err.to_string().contains("expected legacy message"), "got: {err}");
+    }
+
+    // deserialize_message_v0: legacy bytes (no prefix) report the mismatch
+    #[test]
+    fn test_v0_rejects_legacy_message() {
+        let legacy_bytes = &v0_message_bytes()[1..];
+        let err = deserialize_message_v0(legacy_bytes).expect_err("legacy bytes must not parse as v0");
+        assert!(matches!(
+            err,
+            DeserializeError::VersionMismatch { expected: "v0", found: "legacy" }
+        ));
+    }
 }
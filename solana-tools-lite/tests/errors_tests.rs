@@ -1,4 +1,5 @@
-use solana_tools_lite::errors::{AsExitCode, ExitCode, IoError, ToolError};
+use solana_tools_lite::adapters::io_adapter::read_secret_key_file;
+use solana_tools_lite::errors::{AsExitCode, ExitCode, IoError, SignError, ToolError};
 use std::io;
 
 #[test]
@@ -28,3 +29,24 @@ fn io_error_display_includes_path() {
     let text = err.to_string();
     assert!(text.contains("io(foo.txt:"));
 }
+
+#[test]
+fn display_chain_includes_missing_file_path() {
+    let path = "missing_dir_for_display_chain/keypair.json";
+    let err = read_secret_key_file(path).unwrap_err();
+
+    let text = err.display_chain();
+    assert!(text.starts_with("io: "), "got: {text}");
+    assert!(text.ends_with(&format!("(path: {path})")), "got: {text}");
+}
+
+#[test]
+fn display_chain_names_stdio_and_skips_repeated_causes() {
+    let err = ToolError::Io(IoError::stdio(io::Error::new(io::ErrorKind::BrokenPipe, "pipe closed")));
+    assert_eq!(err.display_chain(), "io: pipe closed (path: stdio)");
+
+    // The serde_json cause is already part of the message and is not repeated
+    let json_err = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+    let err = ToolError::Sign(SignError::JsonParse(json_err));
+    assert_eq!(err.display_chain(), err.to_string());
+}
@@ -1,5 +1,6 @@
 use solana_tools_lite::crypto::mnemonic::{derive_seed_from_mnemonic, parse_mnemonic};
 use solana_tools_lite::handlers::generate;
+use solana_tools_lite::models::results::GenResult;
 use solana_tools_lite::utils::hex_encode;
 use std::fs;
 use std::path::PathBuf;
@@ -14,30 +15,68 @@ fn temp_path(tag: &str) -> PathBuf {
 }
 
 #[test]
-fn generate_from_mnemonic_and_passphrase_file() {
+fn generate_from_mnemonic_and_passphrase() {
     let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
     let passphrase = "TREZOR";
 
-    let mnemonic_path = temp_path("mnemonic");
-    let passphrase_path = temp_path("passphrase");
-    fs::write(&mnemonic_path, format!("{mnemonic}\n")).expect("write mnemonic file");
-    fs::write(&passphrase_path, format!("{passphrase}\n")).expect("write passphrase file");
-
-    let result = generate::handle(
-        Some(mnemonic_path.to_string_lossy().as_ref()),
-        Some(passphrase_path.to_string_lossy().as_ref()),
-    )
-    .expect("handle");
+    let wallet = generate::handle(Some(mnemonic), Some(passphrase), true).expect("handle");
 
     let normalized = parse_mnemonic(mnemonic).expect("parse mnemonic");
     let expected_seed = derive_seed_from_mnemonic(&normalized, passphrase);
     let expected_seed_hex = hex_encode(expected_seed.as_bytes());
 
-    assert_eq!(result.mnemonic, mnemonic);
-    assert_eq!(result.seed_hex, expected_seed_hex);
-    assert!(!result.public_key.is_empty());
-    assert!(!result.secret_key.is_empty());
+    assert_eq!(wallet.mnemonic.as_deref(), Some(mnemonic));
+    assert!(wallet.passphrase_used);
+    assert_eq!(wallet.master_fingerprint.len(), 8);
+    assert!(!wallet.pubkey.is_empty());
+    let secret = wallet.secret.expect("secret requested");
+    assert_eq!(secret.seed_hex, expected_seed_hex);
+    assert!(!secret.secret_key.is_empty());
+}
+
+#[test]
+fn generate_handler_returns_known_pubkey_without_secret() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    let wallet = generate::handle(Some(mnemonic), None, false).expect("handle");
+
+    assert_eq!(wallet.pubkey, "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk");
+    assert_eq!(wallet.derivation_path, "m/44'/501'/0'/0'");
+    assert!(!wallet.passphrase_used);
+    assert!(wallet.secret.is_none());
+    assert!(GenResult::try_from(wallet).is_err());
+}
+
+#[test]
+fn derive_only_returns_known_address() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let mnemonic_path = temp_path("derive_only");
+    fs::write(&mnemonic_path, format!("{mnemonic}\n")).expect("write mnemonic file");
+
+    let result =
+        generate::derive_only(mnemonic_path.to_string_lossy().as_ref(), None).expect("derive");
+
+    // Address of this mnemonic at m/44'/501'/0'/0' in Solana wallets
+    assert_eq!(result.public_key, "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk");
+    assert_eq!(result.derivation_path, "m/44'/501'/0'/0'");
 
     let _ = fs::remove_file(mnemonic_path);
-    let _ = fs::remove_file(passphrase_path);
+}
+
+#[test]
+fn generate_from_seed_hex_matches_mnemonic_derivation() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let from_mnemonic = generate::handle(Some(mnemonic), Some("TREZOR"), true).expect("mnemonic");
+    let seed_hex = from_mnemonic.secret.as_ref().expect("secret").seed_hex.clone();
+
+    let from_seed = generate::handle_seed(&format!("{seed_hex}\n"), true).expect("seed");
+
+    assert_eq!(from_seed.pubkey, from_mnemonic.pubkey);
+    assert_eq!(from_seed.master_fingerprint, from_mnemonic.master_fingerprint);
+    assert_eq!(from_seed.secret.expect("secret").seed_hex, seed_hex);
+    assert!(from_seed.mnemonic.is_none());
+
+    // 63 bytes and non-hex input are rejected
+    assert!(generate::handle_seed(&seed_hex[..126], false).is_err());
+    assert!(generate::handle_seed(&"zz".repeat(64), false).is_err());
 }
@@ -0,0 +1,35 @@
+use solana_tools_lite::crypto::hash::{DIGEST_LEN, HashAlgo, blake3, digest, keccak256, sha256};
+use solana_tools_lite::utils::hex_encode;
+
+const ALGOS: [HashAlgo; 3] = [HashAlgo::Sha256, HashAlgo::Blake3, HashAlgo::Keccak256];
+
+#[test]
+fn digests_match_known_vectors_for_empty_input() {
+    assert_eq!(
+        hex_encode(&sha256(b"")),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        hex_encode(&blake3(b"")),
+        "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+    );
+    assert_eq!(
+        hex_encode(&keccak256(b"")),
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+}
+
+#[test]
+fn digests_are_deterministic_and_distinct_per_algorithm() {
+    let message = b"fixed message bytes";
+
+    let digests: Vec<[u8; DIGEST_LEN]> = ALGOS.iter().map(|&algo| digest(algo, message)).collect();
+    for (algo, first) in ALGOS.iter().zip(&digests) {
+        assert_eq!(first.len(), DIGEST_LEN);
+        assert_eq!(*first, digest(*algo, message), "{} must be deterministic", algo.name());
+    }
+    assert_ne!(digests[0], digests[1]);
+    assert_ne!(digests[1], digests[2]);
+    assert_ne!(digests[0], digests[2]);
+    assert_eq!(HashAlgo::default(), HashAlgo::Sha256);
+}
@@ -0,0 +1,58 @@
+use ed25519_dalek::{Signature, SigningKey};
+use solana_tools_lite::handlers::inspect;
+use solana_tools_lite::handlers::sign_tx::sign_transaction_by_key;
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::network::Network;
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+use solana_tools_lite::models::transaction::Transaction;
+
+#[test]
+fn inspect_json_labels_account_regions_and_signatures() {
+    let payer = SigningKey::from_bytes(&[1u8; 32]);
+    let cosigner = SigningKey::from_bytes(&[2u8; 32]);
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 2,
+            num_readonly_signed_accounts: 1,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![
+            PubkeyBase58::from(payer.verifying_key().to_bytes()),
+            PubkeyBase58::from(cosigner.verifying_key().to_bytes()),
+            PubkeyBase58::from([3u8; 32]),
+            PubkeyBase58::from([4u8; 32]),
+        ],
+        recent_blockhash: HashBase58([5u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 3,
+            accounts: vec![0, 1, 2],
+            data: vec![0xde, 0xad],
+        }],
+    });
+    let mut tx = Transaction {
+        signatures: vec![Signature::from_bytes(&[0u8; 64]); 2],
+        message,
+    };
+    sign_transaction_by_key(&mut tx, &payer).expect("sign payer");
+
+    let json = serde_json::to_value(inspect::handle(&tx, Network::Mainnet)).unwrap();
+
+    let regions: Vec<&str> = json["account_keys"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|account| account["region"].as_str().unwrap())
+        .collect();
+    assert_eq!(regions, vec!["writable-signer", "readonly-signer", "writable", "readonly"]);
+
+    assert_eq!(json["version"], "legacy");
+    assert_eq!(json["header"]["num_readonly_signed_accounts"], 1);
+    assert_eq!(json["signatures"][0]["status"], "valid");
+    assert_eq!(json["signatures"][1]["status"], "unsigned");
+    assert_eq!(json["instructions"][0]["program_id"], PubkeyBase58::from([4u8; 32]).to_string());
+    assert_eq!(json["instructions"][0]["accounts"], serde_json::json!([0, 1, 2]));
+    assert_eq!(json["instructions"][0]["data_hex"], "dead");
+    assert!(json.get("address_table_lookups").is_none());
+}
@@ -0,0 +1,27 @@
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+
+fn instruction_with_program(program_id_index: u8) -> Instruction {
+    Instruction {
+        program_id_index,
+        accounts: vec![0],
+        data: vec![],
+    }
+}
+
+#[test]
+fn program_id_resolves_in_range_index() {
+    let accounts = [PubkeyBase58::from([1u8; 32]), PubkeyBase58::from([2u8; 32])];
+    let instr = instruction_with_program(1);
+
+    assert_eq!(instr.program_id(&accounts), Some(&accounts[1]));
+}
+
+#[test]
+fn program_id_out_of_range_is_none() {
+    let accounts = [PubkeyBase58::from([1u8; 32])];
+    let instr = instruction_with_program(1);
+
+    assert_eq!(instr.program_id(&accounts), None);
+    assert_eq!(instr.program_id(&[]), None);
+}
@@ -6,7 +6,7 @@ use std::fs;
 use bs58;
 use data_encoding::BASE64;
 
-use solana_tools_lite::adapters::io_adapter::{read_mnemonic, read_passphrase};
+use solana_tools_lite::adapters::io_adapter::{read_mnemonic, read_passphrase, read_seed_hex};
 use solana_tools_lite::adapters::io_adapter::read_text_source;
 use solana_tools_lite::adapters::io_adapter::{
     read_input_transaction, read_secret_key_file, save_pretty_json, write_public_file,
@@ -187,6 +187,52 @@ fn test_parse_signing_key_content_keypair_json_32() {
     assert_eq!(sk.verifying_key().as_bytes(), &seed_pk_bytes(&seed));
 }
 
+// Wallet exports add metadata fields; they are ignored
+#[test]
+fn test_parse_signing_key_content_keypair_json_extra_fields() {
+    let seed = build_seed32();
+    let pk_b58 = bs58::encode(seed_pk_bytes(&seed)).into_string();
+    let sec_b58 = bs58::encode(keypair_bytes_from_seed(&seed)).into_string();
+    let json = format!(
+        "{{\"publicKey\":\"{}\",\"secretKey\":\"{}\",\"commitment\":\"confirmed\",\"label\":\"hot\"}}",
+        pk_b58, sec_b58
+    );
+    let sk = parse_signing_key_content(&json).expect("extra fields are ignored");
+    assert_eq!(sk.verifying_key().as_bytes(), &seed_pk_bytes(&seed));
+}
+
+// Parse SigningKey from a {"keypair": [...]} wrapper
+#[test]
+fn test_parse_signing_key_content_keypair_wrapper() {
+    let seed = build_seed32();
+    let kp = keypair_bytes_from_seed(&seed);
+    let json = format!("{{\"keypair\":{:?},\"version\":1}}", kp.to_vec());
+    let sk = parse_signing_key_content(&json).expect("parse keypair wrapper");
+    assert_eq!(sk.verifying_key().as_bytes(), &seed_pk_bytes(&seed));
+}
+
+// Conflicting, missing or mismatching fields are reported instead of guessed
+#[test]
+fn test_parse_signing_key_content_keypair_json_errors() {
+    let seed = build_seed32();
+    let kp = keypair_bytes_from_seed(&seed);
+    let sec_b58 = bs58::encode(kp).into_string();
+    let other_pk = bs58::encode([9u8; 32]).into_string();
+
+    let both = format!("{{\"secretKey\":\"{}\",\"keypair\":{:?}}}", sec_b58, kp.to_vec());
+    let missing = format!("{{\"publicKey\":\"{}\"}}", other_pk);
+    let mismatch = format!("{{\"publicKey\":\"{}\",\"secretKey\":\"{}\"}}", other_pk, sec_b58);
+
+    for (json, reason) in [
+        (both, "both secretKey and keypair are set"),
+        (missing, "missing secretKey or keypair"),
+        (mismatch, "publicKey does not match the secret key"),
+    ] {
+        let err = parse_signing_key_content(&json).unwrap_err();
+        assert!(matches!(err, SignError::InvalidKeypairJson(r) if r == reason), "{err}");
+    }
+}
+
 // Parse SigningKey from raw 64-byte Base58 string
 #[test]
 fn test_parse_signing_key_content_raw_base58_64() {
@@ -665,6 +711,48 @@ fn test_write_output_to_file_adapter() -> Result<()> {
     Ok(())
 }
 
+// Adapter: write_signed_transaction streams into an existing FIFO instead of replacing it
+#[cfg(unix)]
+#[test]
+fn test_write_signed_transaction_to_fifo() -> Result<()> {
+    use std::io::Read;
+    use std::os::unix::fs::FileTypeExt;
+
+    let ui = test_utils::generate_ui_transaction(
+        1,
+        vec![
+            "11111111111111111111111111111111",
+            "11111111111111111111111111111111",
+        ],
+        "11111111111111111111111111111111",
+        0,
+        vec![],
+        "",
+    );
+    let tx = solana_tools_lite::models::transaction::Transaction::try_from(ui).expect("ui -> tx");
+
+    let path = std::env::temp_dir().join(format!("test_write_adapter_{}.fifo", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let status = std::process::Command::new("mkfifo").arg(&path).status().expect("mkfifo");
+    assert!(status.success());
+
+    let reader_path = path.clone();
+    let reader = std::thread::spawn(move || {
+        let mut out = String::new();
+        fs::File::open(&reader_path).unwrap().read_to_string(&mut out).unwrap();
+        out
+    });
+
+    // No --force needed: the pipe already exists and is not replaced
+    write_signed_transaction(&tx, OutputFormat::Base64, Some(path.to_str().unwrap()), false)?;
+
+    assert_eq!(reader.join().unwrap(), BASE64.encode(&serialize_transaction(&tx)));
+    assert!(fs::metadata(&path).unwrap().file_type().is_fifo());
+
+    fs::remove_file(&path).map_err(|e| ToolError::Io(IoError::Io(e)))?;
+    Ok(())
+}
+
 // Write secret file: reject stdout and handle existing file without force
 #[test]
 fn test_write_secret_file_rejects_stdout_and_existing() -> Result<()> {
@@ -715,6 +803,21 @@ fn test_read_passphrase_trims_newlines() -> Result<()> {
     Ok(())
 }
 
+// Read seed hex from file: trims surrounding whitespace, keeps the content as written
+#[test]
+fn test_read_seed_hex_trims_without_normalizing() -> Result<()> {
+    let path = "test_seed_hex.txt";
+    fs::write(path, "  00ff\n").map_err(|e| ToolError::Io(IoError::Io(e)))?;
+    assert_eq!(read_seed_hex(path)?, "00ff");
+
+    // Internal whitespace is not collapsed the way `read_mnemonic` does
+    fs::write(path, "00 ff\n").map_err(|e| ToolError::Io(IoError::Io(e)))?;
+    assert_eq!(read_seed_hex(path)?, "00 ff");
+
+    fs::remove_file(path).map_err(|e| ToolError::Io(IoError::Io(e)))?;
+    Ok(())
+}
+
 // Adapter: write_signed_transaction(Base64/Base58) writes wire bytes
 #[test]
 fn test_write_signed_transaction_base64_and_base58() -> Result<()> {
@@ -1,6 +1,8 @@
 use solana_tools_lite::constants::permission::{FILE_PERMS_PUBLIC, FILE_PERMS_SECRET};
 use solana_tools_lite::errors::{IoError, Result, ToolError};
-use solana_tools_lite::layers::io::{read_from_file, write_to_file};
+use solana_tools_lite::layers::io::{
+    read_from_file, read_to_string_limited, write_atomic, write_to_file,
+};
 use std::fs;
 use std::path::Path;
 
@@ -112,3 +114,75 @@ fn test_write_to_file_sets_permissions_unix() -> Result<()> {
     io(fs::remove_file(path))?;
     Ok(())
 }
+
+#[test]
+fn test_write_atomic_failure_before_rename_keeps_original() -> Result<()> {
+    let dir = "test_io_atomic_fail";
+    io(fs::create_dir_all(dir))?;
+    let path = Path::new(dir).join("out.txt");
+    io(fs::write(&path, "original"))?;
+
+    // Simulate a crash mid-write: partial content, then an error before rename
+    let err = write_atomic(&path, FILE_PERMS_PUBLIC, true, |file| {
+        use std::io::Write;
+        file.write_all(b"trunc")?;
+        Err(std::io::Error::other("simulated failure"))
+    })
+    .expect_err("expected the fill error to propagate");
+    assert_eq!(err.to_string(), "simulated failure");
+
+    let content = io(fs::read_to_string(&path))?;
+    assert_eq!(content, "original");
+
+    // The temp file must be cleaned up
+    let entries: Vec<_> = io(fs::read_dir(dir))?.collect();
+    assert_eq!(entries.len(), 1);
+
+    io(fs::remove_dir_all(dir))?;
+    Ok(())
+}
+
+#[test]
+fn test_write_atomic_force_replaces_and_no_force_rejects() -> Result<()> {
+    let dir = "test_io_atomic_replace";
+    io(fs::create_dir_all(dir))?;
+    let path = Path::new(dir).join("out.txt");
+    io(fs::write(&path, "original"))?;
+
+    let err = write_atomic(&path, FILE_PERMS_PUBLIC, false, |_| Ok(()))
+        .expect_err("expected AlreadyExists error when force=false");
+    assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+    assert_eq!(io(fs::read_to_string(&path))?, "original");
+
+    io(write_atomic(&path, FILE_PERMS_PUBLIC, true, |file| {
+        use std::io::Write;
+        file.write_all(b"replaced")
+    }))?;
+    assert_eq!(io(fs::read_to_string(&path))?, "replaced");
+
+    let entries: Vec<_> = io(fs::read_dir(dir))?.collect();
+    assert_eq!(entries.len(), 1);
+
+    io(fs::remove_dir_all(dir))?;
+    Ok(())
+}
+
+#[test]
+fn test_read_limited_rejects_oversized_input() {
+    let data = "a".repeat(1025);
+
+    let err = read_to_string_limited(std::io::Cursor::new(data.as_bytes()), 1024).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert!(err.to_string().contains("input too large"));
+
+    // Surfaces as a ToolError like any other read failure
+    let tool_err: ToolError = IoError::IoWithPath { source: err, path: None }.into();
+    assert!(tool_err.to_string().contains("input too large"));
+}
+
+#[test]
+fn test_read_limited_accepts_input_at_limit() {
+    let data = "a".repeat(1024);
+    let text = read_to_string_limited(std::io::Cursor::new(data.as_bytes()), 1024).unwrap();
+    assert_eq!(text.len(), 1024);
+}
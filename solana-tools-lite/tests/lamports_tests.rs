@@ -0,0 +1,30 @@
+use solana_tools_lite::models::lamports::Lamports;
+
+#[test]
+fn lamports_checked_add_detects_overflow() {
+    assert_eq!(Lamports(5_000).checked_add(Lamports(1_000)), Some(Lamports(6_000)));
+    assert_eq!(Lamports(u64::MAX).checked_add(Lamports(1)), None);
+    assert_eq!(Lamports(1).checked_sub(Lamports(2)), None);
+}
+
+#[test]
+fn lamports_to_sol_string_formats() {
+    assert_eq!(Lamports::ZERO.to_sol_string(), "0 SOL");
+    assert_eq!(Lamports(5_000).to_sol_string(), "0.000005 SOL");
+    assert_eq!(Lamports(1_500_000_000).to_sol_string(), "1.5 SOL");
+}
+
+#[test]
+fn lamports_conversions() {
+    assert_eq!(Lamports::from(42u64).get(), 42);
+    assert_eq!(u64::from(Lamports(7)), 7);
+    assert_eq!(Lamports::try_from(9u128).unwrap(), Lamports(9));
+    assert!(Lamports::try_from(u64::MAX as u128 + 1).is_err());
+}
+
+#[test]
+fn lamports_serialize_as_plain_number() {
+    assert_eq!(serde_json::to_string(&Lamports(5_000)).unwrap(), "5000");
+    let parsed: Lamports = serde_json::from_str("123").unwrap();
+    assert_eq!(parsed, Lamports(123));
+}
@@ -4,7 +4,7 @@ use extensions::analysis::light_protocol::constants::{
 };
 use extensions::analysis::light_protocol::LightProtocol;
 use solana_tools_lite::extensions::traits::ProtocolAnalyzer;
-use solana_tools_lite::models::analysis::{PrivacyLevel, TxAnalysis};
+use solana_tools_lite::models::analysis::TxAnalysis;
 use solana_tools_lite::models::hash_base58::HashBase58;
 use solana_tools_lite::models::instruction::Instruction;
 use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
@@ -28,27 +28,6 @@ fn mock_message(program_id: &PubkeyBase58, data: Vec<u8>, signer: &PubkeyBase58)
     })
 }
 
-fn empty_analysis() -> TxAnalysis {
-    TxAnalysis {
-        transfers: vec![],
-        base_fee_lamports: 0,
-        priority_fee_lamports: None,
-        total_fee_lamports: 0,
-        total_sol_send_by_signer: 0,
-        compute_unit_limit: None,
-        compute_unit_price_micro: None,
-        warnings: vec![],
-        message_version: "legacy",
-        privacy_level: PrivacyLevel::Public,
-        extension_actions: vec![],
-        extension_notices: vec![],
-        confidential_ops_count: 0,
-        storage_ops_count: 0,
-        is_fee_payer: false,
-        has_non_sol_assets: false,
-    }
-}
-
 #[test]
 fn test_detect_ctoken_transfer() {
     let analyzer = LightProtocol;
@@ -58,7 +37,7 @@ fn test_detect_ctoken_transfer() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -74,7 +53,7 @@ fn test_detect_transfer2() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -92,7 +71,7 @@ fn test_detect_ctoken_mint_to() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -108,7 +87,7 @@ fn test_detect_light_system_invoke() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -124,7 +103,7 @@ fn test_detect_account_compression_insert() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -140,7 +119,7 @@ fn test_ignore_system_program() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -156,7 +135,7 @@ fn test_unknown_light_instruction() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -187,7 +166,7 @@ fn test_signer_not_involved() {
         instructions: vec![instr],
     });
     
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
@@ -201,10 +180,11 @@ fn test_signer_not_involved() {
 #[test]
 fn test_enrich_notice_dynamic() {
     let analyzer = LightProtocol;
-    let mut analysis = empty_analysis();
-
     // Case 1: Only storage compression
-    analysis.storage_ops_count = 1;
+    let mut analysis = TxAnalysis {
+        storage_ops_count: 1,
+        ..TxAnalysis::default()
+    };
     analyzer.enrich_notice(&mut analysis);
     assert!(!analysis.extension_notices.is_empty());
     
@@ -222,7 +202,7 @@ fn test_detect_token_interface_transfer() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -238,7 +218,7 @@ fn test_detect_token_interface_mint_to() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -254,7 +234,7 @@ fn test_detect_batch_compress() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -274,7 +254,7 @@ fn test_detect_ctoken_approve() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -290,7 +270,7 @@ fn test_detect_ctoken_revoke() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -306,7 +286,7 @@ fn test_detect_ctoken_burn() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -322,7 +302,7 @@ fn test_detect_close_token_account() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -338,7 +318,7 @@ fn test_detect_ctoken_freeze_account() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -354,7 +334,7 @@ fn test_detect_ctoken_thaw_account() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -370,7 +350,7 @@ fn test_detect_ctoken_transfer_checked() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -386,7 +366,7 @@ fn test_detect_ctoken_mint_to_checked() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -402,7 +382,7 @@ fn test_detect_ctoken_burn_checked() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -418,7 +398,7 @@ fn test_detect_create_associated_token_account() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -434,7 +414,7 @@ fn test_detect_create_associated_token_account_idempotent() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -450,7 +430,7 @@ fn test_detect_mint_action() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -466,7 +446,7 @@ fn test_detect_claim() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -482,7 +462,7 @@ fn test_detect_withdraw_funding_pool() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -502,7 +482,7 @@ fn test_detect_create_config_counter() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -518,7 +498,7 @@ fn test_detect_create_compressible_config() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -538,7 +518,7 @@ fn test_detect_token_interface_approve() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -554,7 +534,7 @@ fn test_detect_token_interface_revoke() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -570,7 +550,7 @@ fn test_detect_token_interface_freeze() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -586,7 +566,7 @@ fn test_detect_token_interface_thaw() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -602,7 +582,7 @@ fn test_detect_create_token_pool() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -618,7 +598,7 @@ fn test_detect_add_token_pool() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -638,7 +618,7 @@ fn test_detect_invoke_cpi() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -654,7 +634,7 @@ fn test_detect_invoke_cpi_with_read_only() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
@@ -670,7 +650,7 @@ fn test_detect_invoke_cpi_with_account_info() {
     
     let signer = PubkeyBase58::try_from("54pMAtV1S7S9B6V95eU7x6fA5Fz5xY6gR8H9N7V1p2A3").unwrap();
     let message = mock_message(&program_id, data, &signer);
-    let mut analysis = empty_analysis();
+    let mut analysis = TxAnalysis::default();
 
     analyzer.analyze(&message, &message.account_keys(), &signer, &mut analysis);
     
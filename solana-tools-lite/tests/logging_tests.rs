@@ -0,0 +1,91 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use solana_tools_lite::ToolError;
+use solana_tools_lite::extensions::registry::AnalyzerSet;
+use solana_tools_lite::extensions::traits::ProtocolAnalyzer;
+use solana_tools_lite::handlers::analysis::{AnalysisConfig, analyze_transaction_with};
+use solana_tools_lite::models::analysis::TxAnalysis;
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+use std::sync::{Arc, Mutex};
+
+/// Collects every record; the logger is process-wide, so this binary holds a single test.
+struct CapturingLogger {
+    records: Mutex<Vec<(Level, String)>>,
+}
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        self.records
+            .lock()
+            .unwrap()
+            .push((record.level(), record.args().to_string()));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger {
+    records: Mutex::new(Vec::new()),
+};
+
+/// Cannot produce its program ids.
+struct BrokenAnalyzer;
+
+impl ProtocolAnalyzer for BrokenAnalyzer {
+    fn name(&self) -> &'static str {
+        "Broken"
+    }
+
+    fn supported_programs(&self) -> Result<&'static [PubkeyBase58], ToolError> {
+        Err(ToolError::InvalidInput("bad program id".to_string()))
+    }
+
+    fn analyze(&self, _: &Message, _: &[PubkeyBase58], _: &PubkeyBase58, _: &mut TxAnalysis) {}
+
+    fn enrich_notice(&self, _: &mut TxAnalysis) {}
+}
+
+#[test]
+fn analyzer_init_failure_is_logged() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Trace);
+
+    let signer = PubkeyBase58::from([1u8; 32]);
+    let program = PubkeyBase58::from([77u8; 32]);
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer.clone(), program],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: vec![1, 2, 3],
+        }],
+    });
+
+    let analyzers = AnalyzerSet::new(vec![Arc::new(BrokenAnalyzer)]);
+    let config = AnalysisConfig {
+        analyzers: &analyzers,
+        ..Default::default()
+    };
+    analyze_transaction_with(&msg, &signer, None, None, None, &config);
+
+    let records = LOGGER.records.lock().unwrap();
+    let (level, message) = records
+        .iter()
+        .find(|(_, message)| message.contains("Broken"))
+        .expect("init failure should be logged");
+    assert_eq!(*level, Level::Warn);
+    assert!(message.contains("failed to initialize program ids"), "{message}");
+    assert!(message.contains("bad program id"), "{message}");
+}
@@ -20,6 +20,7 @@ fn parse_lookup_tables_valid_json() {
     let expected = solana_tools_lite::serde::LookupTableEntry {
         writable: vec![],
         readonly: vec![addr1, addr2],
+        tables: vec![],
     };
     
     assert_eq!(tables, expected);
@@ -39,3 +40,13 @@ fn parse_lookup_tables_invalid_address() {
     let msg = err.to_string();
     assert!(msg.contains("invalid writable address"));
 }
+
+#[test]
+fn parse_lookup_tables_declared_tables() {
+    let json = r#"{"writable": [], "tables": ["ComputeBudget111111111111111111111111111111"]}"#;
+    let tables = parse_lookup_tables(json).expect("must parse");
+    assert_eq!(tables.tables.len(), 1);
+
+    let err = parse_lookup_tables(r#"{"tables": ["not-base58"]}"#).unwrap_err();
+    assert!(err.to_string().contains("invalid table address"));
+}
@@ -0,0 +1,40 @@
+use solana_tools_lite::constants::programs;
+use solana_tools_lite::handlers::inspect;
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::network::Network;
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+use solana_tools_lite::models::transaction::Transaction;
+use ed25519_dalek::Signature;
+
+#[test]
+fn network_selects_program_labels() {
+    // Native programs are labelled everywhere, Squads only where it is deployed
+    assert_eq!(Network::Localnet.program_label(programs::SYSTEM_PROGRAM_ID), Some("System Program"));
+    assert_eq!(Network::Mainnet.program_label(programs::SQUADS_V4_PROGRAM_ID), Some("Squads Multisig v4"));
+    assert_eq!(Network::Localnet.program_label(programs::SQUADS_V4_PROGRAM_ID), None);
+}
+
+#[test]
+fn inspect_labels_programs_for_the_given_network() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let tx = Transaction {
+        signatures: vec![Signature::from_bytes(&[0u8; 64])],
+        message: Message::Legacy(MessageLegacy {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![signer, PubkeyBase58::try_from(programs::SQUADS_V4_PROGRAM_ID).unwrap()],
+            recent_blockhash: HashBase58([0u8; 32]),
+            instructions: vec![Instruction { program_id_index: 1, accounts: vec![0], data: vec![] }],
+        }),
+    };
+
+    let mainnet = inspect::handle(&tx, Network::Mainnet);
+    assert_eq!(mainnet.instructions[0].program_label, Some("Squads Multisig v4"));
+    let localnet = inspect::handle(&tx, Network::Localnet);
+    assert_eq!(localnet.instructions[0].program_label, None);
+}
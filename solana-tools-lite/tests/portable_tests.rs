@@ -0,0 +1,67 @@
+//! Entry points of the `portable` module. Building them without the `io` feature is
+//! checked on the library alone (`cargo check -p solana-tools-lite --no-default-features --lib`),
+//! since the dev-dependencies of this test target turn `io` back on.
+
+use solana_tools_lite::codec::serialize_transaction;
+use solana_tools_lite::crypto::ed25519;
+use solana_tools_lite::data_encoding::BASE64;
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+use solana_tools_lite::models::transaction::{SignatureStatus, Transaction};
+use solana_tools_lite::portable::{analyze_from_str, sign_from_str};
+use solana_tools_lite::constants::programs;
+
+fn unsigned_transfer_base64(signer: &PubkeyBase58) -> String {
+    let mut data = 2u32.to_le_bytes().to_vec(); // SystemProgram::Transfer
+    data.extend_from_slice(&1_500u64.to_le_bytes());
+
+    let tx = Transaction {
+        signatures: vec![ed25519::signature_from_bytes(&[0u8; 64])],
+        message: Message::Legacy(MessageLegacy {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![
+                signer.clone(),
+                PubkeyBase58::from([19u8; 32]),
+                PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap(),
+            ],
+            recent_blockhash: HashBase58([7u8; 32]),
+            instructions: vec![Instruction {
+                program_id_index: 2,
+                accounts: vec![0, 1],
+                data,
+            }],
+        }),
+    };
+    BASE64.encode(&serialize_transaction(&tx))
+}
+
+#[test]
+fn analyze_from_str_returns_summary() {
+    let key = ed25519::keypair_from_seed(&[4u8; 32]).unwrap();
+    let signer = PubkeyBase58::from(key.verifying_key().to_bytes());
+    let input = unsigned_transfer_base64(&signer);
+
+    let summary = analyze_from_str(&input, &signer.to_string(), None).expect("summary");
+    assert_eq!(summary.total_sol_send_by_signer.get(), 1_500);
+    assert!(summary.is_fee_payer);
+
+    assert!(analyze_from_str(&input, "not-a-key", None).is_err());
+}
+
+#[test]
+fn sign_from_str_signs_with_key_content() {
+    let key = ed25519::keypair_from_seed(&[4u8; 32]).unwrap();
+    let signer = PubkeyBase58::from(key.verifying_key().to_bytes());
+    let input = unsigned_transfer_base64(&signer);
+    let secret_json = format!("{:?}", key.to_bytes().to_vec());
+
+    let outcome = sign_from_str(&input, &secret_json, None).expect("signed");
+    assert_eq!(outcome.signed_tx.verify_all(), vec![SignatureStatus::Valid]);
+    assert_eq!(outcome.analysis.transfers.len(), 1);
+}
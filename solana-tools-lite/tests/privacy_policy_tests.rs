@@ -0,0 +1,58 @@
+//! The privacy policy is process-wide, so these checks live in their own test binary.
+
+use extensions::analysis::light_protocol::constants::{self, DISCRIMINATOR_CTOKEN_TRANSFER};
+use extensions::analysis::light_protocol::LightProtocol;
+use solana_tools_lite::extensions::analysis::{PrivacyImpact, PrivacyPolicy};
+use solana_tools_lite::extensions::registry;
+use solana_tools_lite::extensions::traits::ProtocolAnalyzer;
+use solana_tools_lite::models::analysis::{PrivacyLevel, TxAnalysis};
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+
+fn ctoken_transfer_message(signer: &PubkeyBase58) -> Message {
+    let program_id = PubkeyBase58::try_from(constants::COMPRESSED_TOKEN_PROGRAM_ID).unwrap();
+    let mut data = vec![DISCRIMINATOR_CTOKEN_TRANSFER];
+    data.extend_from_slice(&100u64.to_le_bytes());
+
+    Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 0,
+        },
+        account_keys: vec![signer.clone(), program_id],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data,
+        }],
+    })
+}
+
+fn analyze_privacy_level(message: &Message, signer: &PubkeyBase58) -> PrivacyLevel {
+    let mut analysis = TxAnalysis::default();
+    LightProtocol.analyze(message, message.account_keys(), signer, &mut analysis);
+    analysis.recalculate_privacy_level();
+    analysis.privacy_level
+}
+
+#[test]
+fn test_privacy_policy_overrides_action_impact() {
+    let signer = PubkeyBase58::from([19u8; 32]);
+    let message = ctoken_transfer_message(&signer);
+
+    // Default: CTokenTransfer is confidential
+    assert_eq!(analyze_privacy_level(&message, &signer), PrivacyLevel::Confidential);
+
+    let mut policy = PrivacyPolicy::new();
+    policy.insert("CTokenTransfer".to_string(), PrivacyImpact::StorageCompression);
+    registry::set_privacy_policy(Some(policy));
+    assert_eq!(analyze_privacy_level(&message, &signer), PrivacyLevel::Compressed);
+
+    // Clearing the policy restores the default classification
+    registry::set_privacy_policy(None);
+    assert_eq!(analyze_privacy_level(&message, &signer), PrivacyLevel::Confidential);
+}
@@ -0,0 +1,36 @@
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+
+#[test]
+fn pubkey_from_bytes_and_as_bytes_roundtrip() {
+    let bytes = [7u8; 32];
+    let pk = PubkeyBase58::from_bytes(&bytes);
+
+    assert_eq!(pk.as_bytes(), &bytes);
+    assert_eq!(pk, PubkeyBase58::from(bytes));
+
+    let parsed = PubkeyBase58::try_from(pk.to_string().as_str()).expect("base58 roundtrip");
+    assert_eq!(parsed, pk);
+}
+
+#[test]
+fn pubkey_try_from_slice_checks_length() {
+    let pk = PubkeyBase58::try_from(&[1u8; 32][..]).expect("32-byte slice");
+    assert_eq!(pk.as_bytes(), &[1u8; 32]);
+
+    assert!(PubkeyBase58::try_from(&[1u8; 31][..]).is_err());
+    assert!(PubkeyBase58::try_from(&[1u8; 33][..]).is_err());
+}
+
+#[test]
+fn pubkey_short_form_is_display_only() {
+    let pk = PubkeyBase58::from([7u8; 32]);
+    let full = pk.to_string();
+    let short = pk.short();
+
+    assert_eq!(short.chars().count(), 4 + 1 + 4);
+    assert!(short.starts_with(&full[..4]) && short.ends_with(&full[full.len() - 4..]));
+    assert!(short.contains('…'));
+    // Display stays the full key; the short form never parses back
+    assert_eq!(format!("{pk}"), full);
+    assert!(PubkeyBase58::try_from(short.as_str()).is_err());
+}
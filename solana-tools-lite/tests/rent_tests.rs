@@ -0,0 +1,73 @@
+use solana_tools_lite::constants::programs;
+use solana_tools_lite::handlers::analysis::{AnalysisConfig, analyze_transaction, analyze_transaction_with};
+use solana_tools_lite::models::analysis::CreatedAccount;
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+use solana_tools_lite::models::rent::RentConfig;
+
+fn create_account_message(funder: &PubkeyBase58, new_account: &PubkeyBase58, lamports: u64, space: u64) -> Message {
+    let owner = PubkeyBase58::try_from(programs::TOKEN_PROGRAM_ID).unwrap();
+    let mut data = 0u32.to_le_bytes().to_vec(); // SystemProgram::CreateAccount
+    data.extend_from_slice(&lamports.to_le_bytes());
+    data.extend_from_slice(&space.to_le_bytes());
+    data.extend_from_slice(owner.as_bytes());
+
+    Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 2,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![
+            funder.clone(),
+            new_account.clone(),
+            PubkeyBase58::try_from(programs::SYSTEM_PROGRAM_ID).unwrap(),
+        ],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 2,
+            accounts: vec![0, 1],
+            data,
+        }],
+    })
+}
+
+#[test]
+fn token_account_rent_exemption_matches_known_value() {
+    // 165-byte SPL token account: (128 + 165) * 3480 * 2
+    assert_eq!(RentConfig::default().minimum_balance(165), 2_039_280);
+    assert_eq!(RentConfig::default().minimum_balance(0), 890_880);
+}
+
+#[test]
+fn create_account_reports_rent_exempt_minimum() {
+    let funder = PubkeyBase58::from([19u8; 32]);
+    let new_account = PubkeyBase58::from([22u8; 32]);
+    let message = create_account_message(&funder, &new_account, 2_039_280, 165);
+
+    let analysis = analyze_transaction(&message, &funder, None, None, None);
+    assert_eq!(
+        analysis.created_accounts,
+        vec![CreatedAccount {
+            account: new_account.clone(),
+            funder: funder.clone(),
+            owner: PubkeyBase58::try_from(programs::TOKEN_PROGRAM_ID).unwrap(),
+            space: 165,
+            lamports: 2_039_280,
+            rent_exempt_minimum: 2_039_280,
+        }]
+    );
+
+    let custom = RentConfig {
+        lamports_per_byte_year: 1000,
+        exemption_threshold: 1.0,
+    };
+    let config = AnalysisConfig {
+        rent: custom,
+        ..Default::default()
+    };
+    let analysis = analyze_transaction_with(&message, &funder, None, None, None, &config);
+    assert_eq!(analysis.created_accounts[0].rent_exempt_minimum, 293_000);
+}
@@ -1,7 +1,10 @@
-use solana_tools_lite::models::input_transaction::InputTransaction;
+use solana_tools_lite::errors::TransactionParseError;
+use solana_tools_lite::models::input_transaction::{InputFormat, InputTransaction};
 use solana_tools_lite::serde::input_tx::{
     is_base58 as is_b58, is_base64 as is_b64, parse_input_transaction,
+    parse_input_transaction_as, parse_input_transaction_strict, validate_signatures,
 };
+use solana_tools_lite::errors::ToolError;
 
 #[test]
 fn test_parse_input_transaction_json_ok() {
@@ -56,3 +59,180 @@ fn test_codecs_helpers_trim_and_nonempty() {
     assert!(!is_b64("   "));
     assert!(!is_b58("   "));
 }
+
+fn sample_wire_transaction() -> Vec<u8> {
+    use ed25519_dalek::Signature;
+    use solana_tools_lite::codec::serialize_transaction;
+    use solana_tools_lite::models::hash_base58::HashBase58;
+    use solana_tools_lite::models::instruction::Instruction;
+    use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+    use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+    use solana_tools_lite::models::transaction::Transaction;
+
+    let tx = Transaction {
+        signatures: vec![Signature::from_bytes(&[0u8; 64])],
+        message: Message::Legacy(MessageLegacy {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![PubkeyBase58::from([1u8; 32]), PubkeyBase58::from([2u8; 32])],
+            recent_blockhash: HashBase58([3u8; 32]),
+            instructions: vec![Instruction {
+                program_id_index: 1,
+                accounts: vec![0],
+                data: vec![9, 9],
+            }],
+        }),
+    };
+    serialize_transaction(&tx)
+}
+
+#[test]
+fn test_parse_input_transaction_strict_base64_ok() {
+    let b64 = data_encoding::BASE64.encode(&sample_wire_transaction());
+    let v = parse_input_transaction_strict(Some(&b64)).expect("strict b64 parse");
+    assert!(matches!(v, InputTransaction::Base64(_)));
+}
+
+#[test]
+fn test_parse_input_transaction_strict_base64_trailing_bytes_rejected() {
+    let mut raw = sample_wire_transaction();
+    raw.extend_from_slice(&[0xde, 0xad, 0xbe]);
+    let b64 = data_encoding::BASE64.encode(&raw);
+
+    // Lenient detection still accepts it
+    assert!(parse_input_transaction(Some(&b64)).is_ok());
+
+    let err = parse_input_transaction_strict(Some(&b64)).expect_err("trailing bytes must fail");
+    assert!(err.to_string().contains("3 trailing bytes"), "got: {err}");
+}
+
+#[test]
+fn test_parse_input_transaction_strict_json_trailing_garbage_rejected() {
+    let json = r#"{
+        "signatures": [],
+        "message": {
+            "header": {
+                "num_required_signatures": 1,
+                "num_readonly_signed_accounts": 0,
+                "num_readonly_unsigned_accounts": 1
+            },
+            "account_keys": [
+                "11111111111111111111111111111111",
+                "11111111111111111111111111111111"
+            ],
+            "recent_blockhash": "11111111111111111111111111111111",
+            "instructions": []
+        }
+    }"#;
+    assert!(parse_input_transaction_strict(Some(json)).is_ok());
+    assert!(parse_input_transaction_strict(Some(&format!("{json}\n\n"))).is_ok());
+
+    let err = parse_input_transaction_strict(Some(&format!("{json} {{}}")))
+        .expect_err("trailing JSON must fail");
+    assert!(err.to_string().contains("trailing characters"), "got: {err}");
+}
+
+#[test]
+fn test_parse_input_transaction_json_tolerates_bom_and_whitespace() {
+    let json = r#"{"signatures":[],"message":{"header":{"num_required_signatures":1,"num_readonly_signed_accounts":0,"num_readonly_unsigned_accounts":1},"account_keys":["11111111111111111111111111111111","11111111111111111111111111111111"],"recent_blockhash":"11111111111111111111111111111111","instructions":[]}}"#;
+
+    let with_bom = format!("\u{FEFF}{json}\n");
+    assert!(matches!(parse_input_transaction(Some(&with_bom)), Ok(InputTransaction::Json(_))));
+    assert!(matches!(parse_input_transaction_strict(Some(&with_bom)), Ok(InputTransaction::Json(_))));
+    assert!(parse_input_transaction_as(Some(&with_bom), InputFormat::Json).is_ok());
+
+    let padded = format!("\r\n  {json} \t\r\n\n");
+    assert!(matches!(parse_input_transaction(Some(&padded)), Ok(InputTransaction::Json(_))));
+    assert!(matches!(parse_input_transaction_strict(Some(&padded)), Ok(InputTransaction::Json(_))));
+
+    // Trailing non-whitespace is still rejected by the strict parser
+    let err = parse_input_transaction_strict(Some(&format!("\u{FEFF}{json}\n]"))).expect_err("garbage");
+    assert!(err.to_string().contains("trailing characters"), "got: {err}");
+}
+
+#[test]
+fn test_parse_input_transaction_forced_base64_rejects_base58_text() {
+    // Valid Base58 (length not a multiple of 4) is not Base64; no fallback to Base58
+    let b58 = "3yZe7d";
+    assert!(is_b58(b58));
+
+    let err = parse_input_transaction_as(Some(b58), InputFormat::Base64).unwrap_err();
+    assert!(matches!(err, TransactionParseError::InvalidBase64(_)));
+
+    let parsed = parse_input_transaction_as(Some(b58), InputFormat::Base58).unwrap();
+    assert!(matches!(parsed, InputTransaction::Base58(s) if s == b58));
+
+    // Forced Base58 on text outside the alphabet keeps the Base58-specific error
+    let err = parse_input_transaction_as(Some("0OIl"), InputFormat::Base58).unwrap_err();
+    assert!(matches!(err, TransactionParseError::InvalidBase58(_)));
+}
+
+#[test]
+fn test_parse_input_transaction_forced_format_overrides_detection() {
+    // "abcd" is valid in both alphabets; detection picks Base64, forcing picks Base58
+    assert!(matches!(
+        parse_input_transaction(Some("abcd")).unwrap(),
+        InputTransaction::Base64(_)
+    ));
+    assert!(matches!(
+        parse_input_transaction_as(Some("abcd"), InputFormat::Base58).unwrap(),
+        InputTransaction::Base58(_)
+    ));
+
+    // Forced JSON on non-JSON text is a hard error
+    let err = parse_input_transaction_as(Some("abcd"), InputFormat::Json).unwrap_err();
+    assert!(matches!(err, TransactionParseError::InvalidFormat(_)));
+}
+
+fn json_with_signatures(signatures: &str) -> InputTransaction {
+    let json = format!(
+        r#"{{
+        "signatures": [{signatures}],
+        "message": {{
+            "header": {{
+                "num_required_signatures": 2,
+                "num_readonly_signed_accounts": 0,
+                "num_readonly_unsigned_accounts": 1
+            }},
+            "account_keys": [
+                "11111111111111111111111111111111",
+                "11111111111111111111111111111112",
+                "11111111111111111111111111111111"
+            ],
+            "recent_blockhash": "11111111111111111111111111111111",
+            "instructions": []
+        }}
+    }}"#
+    );
+    parse_input_transaction(Some(&json)).expect("json parse")
+}
+
+#[test]
+fn test_validate_signatures_rejects_count_mismatch() {
+    let sig = format!("\"{}\"", bs58::encode([7u8; 64]).into_string());
+
+    let too_few = json_with_signatures(&sig);
+    let err = validate_signatures(&too_few).expect_err("one of two signatures must fail");
+    assert!(matches!(err, ToolError::InvalidInput(_)), "got: {err:?}");
+    assert!(err.to_string().contains("1 signatures but its header requires 2"), "got: {err}");
+
+    let too_many = json_with_signatures(&[sig.as_str(); 3].join(","));
+    let err = validate_signatures(&too_many).expect_err("three of two signatures must fail");
+    assert!(err.to_string().contains("3 signatures but its header requires 2"), "got: {err}");
+}
+
+#[test]
+fn test_validate_signatures_checks_each_nonempty_signature() {
+    let sig = bs58::encode([7u8; 64]).into_string();
+
+    let ok = json_with_signatures(&format!("\"{sig}\", \"\""));
+    validate_signatures(&ok).expect("empty slot marks an unsigned signer");
+
+    let short = bs58::encode([7u8; 32]).into_string();
+    let bad = json_with_signatures(&format!("\"{sig}\", \"{short}\""));
+    let err = validate_signatures(&bad).expect_err("32-byte signature must fail");
+    assert!(err.to_string().contains("signature #1"), "got: {err}");
+}
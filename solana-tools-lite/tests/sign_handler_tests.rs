@@ -68,6 +68,30 @@ mod tests {
         assert!(err.contains("InvalidBase58"));
     }
 
+    /// Every accepted key encoding still yields the same key after wiping intermediates.
+    #[test]
+    fn test_parse_signing_key_content_formats_agree() {
+        let key = ed25519::keypair_from_seed(&[42u8; 64]).unwrap();
+        let seed = key.to_bytes();
+        let full = key.to_keypair_bytes();
+
+        let from_seed_json = parse_signing_key_content(&format!("{:?}", seed.to_vec())).unwrap();
+        let from_full_json = parse_signing_key_content(&format!("{:?}", full.to_vec())).unwrap();
+        let from_b58 = parse_signing_key_content(&bs58::encode(full).into_string()).unwrap();
+
+        assert_eq!(from_seed_json.to_bytes(), seed);
+        assert_eq!(from_full_json.to_bytes(), seed);
+        assert_eq!(from_b58.to_bytes(), seed);
+    }
+
+    /// `env:VAR` with a missing variable is a clear error naming it. Reading a set variable
+    /// is covered by the CLI tests, which set it on a child process only.
+    #[test]
+    fn test_read_secret_key_from_unset_env_var() {
+        let err = read_and_parse_secret_key("env:SOLANA_TOOLS_LITE_TEST_UNSET_VAR").unwrap_err();
+        assert!(err.to_string().contains("SOLANA_TOOLS_LITE_TEST_UNSET_VAR"));
+    }
+
     /// A secret key that decodes to a wrong length (too short) should be rejected.
     #[test]
     fn test_sign_secret_too_short_should_fail() {
@@ -117,4 +141,42 @@ mod tests {
         
         assert!(res.is_err());
     }
+
+    /// Raw signing signs opaque bytes but refuses anything that decodes as a message.
+    #[test]
+    fn test_handle_raw_refuses_message_bytes() {
+        use solana_tools_lite::codec::serialize_message;
+        use solana_tools_lite::models::instruction::Instruction;
+        use solana_tools_lite::models::message::{MessageHeader, MessageLegacy};
+        use solana_tools_lite::models::{HashBase58, Message, PubkeyBase58};
+
+        let key = ed25519::keypair_from_seed(&[5u8; 32]).unwrap();
+        let message = Message::Legacy(MessageLegacy {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![
+                PubkeyBase58::from(key.verifying_key().to_bytes()),
+                PubkeyBase58::from([0u8; 32]),
+            ],
+            recent_blockhash: HashBase58([1u8; 32]),
+            instructions: vec![Instruction { program_id_index: 1, accounts: vec![0], data: vec![] }],
+        });
+
+        let err = sign_message::handle_raw(&serialize_message(&message), &key)
+            .expect_err("message bytes must be refused");
+        assert!(err.to_string().contains("decode as a Solana transaction"));
+
+        let blob = [0xffu8, 0x00, 0x13, 0x37];
+        let result = sign_message::handle_raw(&blob, &key).expect("opaque bytes are signed");
+        let sig_bytes: [u8; 64] = bs58::decode(&result.signature_base58)
+            .into_vec()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert!(key.verifying_key().verify(&blob, &ed25519::signature_from_bytes(&sig_bytes)).is_ok());
+        assert_eq!(result.message, "/wATNw==");
+    }
 }
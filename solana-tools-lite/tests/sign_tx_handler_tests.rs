@@ -484,4 +484,116 @@ mod tests_signing {
             Err(ToolError::Sign(SignError::SigningNotRequiredForKey))
         ));
     }
+
+    #[test]
+    fn test_prepare_and_sign_happy_path() {
+        use solana_tools_lite::errors::{SignError, ToolError};
+        use solana_tools_lite::handlers::sign_tx::{SignOptions, prepare_and_sign};
+        use solana_tools_lite::models::analysis::TxAnalysis;
+        use data_encoding::BASE64;
+        use solana_tools_lite::codec::serialize_transaction;
+        use solana_tools_lite::models::input_transaction::InputTransaction;
+        use solana_tools_lite::models::transaction::SignatureStatus;
+
+        let key = ed25519::keypair_from_seed(&[4u8; 32]).unwrap();
+        let signer_pk = bs58::encode(key.verifying_key().to_bytes()).into_string();
+        let recipient = generate_mock_pubkey();
+        let blockhash = generate_mock_pubkey();
+
+        let mut transfer = 2u32.to_le_bytes().to_vec(); // SystemProgram::Transfer
+        transfer.extend_from_slice(&5_000_000u64.to_le_bytes());
+        let data = bs58::encode(&transfer).into_string();
+
+        let InputTransaction::Json(ui_tx) = generate_input_transaction(
+            1,
+            vec![&signer_pk, &recipient, "11111111111111111111111111111111"],
+            &blockhash,
+            2,
+            vec![0, 1],
+            &data,
+        ) else {
+            panic!("expected JSON input");
+        };
+        let input = serde_json::to_string(&ui_tx).unwrap();
+
+        let confirm = |analysis: &TxAnalysis| analysis.total_sol_send_by_signer == 5_000_000;
+        let opts = SignOptions {
+            max_fee: Some(10_000),
+            require_fee_payer: Some(true),
+            deny_unknown_programs: true,
+            confirm: Some(&confirm),
+            ..Default::default()
+        };
+        let outcome = prepare_and_sign(&input, std::slice::from_ref(&key), &opts).expect("signed");
+
+        assert_eq!(outcome.analysis.transfers.len(), 1);
+        assert!(outcome.analysis.is_fee_payer);
+        assert_eq!(outcome.signed_tx.verify_all(), vec![SignatureStatus::Valid]);
+
+        let raw = serialize_transaction(&outcome.signed_tx);
+        assert_eq!(outcome.signed_tx_base64, BASE64.encode(&raw));
+        assert_eq!(outcome.signed_tx_base58, bs58::encode(&raw).into_string());
+
+        // A gate refusal never signs, but the analysis was reviewed before it
+        let reviewed = std::cell::Cell::new(false);
+        let review = |_: &mut TxAnalysis| reviewed.set(true);
+        let strict = SignOptions {
+            max_fee: Some(1),
+            review: Some(&review),
+            ..Default::default()
+        };
+        assert!(matches!(
+            prepare_and_sign(&input, std::slice::from_ref(&key), &strict),
+            Err(ToolError::Sign(SignError::FeeLimitExceeded { max_lamports: 1, .. }))
+        ));
+        assert!(reviewed.get());
+    }
+
+    #[test]
+    fn test_plan_reports_full_coverage_for_two_of_two() {
+        use solana_tools_lite::handlers::sign_tx::plan;
+        use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+
+        let first = ed25519::keypair_from_seed(&[5u8; 32]).unwrap();
+        let second = ed25519::keypair_from_seed(&[6u8; 32]).unwrap();
+        let first_pk = bs58::encode(first.verifying_key().to_bytes()).into_string();
+        let second_pk = bs58::encode(second.verifying_key().to_bytes()).into_string();
+        let blockhash = generate_mock_pubkey();
+
+        let input_tx = generate_input_transaction(
+            2,
+            vec![&first_pk, &second_pk, "11111111111111111111111111111111"],
+            &blockhash,
+            2,
+            vec![0, 1],
+            "",
+        );
+        let tx = Transaction::try_from(input_tx).expect("parse");
+        let signatures_before = tx.signatures.clone();
+
+        // Keys supplied out of slot order
+        let keys = [
+            PubkeyBase58::from(second.verifying_key().to_bytes()),
+            PubkeyBase58::from(first.verifying_key().to_bytes()),
+        ];
+        let result = plan(&tx, &keys);
+        assert!(result.fully_covered);
+        assert!(result.unused_keys.is_empty());
+        assert_eq!(result.slots.len(), 2);
+        assert_eq!((result.slots[0].pubkey.as_str(), result.slots[0].covered_by), (first_pk.as_str(), Some(1)));
+        assert_eq!((result.slots[1].pubkey.as_str(), result.slots[1].covered_by), (second_pk.as_str(), Some(0)));
+        assert_eq!(
+            serde_json::to_value(&result.slots[0]).unwrap(),
+            serde_json::json!({ "index": 0, "pubkey": first_pk, "covered_by": 1 })
+        );
+
+        // One key only: the other slot stays uncovered; a stranger is unused
+        let stranger = PubkeyBase58::from([9u8; 32]);
+        let result = plan(&tx, &[keys[1].clone(), stranger.clone()]);
+        assert!(!result.fully_covered);
+        assert_eq!(result.slots[1].covered_by, None);
+        assert_eq!(result.unused_keys, vec![stranger.to_string()]);
+        // Dry run: nothing was signed
+        assert_eq!(tx.signatures, signatures_before);
+    }
 }
@@ -33,12 +33,12 @@ fn benchmark_signing_realistic_transactions_bin() {
                     num_readonly_unsigned_accounts: 1,
                 },
                 account_keys: vec![
-                    PubkeyBase58([
+                    PubkeyBase58::from_bytes(&[
                         138, 136, 227, 221, 116, 9, 241, 149, 253, 82, 219, 45, 60, 186, 93, 114,
                         202, 103, 9, 191, 29, 148, 18, 27, 243, 116, 136, 1, 180, 15, 111, 92,
                     ]),
-                    PubkeyBase58([2u8; 32]),
-                    PubkeyBase58([3u8; 32]),
+                    PubkeyBase58::from_bytes(&[2u8; 32]),
+                    PubkeyBase58::from_bytes(&[3u8; 32]),
                 ],
                 recent_blockhash: HashBase58([9u8; 32]),
                 instructions: vec![Instruction {
@@ -94,13 +94,13 @@ fn benchmark_e2e_parallel_build_and_sign() {
                         num_readonly_unsigned_accounts: 1,
                     },
                     account_keys: vec![
-                        PubkeyBase58([
+                        PubkeyBase58::from_bytes(&[
                             138, 136, 227, 221, 116, 9, 241, 149, 253, 82, 219, 45, 60, 186, 93,
                             114, 202, 103, 9, 191, 29, 148, 18, 27, 243, 116, 136, 1, 180, 15,
                             111, 92,
                         ]),
-                        PubkeyBase58([2u8; 32]),
-                        PubkeyBase58([3u8; 32]),
+                        PubkeyBase58::from_bytes(&[2u8; 32]),
+                        PubkeyBase58::from_bytes(&[3u8; 32]),
                     ],
                     recent_blockhash: HashBase58([9u8; 32]),
                     instructions: vec![Instruction {
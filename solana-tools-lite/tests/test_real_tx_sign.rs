@@ -30,9 +30,9 @@ fn test_real_tx_signature_base58() {
             num_readonly_unsigned_accounts: 1,
         },
         account_keys: vec![
-            PubkeyBase58(keypair.verifying_key().to_bytes()),
-            PubkeyBase58([2u8; 32]),
-            PubkeyBase58([3u8; 32]),
+            PubkeyBase58::from_bytes(&keypair.verifying_key().to_bytes()),
+            PubkeyBase58::from_bytes(&[2u8; 32]),
+            PubkeyBase58::from_bytes(&[3u8; 32]),
         ],
         recent_blockhash: recent_blockhash,
         instructions: vec![Instruction {
@@ -0,0 +1,208 @@
+use ed25519_dalek::{Signature, SigningKey};
+use solana_tools_lite::handlers::sign_tx::sign_transaction_by_key;
+use solana_tools_lite::handlers::verify::handle_transaction;
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+use solana_tools_lite::models::results::VerifyTxStatus;
+use solana_tools_lite::models::transaction::{SignatureStatus, Transaction};
+
+/// Two-signer legacy transaction with placeholder signatures.
+fn two_signer_tx(a: &SigningKey, b: &SigningKey) -> Transaction {
+    let program = PubkeyBase58::from([7u8; 32]);
+    let message = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 2,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![
+            PubkeyBase58::from(a.verifying_key().to_bytes()),
+            PubkeyBase58::from(b.verifying_key().to_bytes()),
+            program,
+        ],
+        recent_blockhash: HashBase58([5u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 2,
+            accounts: vec![0, 1],
+            data: vec![1, 2, 3],
+        }],
+    });
+
+    Transaction {
+        signatures: vec![Signature::from_bytes(&[0u8; 64]); 2],
+        message,
+    }
+}
+
+#[test]
+fn verify_all_fully_signed_is_valid() {
+    let a = SigningKey::from_bytes(&[1u8; 32]);
+    let b = SigningKey::from_bytes(&[2u8; 32]);
+    let mut tx = two_signer_tx(&a, &b);
+
+    sign_transaction_by_key(&mut tx, &a).expect("sign a");
+    sign_transaction_by_key(&mut tx, &b).expect("sign b");
+
+    assert_eq!(
+        tx.verify_all(),
+        vec![SignatureStatus::Valid, SignatureStatus::Valid]
+    );
+}
+
+#[test]
+fn verify_all_partially_signed_reports_unsigned_slot() {
+    let a = SigningKey::from_bytes(&[1u8; 32]);
+    let b = SigningKey::from_bytes(&[2u8; 32]);
+    let mut tx = two_signer_tx(&a, &b);
+
+    sign_transaction_by_key(&mut tx, &b).expect("sign b");
+
+    assert_eq!(
+        tx.verify_all(),
+        vec![SignatureStatus::Unsigned, SignatureStatus::Valid]
+    );
+
+    // Missing signature entries are reported as unsigned too
+    tx.signatures.truncate(1);
+    assert_eq!(
+        tx.verify_all(),
+        vec![SignatureStatus::Unsigned, SignatureStatus::Unsigned]
+    );
+}
+
+#[test]
+fn verify_all_tampered_message_is_invalid() {
+    let a = SigningKey::from_bytes(&[1u8; 32]);
+    let b = SigningKey::from_bytes(&[2u8; 32]);
+    let mut tx = two_signer_tx(&a, &b);
+
+    sign_transaction_by_key(&mut tx, &a).expect("sign a");
+    sign_transaction_by_key(&mut tx, &b).expect("sign b");
+
+    // Change instruction data after signing
+    if let Message::Legacy(m) = &mut tx.message {
+        m.instructions[0].data[0] ^= 0xff;
+    }
+
+    assert_eq!(
+        tx.verify_all(),
+        vec![SignatureStatus::Invalid, SignatureStatus::Invalid]
+    );
+}
+
+fn key_b58(key: &SigningKey) -> String {
+    PubkeyBase58::from(key.verifying_key().to_bytes()).to_string()
+}
+
+#[test]
+fn verify_tx_reports_each_signer_outcome() {
+    let a = SigningKey::from_bytes(&[1u8; 32]);
+    let b = SigningKey::from_bytes(&[2u8; 32]);
+    let mut tx = two_signer_tx(&a, &b);
+    sign_transaction_by_key(&mut tx, &b).expect("sign b");
+
+    let valid = handle_transaction(&tx, &key_b58(&b)).expect("verify b");
+    assert_eq!((valid.signer_index, valid.status), (Some(1), VerifyTxStatus::Valid));
+
+    let unsigned = handle_transaction(&tx, &key_b58(&a)).expect("verify a");
+    assert_eq!((unsigned.signer_index, unsigned.status), (Some(0), VerifyTxStatus::Unsigned));
+
+    // The program id is an account key but not a signer
+    let program = PubkeyBase58::from([7u8; 32]).to_string();
+    let outsider = handle_transaction(&tx, &program).expect("verify program");
+    assert_eq!((outsider.signer_index, outsider.status), (None, VerifyTxStatus::NotASigner));
+
+    tx.signatures[1] = Signature::from_bytes(&[9u8; 64]);
+    let invalid = handle_transaction(&tx, &key_b58(&b)).expect("verify tampered");
+    assert_eq!((invalid.signer_index, invalid.status), (Some(1), VerifyTxStatus::Invalid));
+
+    assert!(handle_transaction(&tx, "not-a-key").is_err());
+}
+
+#[test]
+fn merge_signatures_combines_partial_signings() {
+    let a = SigningKey::from_bytes(&[1u8; 32]);
+    let b = SigningKey::from_bytes(&[2u8; 32]);
+    let mut signed_by_a = two_signer_tx(&a, &b);
+    let mut signed_by_b = two_signer_tx(&a, &b);
+
+    sign_transaction_by_key(&mut signed_by_a, &a).expect("sign a");
+    sign_transaction_by_key(&mut signed_by_b, &b).expect("sign b");
+
+    signed_by_a.merge_signatures(&signed_by_b).expect("merge");
+    assert_eq!(
+        signed_by_a.verify_all(),
+        vec![SignatureStatus::Valid, SignatureStatus::Valid]
+    );
+
+    // Merging the same copy again is a no-op
+    signed_by_a.merge_signatures(&signed_by_b).expect("merge again");
+}
+
+#[test]
+fn merge_signatures_rejects_mismatch_and_conflict() {
+    let a = SigningKey::from_bytes(&[1u8; 32]);
+    let b = SigningKey::from_bytes(&[2u8; 32]);
+    let mut signed = two_signer_tx(&a, &b);
+    sign_transaction_by_key(&mut signed, &a).expect("sign a");
+
+    let mut other_message = two_signer_tx(&a, &b);
+    if let Message::Legacy(m) = &mut other_message.message {
+        m.instructions[0].data[0] ^= 0xff;
+    }
+    assert!(signed.merge_signatures(&other_message).is_err());
+
+    let mut conflicting = two_signer_tx(&a, &b);
+    conflicting.signatures[0] = Signature::from_bytes(&[9u8; 64]);
+    let before = signed.signatures.clone();
+    assert!(signed.merge_signatures(&conflicting).is_err());
+    assert_eq!(signed.signatures, before);
+}
+
+#[test]
+fn is_fully_signed_requires_every_slot() {
+    let a = SigningKey::from_bytes(&[1u8; 32]);
+    let b = SigningKey::from_bytes(&[2u8; 32]);
+    let mut tx = two_signer_tx(&a, &b);
+    assert!(!tx.is_fully_signed());
+
+    sign_transaction_by_key(&mut tx, &a).expect("sign a");
+    assert!(!tx.is_fully_signed());
+
+    sign_transaction_by_key(&mut tx, &b).expect("sign b");
+    assert!(tx.is_fully_signed());
+
+    // Count must match the header, not merely be non-zero
+    tx.signatures.push(Signature::from_bytes(&[9u8; 64]));
+    assert!(!tx.is_fully_signed());
+    tx.signatures.truncate(1);
+    assert!(!tx.is_fully_signed());
+}
+
+#[test]
+fn clear_signatures_resets_slots_for_resigning() {
+    let a = SigningKey::from_bytes(&[1u8; 32]);
+    let b = SigningKey::from_bytes(&[2u8; 32]);
+    let mut tx = two_signer_tx(&a, &b);
+    sign_transaction_by_key(&mut tx, &a).expect("sign a");
+    sign_transaction_by_key(&mut tx, &b).expect("sign b");
+
+    tx.clear_signatures();
+    assert_eq!(tx.signatures, vec![Signature::from_bytes(&[0u8; 64]); 2]);
+    assert_eq!(
+        tx.verify_all(),
+        vec![SignatureStatus::Unsigned, SignatureStatus::Unsigned]
+    );
+    assert!(!tx.is_fully_signed());
+
+    // Short signature lists are padded to the required count
+    tx.signatures.clear();
+    tx.clear_signatures();
+    assert_eq!(tx.signatures.len(), 2);
+
+    sign_transaction_by_key(&mut tx, &a).expect("re-sign a");
+    sign_transaction_by_key(&mut tx, &b).expect("re-sign b");
+    assert!(tx.is_fully_signed());
+}
@@ -0,0 +1,72 @@
+//! Transfer decoders are registered process-wide, so these checks live in their own test binary.
+
+use solana_tools_lite::extensions::registry;
+use solana_tools_lite::handlers::analysis::analyze_transaction;
+use solana_tools_lite::models::analysis::{AnalysisWarning, TransferView};
+use solana_tools_lite::models::hash_base58::HashBase58;
+use solana_tools_lite::models::instruction::Instruction;
+use solana_tools_lite::models::message::{Message, MessageHeader, MessageLegacy};
+use solana_tools_lite::models::pubkey_base58::PubkeyBase58;
+
+fn signer() -> PubkeyBase58 {
+    PubkeyBase58::from([1u8; 32])
+}
+
+fn recipient(n: u8) -> PubkeyBase58 {
+    PubkeyBase58::from([n; 32])
+}
+
+/// Test router layout: two little-endian u64 amounts, paid from the signer to two recipients.
+fn decode_split_router(_program_id: &PubkeyBase58, data: &[u8]) -> Vec<TransferView> {
+    data.chunks_exact(8)
+        .zip([3u8, 4u8])
+        .map(|(chunk, n)| TransferView {
+            from: signer().to_string(),
+            to: recipient(n).to_string(),
+            lamports: u64::from_le_bytes(chunk.try_into().unwrap()),
+            from_is_signer: false,
+            from_writable: true,
+            to_writable: true,
+            to_is_fee_payer: false,
+        })
+        .collect()
+}
+
+#[test]
+fn registered_decoder_reports_batched_transfers() {
+    let router = PubkeyBase58::from([77u8; 32]);
+    let mut data = Vec::new();
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&2_500u64.to_le_bytes());
+
+    let msg = Message::Legacy(MessageLegacy {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![signer(), recipient(3), recipient(4), router.clone()],
+        recent_blockhash: HashBase58([0u8; 32]),
+        instructions: vec![Instruction {
+            program_id_index: 3,
+            accounts: vec![0, 1, 2],
+            data,
+        }],
+    });
+
+    // Without a decoder the router is an unknown program
+    let before = analyze_transaction(&msg, &signer(), None, None, None);
+    assert!(before.transfers.is_empty());
+    assert!(before.warnings.iter().any(|w| matches!(w, AnalysisWarning::UnknownProgram { .. })));
+
+    registry::register_transfer_decoder(router.clone(), decode_split_router);
+    let analysis = analyze_transaction(&msg, &signer(), None, None, None);
+
+    assert_eq!(analysis.transfers.len(), 2);
+    assert_eq!(analysis.transfers[0].lamports, 1_000);
+    assert_eq!(analysis.transfers[1].to, recipient(4).to_string());
+    assert!(analysis.transfers.iter().all(|t| t.from_is_signer));
+    assert_eq!(analysis.total_sol_send_by_signer, 3_500);
+    assert_eq!(analysis.signer_outflows_by_recipient.len(), 2);
+    assert!(!analysis.warnings.iter().any(|w| matches!(w, AnalysisWarning::UnknownProgram { .. })));
+}
@@ -166,6 +166,94 @@ fn v0_transaction_roundtrip_via_ui_transaction() {
     }
 }
 
+#[test]
+fn ui_transaction_roundtrip_preserves_real_and_empty_signatures() {
+    let header = MessageHeader {
+        num_required_signatures: 2,
+        num_readonly_signed_accounts: 0,
+        num_readonly_unsigned_accounts: 1,
+    };
+    let real = deterministic_signature(7);
+    let placeholder = bs58::encode([0u8; 64]).into_string();
+
+    let ui_tx = UiTransaction {
+        // Second signer has not signed yet
+        signatures: vec![bs58::encode(real.to_bytes()).into_string(), String::new()],
+        message: UiRawMessage::Legacy(UiRawMessageLegacy {
+            header,
+            account_keys: vec![
+                deterministic_base58(55, 32),
+                deterministic_base58(56, 32),
+                deterministic_base58(57, 32),
+            ],
+            recent_blockhash: deterministic_base58(10, 32),
+            instructions: vec![UiCompiledInstruction {
+                program_id_index: 2,
+                accounts: vec![0, 1],
+                data: bs58::encode([1u8, 2, 3]).into_string(),
+            }],
+        }),
+    };
+
+    let tx = Transaction::try_from(&ui_tx).expect("ui -> tx");
+    assert_eq!(tx.signatures.len(), 2);
+    assert_eq!(tx.signatures[0].to_bytes(), real.to_bytes());
+    assert_eq!(tx.signatures[1].to_bytes(), [0u8; 64]);
+
+    let back = UiTransaction::from(&tx);
+    assert_eq!(
+        back.signatures,
+        vec![bs58::encode(real.to_bytes()).into_string(), placeholder.clone()]
+    );
+
+    // A missing slot is padded the same way as an empty one
+    let short = UiTransaction {
+        signatures: vec![bs58::encode(real.to_bytes()).into_string()],
+        ..ui_tx
+    };
+    let padded = UiTransaction::from(&Transaction::try_from(short).expect("short -> tx"));
+    assert_eq!(padded.signatures, back.signatures);
+}
+
+#[test]
+fn ui_transaction_rejects_more_signatures_than_required() {
+    let header = MessageHeader {
+        num_required_signatures: 2,
+        num_readonly_signed_accounts: 0,
+        num_readonly_unsigned_accounts: 1,
+    };
+
+    let ui_tx = UiTransaction {
+        signatures: vec![
+            bs58::encode(deterministic_signature(1).to_bytes()).into_string(),
+            bs58::encode(deterministic_signature(2).to_bytes()).into_string(),
+            bs58::encode(deterministic_signature(3).to_bytes()).into_string(),
+        ],
+        message: UiRawMessage::Legacy(UiRawMessageLegacy {
+            header,
+            account_keys: vec![
+                deterministic_base58(55, 32),
+                deterministic_base58(56, 32),
+                deterministic_base58(57, 32),
+            ],
+            recent_blockhash: deterministic_base58(10, 32),
+            instructions: vec![UiCompiledInstruction {
+                program_id_index: 2,
+                accounts: vec![0, 1],
+                data: bs58::encode(&[1u8, 2, 3]).into_string(),
+            }],
+        }),
+    };
+
+    let err = Transaction::try_from(ui_tx).expect_err("extra signature must be rejected");
+    match err {
+        TransactionParseError::TooManySignatures { found, required } => {
+            assert_eq!((found, required), (3, 2));
+        }
+        other => panic!("expected TooManySignatures error, got {other:?}"),
+    }
+}
+
 #[test]
 fn ui_transaction_rejects_short_signature() {
     let header = MessageHeader {
@@ -203,3 +291,51 @@ fn ui_transaction_rejects_short_signature() {
         other => panic!("expected InvalidFormat error, got {other:?}"),
     }
 }
+
+#[test]
+fn canonicalize_equal_transactions_textually_different() {
+    let key_a = deterministic_pubkey(1).to_string();
+    let key_b = deterministic_pubkey(2).to_string();
+    let blockhash = deterministic_base58(9, 32);
+    let data = bs58::encode([2u8, 0, 0, 0, 7]).into_string();
+
+    // camelCase aliases, padded strings and an empty signature slot
+    let textual_a = format!(
+        r#"{{"signatures":[""],"message":{{"header":{{"num_required_signatures":1,"num_readonly_signed_accounts":0,"num_readonly_unsigned_accounts":1}},"accountKeys":[" {key_a}","{key_b} "],"recentBlockhash":"{blockhash}\n","instructions":[{{"programIdIndex":1,"accounts":[0],"data":" {data}"}}]}}}}"#
+    );
+    // snake_case fields, no signatures at all
+    let textual_b = format!(
+        r#"{{"signatures":[],"message":{{"header":{{"num_required_signatures":1,"num_readonly_signed_accounts":0,"num_readonly_unsigned_accounts":1}},"account_keys":["{key_a}","{key_b}"],"recent_blockhash":"{blockhash}","instructions":[{{"program_id_index":1,"accounts":[0],"data":"{data}"}}]}}}}"#
+    );
+
+    let ui_a: UiTransaction = serde_json::from_str(&textual_a).expect("parse a");
+    let ui_b: UiTransaction = serde_json::from_str(&textual_b).expect("parse b");
+
+    let canon_a = ui_a.canonicalize().expect("canonicalize a");
+    let canon_b = ui_b.canonicalize().expect("canonicalize b");
+
+    assert_eq!(
+        serde_json::to_string(&canon_a).unwrap(),
+        serde_json::to_string(&canon_b).unwrap()
+    );
+    assert_eq!(canon_a.signatures, vec![bs58::encode([0u8; 64]).into_string()]);
+}
+
+#[test]
+fn canonicalize_rejects_invalid_account_key() {
+    let ui = UiTransaction {
+        signatures: vec![],
+        message: UiRawMessage::Legacy(UiRawMessageLegacy {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 0,
+            },
+            account_keys: vec!["not-base58!".to_string()],
+            recent_blockhash: deterministic_base58(9, 32),
+            instructions: vec![],
+        }),
+    };
+
+    assert!(ui.canonicalize().is_err());
+}
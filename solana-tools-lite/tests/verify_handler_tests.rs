@@ -168,4 +168,54 @@ mod tests_verify_handler {
             "Signature verification should fail with a mismatched public key"
         );
     }
+
+    /// With several candidate keys, the one that produced the signature is reported.
+    #[test]
+    fn test_verify_candidates_reports_matching_key() {
+        let candidates: Vec<String> = [11u8, 12, 13]
+            .iter()
+            .map(|&b| {
+                let key = ed25519::keypair_from_seed(&[b; 64]).unwrap();
+                bs58::encode(key.verifying_key().to_bytes()).into_string()
+            })
+            .collect();
+        let signer = ed25519::keypair_from_seed(&[12u8; 64]).unwrap();
+        let sig = bs58::encode(ed25519::sign_message(&signer, b"hello").to_bytes()).into_string();
+
+        let result = verify::handle_candidates("hello", &sig, &candidates).unwrap();
+        assert_eq!(result.candidate_index, Some(1));
+        assert_eq!(result.pubkey, candidates[1]);
+
+        assert!(verify::handle_candidates("other", &sig, &candidates).is_err());
+    }
+
+    /// Base64 signatures and public keys are detected and normalized to Base58.
+    #[test]
+    fn test_base64_inputs_normalize_to_base58() {
+        use data_encoding::BASE64;
+        use solana_tools_lite::errors::VerifyError;
+
+        let key = ed25519::keypair_from_seed(&[7u8; 64]).unwrap();
+        let sig = ed25519::sign_message(&key, b"hello").to_bytes();
+        let pubkey = key.verifying_key().to_bytes();
+
+        let sig_b58 = verify::signature_to_base58(&BASE64.encode(&sig)).unwrap();
+        let pubkey_b58 = verify::pubkey_to_base58(&BASE64.encode(&pubkey)).unwrap();
+        assert_eq!(sig_b58, bs58::encode(sig).into_string());
+        assert_eq!(pubkey_b58, bs58::encode(pubkey).into_string());
+        assert!(verify::handle("hello", &sig_b58, &pubkey_b58).is_ok());
+
+        // Base58 input passes through unchanged
+        assert_eq!(verify::pubkey_to_base58(&pubkey_b58).unwrap(), pubkey_b58);
+
+        // Decodes as Base64, but to the wrong length
+        assert!(matches!(
+            verify::signature_to_base58(&BASE64.encode(&pubkey)),
+            Err(VerifyError::InvalidSignatureLength(_))
+        ));
+        assert!(matches!(
+            verify::pubkey_to_base58("not base58 or base64!"),
+            Err(VerifyError::InvalidPubkeyFormat)
+        ));
+    }
 }